@@ -0,0 +1,159 @@
+// Backup and restore of the SkillHub settings in ~/.skillhub
+// Everything is bundled into one JSON file so it can be carried to another machine
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use tokio::fs;
+
+use crate::installer;
+
+// Settings files included in a backup; missing ones are skipped
+const SETTINGS_FILES: &[&str] = &["config.json", "favorites.json", "projects.json", "tools.json"];
+const BACKUP_VERSION: u32 = 1;
+const API_KEY_FIELD: &str = "anthropic_api_key";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SettingsBackup {
+    version: u32,
+    files: BTreeMap<String, Value>,
+}
+
+/// Write all settings files into one JSON backup at `dest_path`.
+/// The API key is left out unless `include_secrets` is set.
+/// Returns the names of the files included.
+pub async fn export_settings(dest_path: &str, include_secrets: bool) -> Result<Vec<String>, String> {
+    let config_dir = installer::get_skillhub_config_dir()?;
+
+    let mut files = BTreeMap::new();
+    for name in SETTINGS_FILES {
+        let content = match fs::read_to_string(config_dir.join(name)).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(format!("Failed to read {}: {}", name, e)),
+        };
+        let mut value: Value = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", name, e))?;
+
+        if *name == "config.json" && !include_secrets {
+            if let Some(config) = value.as_object_mut() {
+                config.remove(API_KEY_FIELD);
+            }
+        }
+        files.insert(name.to_string(), value);
+    }
+
+    let backup = SettingsBackup {
+        version: BACKUP_VERSION,
+        files,
+    };
+    let content = serde_json::to_string_pretty(&backup)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    fs::write(dest_path, content)
+        .await
+        .map_err(|e| format!("Failed to write backup: {}", e))?;
+
+    tracing::info!("Exported {} settings files to {}", backup.files.len(), dest_path);
+    Ok(backup.files.into_keys().collect())
+}
+
+/// Restore settings from a backup made by `export_settings`.
+/// With `merge`, objects are merged key by key (backup wins) and lists are combined;
+/// otherwise each file in the backup replaces the local one. A local API key is kept
+/// when the backup doesn't carry one. Returns the names of the files restored.
+pub async fn import_settings(src_path: &str, merge: bool) -> Result<Vec<String>, String> {
+    let content = fs::read_to_string(src_path)
+        .await
+        .map_err(|e| format!("Failed to read backup: {}", e))?;
+    let backup: SettingsBackup =
+        serde_json::from_str(&content).map_err(|e| format!("Invalid settings backup: {}", e))?;
+    if backup.version > BACKUP_VERSION {
+        return Err(format!("Unsupported settings backup version: {}", backup.version));
+    }
+
+    // Check everything before writing anything, so a bad backup doesn't leave half a restore
+    if let Some(name) = backup.files.keys().find(|name| !SETTINGS_FILES.contains(&name.as_str())) {
+        return Err(format!("Unexpected file in settings backup: {}", name));
+    }
+    if let Some(config) = backup.files.get("config.json") {
+        serde_json::from_value::<installer::SkillHubConfig>(config.clone())
+            .map_err(|e| format!("Invalid config.json in backup: {}", e))?;
+    }
+
+    // Keep settings commands from writing config.json halfway through the restore
+    let _guard = installer::lock_config().await;
+
+    let config_dir = installer::get_skillhub_config_dir()?;
+    fs::create_dir_all(&config_dir)
+        .await
+        .map_err(|e| format!("Failed to create config directory: {}", e))?;
+
+    let mut restored = Vec::new();
+    for (name, incoming) in backup.files {
+        let path = config_dir.join(&name);
+        let existing: Option<Value> = fs::read_to_string(&path)
+            .await
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok());
+
+        let mut value = match existing.clone() {
+            Some(existing) if merge => merge_values(existing, incoming),
+            _ => incoming,
+        };
+
+        if name == "config.json" {
+            keep_local_api_key(&mut value, existing.as_ref());
+        }
+
+        let content = serde_json::to_string_pretty(&value)
+            .map_err(|e| format!("Failed to serialize {}: {}", name, e))?;
+        fs::write(&path, content)
+            .await
+            .map_err(|e| format!("Failed to write {}: {}", name, e))?;
+        restored.push(name);
+    }
+
+    tracing::info!("Imported {} settings files from {}", restored.len(), src_path);
+    Ok(restored)
+}
+
+/// Merge two JSON values: objects key by key, arrays as a union, anything else takes `incoming`
+fn merge_values(existing: Value, incoming: Value) -> Value {
+    match (existing, incoming) {
+        (Value::Object(mut existing), Value::Object(incoming)) => {
+            for (key, value) in incoming {
+                let merged = match existing.remove(&key) {
+                    Some(current) => merge_values(current, value),
+                    None => value,
+                };
+                existing.insert(key, merged);
+            }
+            Value::Object(existing)
+        }
+        (Value::Array(mut existing), Value::Array(incoming)) => {
+            for item in incoming {
+                if !existing.contains(&item) {
+                    existing.push(item);
+                }
+            }
+            Value::Array(existing)
+        }
+        (_, incoming) => incoming,
+    }
+}
+
+/// Backups exported without secrets have no API key; don't let them wipe the local one
+fn keep_local_api_key(config: &mut Value, existing: Option<&Value>) {
+    let local_key = existing
+        .and_then(|e| e.get(API_KEY_FIELD))
+        .filter(|key| !key.is_null())
+        .cloned();
+    let (Some(config), Some(local_key)) = (config.as_object_mut(), local_key) else {
+        return;
+    };
+
+    let has_key = config.get(API_KEY_FIELD).is_some_and(|key| !key.is_null());
+    if !has_key {
+        config.insert(API_KEY_FIELD.to_string(), local_key);
+    }
+}
@@ -0,0 +1,227 @@
+// Fetching a catalog skill's complete file set, with a local cache
+// Bundles are cached in ~/.skillhub/cache/bundles/<skill id>/ so re-installs skip the network
+
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::sync;
+use crate::tools::{self, TextFiles};
+
+/// Max catalog files fetched at once
+const FETCH_CONCURRENCY: usize = 8;
+/// How long a cached bundle is used before fetching the skill again
+const BUNDLE_CACHE_TTL_SECS: u64 = 24 * 60 * 60;
+const BUNDLE_FILE: &str = "bundle.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedBundle {
+    fetched_at: u64, // Unix timestamp (seconds)
+    files: Vec<(String, String)>,
+}
+
+/// Collect file paths, with the SHA-256 the catalog reports when it has one, from the file tree
+fn collect_tree_files(nodes: &[serde_json::Value], files: &mut Vec<(String, Option<String>)>) {
+    for node in nodes {
+        match node.get("type").and_then(|t| t.as_str()) {
+            Some("file") => {
+                if let Some(path) = node.get("path").and_then(|p| p.as_str()) {
+                    let sha256 = node
+                        .get("sha256")
+                        .and_then(|h| h.as_str())
+                        .filter(|h| !h.is_empty())
+                        .map(|h| h.to_string());
+                    files.push((path.to_string(), sha256));
+                }
+            }
+            _ => {
+                if let Some(children) = node.get("children").and_then(|c| c.as_array()) {
+                    collect_tree_files(children, files);
+                }
+            }
+        }
+    }
+}
+
+/// Build the raw GitHub URL of a catalog file (paths are relative to the skill path)
+fn build_raw_url(
+    repo_url: &str,
+    branch: &str,
+    file_path: &str,
+    skill_path: Option<&str>,
+) -> Option<String> {
+    let rest = repo_url.split("github.com/").nth(1)?;
+    let mut parts = rest.split('/');
+    let owner = parts.next().filter(|s| !s.is_empty())?;
+    let repo = parts.next().filter(|s| !s.is_empty())?.trim_end_matches(".git");
+
+    let full_path = match skill_path.filter(|p| !p.is_empty()) {
+        Some(skill_path) => format!("{}/{}", skill_path.trim_matches('/'), file_path),
+        None => file_path.to_string(),
+    };
+
+    Some(format!(
+        "https://raw.githubusercontent.com/{}/{}/{}/{}",
+        owner, repo, branch, full_path
+    ))
+}
+
+/// Resolve a catalog skill's file tree and fetch every file concurrently.
+/// Each file comes back with its own result so callers can decide how to treat failures;
+/// a file whose content doesn't match the catalog's SHA-256 counts as failed.
+pub(crate) async fn fetch_catalog_files(
+    skill_id: &str,
+) -> Result<Vec<(String, Result<String, String>)>, String> {
+    let files_data = crate::get_skill_files(skill_id.to_string()).await?;
+    let repo_url = files_data
+        .get("repo_url")
+        .and_then(|v| v.as_str())
+        .ok_or("Catalog response has no repo_url")?
+        .to_string();
+    let branch = files_data
+        .get("branch")
+        .and_then(|v| v.as_str())
+        .unwrap_or("main")
+        .to_string();
+    let catalog_skill_path = files_data
+        .get("skill_path")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    let mut catalog_files = Vec::new();
+    if let Some(tree) = files_data.get("tree").and_then(|t| t.as_array()) {
+        collect_tree_files(tree, &mut catalog_files);
+    }
+
+    let mut fetched: Vec<(String, Result<String, String>)> = stream::iter(catalog_files)
+        .map(|(file_path, sha256)| {
+            let raw_url =
+                build_raw_url(&repo_url, &branch, &file_path, catalog_skill_path.as_deref())
+                    .ok_or_else(|| format!("Invalid repository URL: {}", repo_url));
+            async move {
+                let result = match raw_url {
+                    Ok(url) => crate::get_remote_file_content(url, None).await,
+                    Err(e) => Err(e),
+                };
+                let result = result.and_then(|content| match sha256 {
+                    Some(expected) => {
+                        let actual = sync::hash_content(content.as_bytes());
+                        if actual.eq_ignore_ascii_case(&expected) {
+                            Ok(content)
+                        } else {
+                            Err(format!("Integrity check failed: expected {}, got {}", expected, actual))
+                        }
+                    }
+                    None => Ok(content),
+                });
+                (file_path, result)
+            }
+        })
+        .buffer_unordered(FETCH_CONCURRENCY)
+        .collect()
+        .await;
+
+    fetched.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(fetched)
+}
+
+/// Get the bundle cache directory for a skill (~/.skillhub/cache/bundles/<id>)
+fn get_bundle_dir(skill_id: &str) -> Result<std::path::PathBuf, String> {
+    let valid = !skill_id.is_empty()
+        && skill_id != "."
+        && skill_id != ".."
+        && skill_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+    if !valid {
+        return Err(format!("Invalid skill id: {}", skill_id));
+    }
+
+    Ok(crate::installer::get_skillhub_config_dir()?
+        .join("cache")
+        .join("bundles")
+        .join(skill_id))
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Read a cached bundle if there is one and it hasn't expired
+async fn read_cached_bundle(skill_id: &str) -> Option<Vec<(String, String)>> {
+    let path = get_bundle_dir(skill_id).ok()?.join(BUNDLE_FILE);
+    let content = fs::read_to_string(&path).await.ok()?;
+    let bundle: CachedBundle = serde_json::from_str(&content).ok()?;
+
+    if now_secs().saturating_sub(bundle.fetched_at) > BUNDLE_CACHE_TTL_SECS {
+        return None;
+    }
+    Some(bundle.files)
+}
+
+async fn write_cached_bundle(skill_id: &str, files: &[(String, String)]) -> Result<(), String> {
+    let dir = get_bundle_dir(skill_id)?;
+    fs::create_dir_all(&dir)
+        .await
+        .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+
+    let bundle = CachedBundle {
+        fetched_at: now_secs(),
+        files: files.to_vec(),
+    };
+    let content = serde_json::to_string(&bundle)
+        .map_err(|e| format!("Failed to serialize bundle: {}", e))?;
+    fs::write(dir.join(BUNDLE_FILE), content)
+        .await
+        .map_err(|e| format!("Failed to write bundle cache: {}", e))
+}
+
+/// Fetch all files of a catalog skill as (relative_path, content) pairs, ready for
+/// `install_skill_files_to_tools`. Served from the cache when fresh unless `refresh` is set.
+/// Fails if any file can't be fetched, since a partial skill isn't installable.
+/// With `skill_md_only` just the root SKILL.md is returned (the cache still holds every file).
+pub async fn fetch_skill_bundle(
+    skill_id: &str,
+    refresh: bool,
+    skill_md_only: bool,
+) -> Result<TextFiles, String> {
+    let files = fetch_full_bundle(skill_id, refresh).await?;
+    if skill_md_only {
+        return Ok(tools::skill_md_only_files(&files)?.0);
+    }
+    Ok(files)
+}
+
+async fn fetch_full_bundle(skill_id: &str, refresh: bool) -> Result<TextFiles, String> {
+    if !refresh {
+        if let Some(files) = read_cached_bundle(skill_id).await {
+            tracing::debug!("Using cached bundle for {}", skill_id);
+            return Ok(files);
+        }
+    }
+
+    let mut files = Vec::new();
+    let mut failed = Vec::new();
+    for (file_path, result) in fetch_catalog_files(skill_id).await? {
+        match result {
+            Ok(content) => files.push((sync::normalize_portable_path(&file_path)?, content)),
+            Err(e) => failed.push(format!("{}: {}", file_path, e)),
+        }
+    }
+
+    if !failed.is_empty() {
+        return Err(format!("Failed to fetch skill files: {}", failed.join("; ")));
+    }
+    if files.is_empty() {
+        return Err(format!("Skill {} has no files", skill_id));
+    }
+
+    // The cache only saves time, so a failed write doesn't fail the fetch
+    if let Err(e) = write_cached_bundle(skill_id, &files).await {
+        tracing::warn!("Failed to cache bundle for {}: {}", skill_id, e);
+    }
+    Ok(files)
+}
@@ -0,0 +1,103 @@
+// Clearing the caches under ~/.skillhub/cache (catalog bundles and the like)
+// Only the cache directory is touched: config, favorites and installed skills are left alone
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tokio::fs;
+
+use crate::installer;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheCategoryReport {
+    pub name: String, // cache subdirectory, e.g. "bundles"; loose files are grouped as "other"
+    pub files: u64,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CacheClearReport {
+    pub categories: Vec<CacheCategoryReport>,
+    pub total_files: u64,
+    pub total_bytes: u64,
+}
+
+/// Delete everything in ~/.skillhub/cache, reporting files and bytes freed per category
+pub async fn clear_all_caches() -> Result<CacheClearReport, String> {
+    let cache_dir = installer::get_skillhub_config_dir()?.join("cache");
+    let mut report = CacheClearReport::default();
+
+    let mut entries = match fs::read_dir(&cache_dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(report),
+        Err(e) => return Err(format!("Failed to read cache directory: {}", e)),
+    };
+
+    let mut other = CacheCategoryReport {
+        name: "other".to_string(),
+        files: 0,
+        bytes: 0,
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        let (files, bytes) = count_files(path.clone()).await;
+
+        let is_dir = fs::symlink_metadata(&path)
+            .await
+            .map(|m| m.is_dir())
+            .unwrap_or(false);
+        let removed = if is_dir {
+            fs::remove_dir_all(&path).await
+        } else {
+            fs::remove_file(&path).await
+        };
+        if let Err(e) = removed {
+            return Err(format!("Failed to remove {}: {}", path.display(), e));
+        }
+
+        if is_dir {
+            report.categories.push(CacheCategoryReport {
+                name: entry.file_name().to_string_lossy().to_string(),
+                files,
+                bytes,
+            });
+        } else {
+            other.files += files;
+            other.bytes += bytes;
+        }
+    }
+    if other.files > 0 {
+        report.categories.push(other);
+    }
+
+    report.categories.sort_by(|a, b| a.name.cmp(&b.name));
+    report.total_files = report.categories.iter().map(|c| c.files).sum();
+    report.total_bytes = report.categories.iter().map(|c| c.bytes).sum();
+
+    tracing::info!(
+        "Cleared caches: {} files, {} bytes",
+        report.total_files,
+        report.total_bytes
+    );
+    Ok(report)
+}
+
+/// Number of files and their total size under a path (symlinks are not followed)
+#[async_recursion::async_recursion]
+async fn count_files(path: PathBuf) -> (u64, u64) {
+    let Ok(metadata) = fs::symlink_metadata(&path).await else {
+        return (0, 0);
+    };
+    if !metadata.is_dir() {
+        return (1, metadata.len());
+    }
+
+    let (mut files, mut bytes) = (0, 0);
+    if let Ok(mut entries) = fs::read_dir(&path).await {
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let (f, b) = count_files(entry.path()).await;
+            files += f;
+            bytes += b;
+        }
+    }
+    (files, bytes)
+}
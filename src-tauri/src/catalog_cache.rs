@@ -0,0 +1,245 @@
+// Local copy of catalog skill summaries for searching while offline
+// Stored as a JSON index in ~/.skillhub/cache/catalog/ (removed by clear_all_caches)
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tokio::fs;
+
+use crate::{categories, installer, SkillHubSkill};
+
+const INDEX_FILE: &str = "index.json";
+const PREFETCH_PAGE_SIZE: u32 = 100;
+/// Most results a cached search returns
+const SEARCH_RESULT_LIMIT: usize = 50;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CatalogIndex {
+    fetched_at: u64, // Unix timestamp (seconds)
+    skills: Vec<SkillHubSkill>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogPagination {
+    pub page: u32,
+    pub limit: u32,
+    pub total: usize,
+    pub total_pages: usize,
+}
+
+/// A page of the prefetched catalog, shaped like the catalog API's response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedCatalogPage {
+    pub skills: Vec<SkillHubSkill>,
+    pub pagination: CatalogPagination,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrefetchReport {
+    pub skills_cached: usize,
+    pub pages_fetched: u32,
+    pub path: String,
+}
+
+fn index_path() -> Result<std::path::PathBuf, String> {
+    Ok(installer::get_skillhub_config_dir()?
+        .join("cache")
+        .join("catalog")
+        .join(INDEX_FILE))
+}
+
+/// Walk the catalog (all of it, or only the given categories) and save up to
+/// `max_skills` skill summaries for `search_cached_catalog`. Replaces the previous index.
+pub async fn prefetch_catalog(
+    categories: Option<&[String]>,
+    max_skills: usize,
+) -> Result<PrefetchReport, String> {
+    installer::ensure_online()?;
+    if max_skills == 0 {
+        return Err("max_skills must be at least 1".to_string());
+    }
+
+    let client = reqwest::Client::new();
+    let filters: Vec<Option<&str>> = match categories {
+        Some(categories) if !categories.is_empty() => {
+            categories.iter().map(|c| Some(c.as_str())).collect()
+        }
+        _ => vec![None],
+    };
+
+    let mut skills: Vec<SkillHubSkill> = Vec::new();
+    let mut seen = HashSet::new();
+    let mut pages_fetched = 0;
+    'filters: for category in filters {
+        let mut page = 1;
+        loop {
+            let data =
+                categories::fetch_catalog_page(&client, page, PREFETCH_PAGE_SIZE, category).await?;
+            pages_fetched += 1;
+
+            let batch = data
+                .get("skills")
+                .and_then(|s| s.as_array())
+                .cloned()
+                .unwrap_or_default();
+            let batch_len = batch.len();
+            for value in batch {
+                let Ok(skill) = serde_json::from_value::<SkillHubSkill>(value) else {
+                    continue;
+                };
+                if seen.insert(skill.id.clone()) {
+                    skills.push(skill);
+                }
+                if skills.len() >= max_skills {
+                    break 'filters;
+                }
+            }
+
+            let total_pages = data
+                .pointer("/pagination/totalPages")
+                .and_then(|t| t.as_u64())
+                .unwrap_or(0);
+            if batch_len < PREFETCH_PAGE_SIZE as usize || u64::from(page) >= total_pages {
+                break;
+            }
+            page += 1;
+        }
+    }
+
+    let index = CatalogIndex {
+        fetched_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        skills,
+    };
+    let path = index_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+    }
+    let content = serde_json::to_string(&index)
+        .map_err(|e| format!("Failed to serialize catalog index: {}", e))?;
+    // Write beside the index and rename, so a search never reads a half-written file
+    let temp = path.with_extension("json.tmp");
+    fs::write(&temp, content)
+        .await
+        .map_err(|e| format!("Failed to write catalog index: {}", e))?;
+    fs::rename(&temp, &path)
+        .await
+        .map_err(|e| format!("Failed to write catalog index: {}", e))?;
+
+    tracing::info!(
+        "Cached {} catalog skills from {} pages",
+        index.skills.len(),
+        pages_fetched
+    );
+    Ok(PrefetchReport {
+        skills_cached: index.skills.len(),
+        pages_fetched,
+        path: path.to_string_lossy().to_string(),
+    })
+}
+
+/// The saved index, None if the catalog hasn't been prefetched
+async fn read_index() -> Result<Option<CatalogIndex>, String> {
+    let content = match fs::read_to_string(index_path()?).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Failed to read catalog index: {}", e)),
+    };
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse catalog index: {}", e))
+}
+
+/// Best rated first
+fn by_score(a: &SkillHubSkill, b: &SkillHubSkill) -> std::cmp::Ordering {
+    b.simple_score
+        .unwrap_or(0.0)
+        .total_cmp(&a.simple_score.unwrap_or(0.0))
+}
+
+/// Browse the prefetched catalog without the network: one page (from 1) of every cached
+/// skill, or of one category's, best rated first. Empty if the catalog hasn't been prefetched.
+pub async fn get_cached_catalog_page(
+    page: u32,
+    limit: u32,
+    category: Option<&str>,
+) -> Result<CachedCatalogPage, String> {
+    if page == 0 || limit == 0 {
+        return Err("page and limit must be at least 1".to_string());
+    }
+    let category = category.filter(|c| !c.is_empty());
+
+    let mut skills: Vec<SkillHubSkill> = read_index()
+        .await?
+        .map(|index| index.skills)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|skill| category.is_none_or(|c| skill.category == c))
+        .collect();
+    skills.sort_by(by_score);
+
+    let total = skills.len();
+    let start = (page as usize - 1).saturating_mul(limit as usize);
+    Ok(CachedCatalogPage {
+        skills: skills.into_iter().skip(start).take(limit as usize).collect(),
+        pagination: CatalogPagination {
+            page,
+            limit,
+            total,
+            total_pages: total.div_ceil(limit as usize).max(1),
+        },
+    })
+}
+
+/// Search the prefetched catalog without the network. Every word of the query has to
+/// appear in the skill's name, slug, description, author or category; name matches rank
+/// highest. Returns nothing if the catalog hasn't been prefetched.
+pub async fn search_cached_catalog(query: &str) -> Result<Vec<SkillHubSkill>, String> {
+    let Some(index) = read_index().await? else {
+        return Ok(Vec::new());
+    };
+
+    let words: Vec<String> = query
+        .split_whitespace()
+        .map(|w| w.to_lowercase())
+        .collect();
+
+    let mut matches: Vec<(usize, SkillHubSkill)> = index
+        .skills
+        .into_iter()
+        .filter_map(|skill| {
+            let name = skill.name.to_lowercase();
+            let slug = skill.slug.to_lowercase();
+            let description = skill.description.to_lowercase();
+            let other = format!("{} {}", skill.author, skill.category).to_lowercase();
+
+            let mut score = 0;
+            for word in &words {
+                let word_score = if name.contains(word.as_str()) {
+                    3
+                } else if slug.contains(word.as_str()) {
+                    2
+                } else if description.contains(word.as_str()) || other.contains(word.as_str()) {
+                    1
+                } else {
+                    return None;
+                };
+                score += word_score;
+            }
+            Some((score, skill))
+        })
+        .collect();
+
+    matches.sort_by(|(a_score, a), (b_score, b)| {
+        b_score.cmp(a_score).then_with(|| by_score(a, b))
+    });
+    Ok(matches
+        .into_iter()
+        .take(SEARCH_RESULT_LIMIT)
+        .map(|(_, skill)| skill)
+        .collect())
+}
@@ -0,0 +1,135 @@
+// The catalog's category list with skill counts, for the Discover category filter
+// The API has no categories endpoint, so categories are found by scanning the catalog and
+// each one's count comes from the catalog's own pagination total
+
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::installer;
+
+/// How long the category list is reused before asking the server again
+const CATEGORIES_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+/// Catalog pages scanned to discover categories, and their size
+const SCAN_PAGES: u32 = 5;
+const SCAN_PAGE_SIZE: u32 = 100;
+/// Max category count queries at once
+const COUNT_CONCURRENCY: usize = 6;
+
+static CATEGORIES_CACHE: Mutex<Option<(Instant, Vec<CategoryInfo>)>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryInfo {
+    pub slug: String, // value for get_catalog's `category` filter
+    pub name: String,
+    pub count: u64,
+}
+
+/// Categories in the catalog with their skill counts, most skills first.
+/// Served from memory for CATEGORIES_CACHE_TTL after a successful fetch.
+pub async fn get_catalog_categories() -> Result<Vec<CategoryInfo>, String> {
+    if let Ok(cache) = CATEGORIES_CACHE.lock() {
+        if let Some((fetched_at, categories)) = cache.as_ref() {
+            if fetched_at.elapsed() < CATEGORIES_CACHE_TTL {
+                return Ok(categories.clone());
+            }
+        }
+    }
+    installer::ensure_online()?;
+
+    let client = reqwest::Client::new();
+    let mut slugs = BTreeSet::new();
+    for page in 1..=SCAN_PAGES {
+        let data = fetch_catalog_page(&client, page, SCAN_PAGE_SIZE, None).await?;
+        let skills = data
+            .get("skills")
+            .and_then(|s| s.as_array())
+            .cloned()
+            .unwrap_or_default();
+        slugs.extend(
+            skills
+                .iter()
+                .filter_map(|skill| skill.get("category").and_then(|c| c.as_str()))
+                .filter(|category| !category.is_empty())
+                .map(|category| category.to_string()),
+        );
+
+        let total_pages = data
+            .pointer("/pagination/totalPages")
+            .and_then(|t| t.as_u64())
+            .unwrap_or(0);
+        if skills.len() < SCAN_PAGE_SIZE as usize || u64::from(page) >= total_pages {
+            break;
+        }
+    }
+
+    let counts: Vec<Result<CategoryInfo, String>> = stream::iter(slugs)
+        .map(|slug| {
+            let client = client.clone();
+            async move {
+                let data = fetch_catalog_page(&client, 1, 1, Some(&slug)).await?;
+                let count = data
+                    .pointer("/pagination/total")
+                    .and_then(|t| t.as_u64())
+                    .unwrap_or(0);
+                Ok(CategoryInfo {
+                    name: display_name(&slug),
+                    slug,
+                    count,
+                })
+            }
+        })
+        .buffer_unordered(COUNT_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut categories = counts.into_iter().collect::<Result<Vec<_>, _>>()?;
+    categories.retain(|c| c.count > 0);
+    categories.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.slug.cmp(&b.slug)));
+
+    if let Ok(mut cache) = CATEGORIES_CACHE.lock() {
+        *cache = Some((Instant::now(), categories.clone()));
+    }
+    Ok(categories)
+}
+
+/// One page of the public catalog, optionally filtered to a category
+pub(crate) async fn fetch_catalog_page(
+    client: &reqwest::Client,
+    page: u32,
+    limit: u32,
+    category: Option<&str>,
+) -> Result<serde_json::Value, String> {
+    let mut url = reqwest::Url::parse(&format!("{}/api/v1/desktop/catalog", crate::get_api_base_url()))
+        .map_err(|e| format!("Invalid API URL: {}", e))?;
+    url.query_pairs_mut()
+        .append_pair("page", &page.to_string())
+        .append_pair("limit", &limit.to_string());
+    if let Some(category) = category {
+        url.query_pairs_mut().append_pair("category", category);
+    }
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to get catalog page: {}", e))?;
+    crate::read_json_response(response, "Failed to get catalog page").await
+}
+
+/// "ai-ml" -> "Ai Ml"; the catalog only carries slugs
+fn display_name(slug: &str) -> String {
+    slug.split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
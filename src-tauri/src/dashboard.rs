@@ -0,0 +1,121 @@
+// Aggregate numbers for the dashboard summary card, in one call instead of several queries
+// Local counts are computed up front; checking for updates needs the network and runs separately
+
+use futures::future::join_all;
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::{skill_backups, sync, tools, verify};
+
+/// Max skills checked against the catalog at once
+const UPDATE_CHECK_CONCURRENCY: usize = 4;
+/// Give up on a single skill's update check after this long
+const UPDATE_CHECK_TIMEOUT: Duration = Duration::from_secs(20);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolStats {
+    pub tool_id: String,
+    pub name: String,
+    pub skills_count: usize,
+    pub total_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardStats {
+    pub total_skills: usize, // skills in directories shared by several tools count once
+    pub total_bytes: u64,
+    pub tools: Vec<ToolStats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateStats {
+    pub checked: usize, // skills with a .skillhub.json, i.e. a known catalog source
+    pub updates_available: usize,
+    pub failed: usize,
+}
+
+/// Count skills and disk usage across all enabled tools, scanning the tools in parallel
+pub async fn get_dashboard_stats() -> Result<DashboardStats, String> {
+    let tools = tools::enabled_tool_names();
+    let scans = join_all(tools.iter().map(|(id, _)| scan_tool(id))).await;
+
+    let mut sizes: BTreeMap<String, u64> = BTreeMap::new();
+    let mut stats = Vec::new();
+    for ((id, name), scan) in tools.into_iter().zip(scans) {
+        let skills = scan?;
+        stats.push(ToolStats {
+            tool_id: id.to_string(),
+            name: name.to_string(),
+            skills_count: skills.len(),
+            total_bytes: skills.iter().map(|(_, size)| size).sum(),
+        });
+        sizes.extend(skills);
+    }
+
+    Ok(DashboardStats {
+        total_skills: sizes.len(),
+        total_bytes: sizes.values().sum(),
+        tools: stats,
+    })
+}
+
+/// Installed skills of one tool with their size in bytes, keyed by path
+async fn scan_tool(tool_id: &str) -> Result<Vec<(String, u64)>, String> {
+    let skills = tools::get_skills_for_tool(tool_id).await?;
+    let sizes = join_all(
+        skills
+            .iter()
+            .map(|skill| skill_backups::disk_usage(PathBuf::from(&skill.path))),
+    )
+    .await;
+    Ok(skills.into_iter().map(|s| s.path).zip(sizes).collect())
+}
+
+/// Count installed skills whose catalog version differs from the local copy.
+/// Only skills installed from the catalog (with a .skillhub.json) can be checked;
+/// a skill whose check fails or times out is counted in `failed`, not as an update.
+pub async fn get_dashboard_update_stats() -> Result<UpdateStats, String> {
+    let mut catalog_skills: Vec<String> = Vec::new();
+    for skill in tools::get_all_installed_skills().await? {
+        if matches!(sync::read_meta(&skill.path).await, Ok(Some(_))) {
+            catalog_skills.push(skill.path);
+        }
+    }
+    let checked = catalog_skills.len();
+
+    let results: Vec<Option<bool>> = stream::iter(catalog_skills)
+        .map(|path| async move {
+            let check = tokio::time::timeout(
+                UPDATE_CHECK_TIMEOUT,
+                verify::verify_against_catalog(&path),
+            )
+            .await;
+            match check {
+                // Files only present locally are the user's additions, not an update
+                Ok(Ok(result)) if result.errors.is_empty() => {
+                    Some(!result.differing.is_empty() || !result.missing.is_empty())
+                }
+                Ok(Ok(_)) => None,
+                Ok(Err(e)) => {
+                    tracing::warn!("Update check failed for {}: {}", path, e);
+                    None
+                }
+                Err(_) => {
+                    tracing::warn!("Update check timed out for {}", path);
+                    None
+                }
+            }
+        })
+        .buffer_unordered(UPDATE_CHECK_CONCURRENCY)
+        .collect()
+        .await;
+
+    Ok(UpdateStats {
+        checked,
+        updates_available: results.iter().filter(|r| **r == Some(true)).count(),
+        failed: results.iter().filter(|r| r.is_none()).count(),
+    })
+}
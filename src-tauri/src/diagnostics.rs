@@ -0,0 +1,106 @@
+// Diagnostics report for bug reports
+// Bundles environment info without leaking secrets (the API key is never included)
+
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+use crate::{installer, preflight, sync, tools};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Diagnostics {
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    pub api_base_url: String,
+    pub api_reachable: bool,
+    pub api_latency_ms: Option<u64>,
+    pub api_error: Option<String>,
+    pub dependencies: installer::DependencyStatus,
+    pub tools: Vec<ToolSummary>,
+    pub config_file_exists: bool,
+    pub non_utf8_entries: Vec<String>,
+    pub path_entries: Vec<String>, // PATH as the app sees it, which can differ from a terminal's
+    pub npm_global_on_path: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolSummary {
+    pub id: String,
+    pub installed: bool,
+    pub skills_count: usize,
+}
+
+/// Check whether the SkillHub API answers at all (any HTTP status counts as reachable)
+async fn ping_api(base_url: &str) -> (bool, Option<u64>, Option<String>) {
+    if let Err(e) = crate::installer::ensure_online() {
+        return (false, None, Some(e));
+    }
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => return (false, None, Some(format!("Failed to create HTTP client: {}", e))),
+    };
+
+    let started = Instant::now();
+    match client.get(base_url).send().await {
+        Ok(_) => (true, Some(started.elapsed().as_millis() as u64), None),
+        Err(e) => (false, None, Some(e.to_string())),
+    }
+}
+
+/// Collect environment info for bug reports
+pub async fn collect_diagnostics(app_version: &str) -> Result<Diagnostics, String> {
+    let api_base_url = crate::get_api_base_url();
+    let (api_reachable, api_latency_ms, api_error) = ping_api(&api_base_url).await;
+
+    // Dependency checks shell out to several commands, keep them off the async runtime
+    let mut dependencies = tokio::task::spawn_blocking(installer::check_all_dependencies)
+        .await
+        .map_err(|e| format!("Failed to check dependencies: {}", e))?;
+    dependencies.config.api_key_preview = None;
+
+    let npm_global_on_path = preflight::path_contains_npm_global().await;
+
+    let tools = tools::detect_all_tools(None)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|t| ToolSummary {
+            id: t.id,
+            installed: t.installed,
+            skills_count: t.skills_count,
+        })
+        .collect();
+
+    let config_file_exists = installer::get_skillhub_config_dir()
+        .map(|dir| dir.join("config.json").exists())
+        .unwrap_or(false);
+
+    Ok(Diagnostics {
+        app_version: app_version.to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        api_base_url,
+        api_reachable,
+        api_latency_ms,
+        api_error,
+        dependencies,
+        tools,
+        config_file_exists,
+        non_utf8_entries: sync::get_non_utf8_entries(),
+        path_entries: preflight::get_effective_path(),
+        npm_global_on_path,
+    })
+}
+
+/// Collect diagnostics and write them to a JSON file
+pub async fn export_diagnostics(app_version: &str, dest_path: &str) -> Result<(), String> {
+    let diagnostics = collect_diagnostics(app_version).await?;
+
+    let content = serde_json::to_string_pretty(&diagnostics)
+        .map_err(|e| format!("Failed to serialize diagnostics: {}", e))?;
+
+    sync::save_export(content.as_bytes(), dest_path).await
+}
@@ -0,0 +1,82 @@
+// File-by-file comparison of two skills, e.g. the same skill installed in two tools
+
+use serde::{Deserialize, Serialize};
+use similar::TextDiff;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::library;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileDiff {
+    pub filepath: String,
+    pub status: String, // "modified", "only_in_a", "only_in_b" or "binary_differs"
+    pub diff: Option<String>, // unified diff, only for modified text files
+}
+
+/// Compare two skills and return a diff for every file that differs.
+/// Files are paired by relative path; identical files are left out.
+pub async fn diff_skills(path_a: &str, path_b: &str) -> Result<Vec<FileDiff>, String> {
+    let files_a = read_files_by_path(path_a).await?;
+    let files_b = read_files_by_path(path_b).await?;
+
+    let mut filepaths: Vec<&String> = files_a.keys().chain(files_b.keys()).collect();
+    filepaths.sort();
+    filepaths.dedup();
+
+    let mut diffs = Vec::new();
+    for filepath in filepaths {
+        let diff = match (files_a.get(filepath), files_b.get(filepath)) {
+            (Some(a), Some(b)) => diff_file(filepath, a, b),
+            (Some(_), None) => Some(FileDiff {
+                filepath: filepath.clone(),
+                status: "only_in_a".to_string(),
+                diff: None,
+            }),
+            (None, Some(_)) => Some(FileDiff {
+                filepath: filepath.clone(),
+                status: "only_in_b".to_string(),
+                diff: None,
+            }),
+            (None, None) => None,
+        };
+        diffs.extend(diff);
+    }
+
+    Ok(diffs)
+}
+
+async fn read_files_by_path(path: &str) -> Result<BTreeMap<String, Vec<u8>>, String> {
+    let skill_path = Path::new(path);
+    if !skill_path.exists() {
+        return Err(format!("Path does not exist: {}", path));
+    }
+
+    Ok(library::read_skill_files(skill_path).await?.into_iter().collect())
+}
+
+/// Diff a file present on both sides, None if the contents are identical
+fn diff_file(filepath: &str, a: &[u8], b: &[u8]) -> Option<FileDiff> {
+    if a == b {
+        return None;
+    }
+
+    match (std::str::from_utf8(a), std::str::from_utf8(b)) {
+        (Ok(text_a), Ok(text_b)) => {
+            let diff = TextDiff::from_lines(text_a, text_b)
+                .unified_diff()
+                .header(&format!("a/{}", filepath), &format!("b/{}", filepath))
+                .to_string();
+            Some(FileDiff {
+                filepath: filepath.to_string(),
+                status: "modified".to_string(),
+                diff: Some(diff),
+            })
+        }
+        _ => Some(FileDiff {
+            filepath: filepath.to_string(),
+            status: "binary_differs".to_string(),
+            diff: None,
+        }),
+    }
+}
@@ -0,0 +1,136 @@
+// Free disk space and writability checks, so large installs/imports fail up front instead of midway
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiskInfo {
+    pub available_bytes: u64,
+    pub total_bytes: u64,
+}
+
+/// The path itself or its closest existing ancestor (destinations are often not created yet)
+fn existing_ancestor(path: &Path) -> Option<&Path> {
+    path.ancestors().find(|p| p.exists())
+}
+
+/// Get the available and total space of the volume containing path
+pub fn check_disk_space(path: &str) -> Result<DiskInfo, String> {
+    let existing = existing_ancestor(Path::new(path))
+        .ok_or_else(|| format!("Path does not exist: {}", path))?;
+
+    let available_bytes = fs2::available_space(existing)
+        .map_err(|e| format!("Failed to get available disk space: {}", e))?;
+    let total_bytes = fs2::total_space(existing)
+        .map_err(|e| format!("Failed to get total disk space: {}", e))?;
+
+    Ok(DiskInfo {
+        available_bytes,
+        total_bytes,
+    })
+}
+
+/// Fail with a clear message if the volume containing path has less than required_bytes free.
+/// If the free space can't be determined the write is allowed to proceed.
+pub fn ensure_free_space(path: &Path, required_bytes: u64) -> Result<(), String> {
+    let Ok(info) = check_disk_space(&path.to_string_lossy()) else {
+        return Ok(());
+    };
+
+    if info.available_bytes < required_bytes {
+        return Err(format!(
+            "Insufficient disk space at {}: {} bytes needed, {} bytes available",
+            path.display(),
+            required_bytes,
+            info.available_bytes
+        ));
+    }
+
+    Ok(())
+}
+
+/// `ensure_free_space` for writing `required_bytes` into each of several directories, checked
+/// before any of them is written. Directories reporting the same volume size and free space
+/// are taken to be on one volume, so the space they need is added up.
+pub fn ensure_free_space_all(dirs: &[PathBuf], required_bytes: u64) -> Result<(), String> {
+    let volumes: Vec<Option<(u64, u64)>> = dirs
+        .iter()
+        .map(|dir| {
+            check_disk_space(&dir.to_string_lossy())
+                .ok()
+                .map(|info| (info.total_bytes, info.available_bytes))
+        })
+        .collect();
+
+    for (dir, volume) in dirs.iter().zip(&volumes) {
+        let Some((_, available_bytes)) = volume else {
+            continue;
+        };
+        let sharing = volumes.iter().filter(|v| *v == volume).count() as u64;
+        let needed = required_bytes.saturating_mul(sharing);
+        if *available_bytes < needed {
+            return Err(format!(
+                "Insufficient disk space at {}: {} bytes needed, {} bytes available",
+                dir.display(),
+                needed,
+                available_bytes
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WritableStatus {
+    pub writable: bool,
+    pub reason: Option<String>, // why writing will fail, when it will
+}
+
+/// Check that files can be written in `dir` by creating and deleting a temp file there.
+/// A directory that doesn't exist yet is checked at its closest existing ancestor,
+/// since installs create it.
+pub fn check_writable(dir: &Path) -> WritableStatus {
+    let Some(existing) = existing_ancestor(dir) else {
+        return WritableStatus {
+            writable: false,
+            reason: Some(format!("{} does not exist", dir.display())),
+        };
+    };
+
+    let probe = existing.join(format!(".skillhub-write-test-{}", std::process::id()));
+    let result = std::fs::File::create(&probe).and_then(|_| std::fs::remove_file(&probe));
+
+    match result {
+        Ok(()) => WritableStatus {
+            writable: true,
+            reason: None,
+        },
+        Err(e) => {
+            let reason = match e.kind() {
+                std::io::ErrorKind::PermissionDenied => {
+                    format!("You don't have permission to write to {}", existing.display())
+                }
+                std::io::ErrorKind::ReadOnlyFilesystem => {
+                    format!("{} is on a read-only volume", existing.display())
+                }
+                _ => format!("Cannot write to {}: {}", existing.display(), e),
+            };
+            WritableStatus {
+                writable: false,
+                reason: Some(reason),
+            }
+        }
+    }
+}
+
+/// Fail with a friendly message if `dir` is not writable
+pub fn ensure_writable(dir: &Path) -> Result<(), String> {
+    match check_writable(dir) {
+        WritableStatus { writable: true, .. } => Ok(()),
+        WritableStatus { reason, .. } => Err(format!(
+            "Skills directory is not writable: {}",
+            reason.unwrap_or_else(|| dir.display().to_string())
+        )),
+    }
+}
@@ -0,0 +1,449 @@
+// Canonical formatting of a SKILL.md's frontmatter, for the editor's format action
+// Covers the YAML subset skills use: scalars, inline and block lists, and nested or
+// multi-line values (kept verbatim). The markdown body is never touched.
+
+/// Known fields come first in this order; other keys follow in their original order
+const KEY_ORDER: &[&str] = &["name", "description", "author", "category", "tags"];
+/// Fields that hold a list; a comma-separated scalar is accepted and turned into one
+const LIST_KEYS: &[&str] = &["tags"];
+
+enum Value {
+    /// A single-line value, unquoted
+    Scalar(String),
+    List(Vec<String>),
+    /// `|`/`>` text or a nested mapping: the header after the colon and the indented lines
+    Raw(String, Vec<String>),
+}
+
+struct Field {
+    comments: Vec<String>, // `#` lines directly above the key
+    key: String,
+    value: Value,
+    verbatim: Option<String>, // original text of an unquoted scalar of an unknown key
+    comment: Option<String>,  // `# ...` after the value on the key's line
+}
+
+/// Reformat the frontmatter of a skill document and return the whole document.
+/// Invalid frontmatter fails with "frontmatter_error: line <n>: <problem>".
+pub fn format_skill_frontmatter(content: &str) -> Result<String, String> {
+    let newline = if content.contains("\r\n") { "\r\n" } else { "\n" };
+    let lines: Vec<&str> = content.split('\n').map(|l| l.trim_end_matches('\r')).collect();
+
+    if lines.first() != Some(&"---") {
+        return Err(error(1, "missing frontmatter (--- block at the top)"));
+    }
+    let close = lines
+        .iter()
+        .skip(1)
+        .position(|line| *line == "---")
+        .map(|i| i + 1)
+        .ok_or_else(|| error(1, "frontmatter is not closed with ---"))?;
+
+    let fields = parse_fields(&lines[1..close])?;
+    let mut output = String::from("---");
+    output.push_str(newline);
+    for field in order_fields(fields) {
+        for line in render_field(&field) {
+            output.push_str(&line);
+            output.push_str(newline);
+        }
+    }
+    output.push_str("---");
+
+    // Everything after the closing line is the body, byte for byte
+    let mut segments = content.split_inclusive('\n');
+    let body_offset: usize = segments.by_ref().take(close).map(str::len).sum();
+    let closing = segments.next().unwrap_or_default();
+    output.push_str(&closing[3..]);
+    output.push_str(&content[body_offset + closing.len()..]);
+    Ok(output)
+}
+
+fn error(line: usize, message: &str) -> String {
+    format!("frontmatter_error: line {}: {}", line, message)
+}
+
+fn parse_fields(lines: &[&str]) -> Result<Vec<Field>, String> {
+    let mut fields: Vec<Field> = Vec::new();
+    let mut comments = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        // Document line numbers: the opening --- is line 1
+        let line_no = i + 2;
+        let line = lines[i];
+        i += 1;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+        if line.trim_start().starts_with('#') {
+            comments.push(line.trim().to_string());
+            continue;
+        }
+        if line.starts_with([' ', '\t']) {
+            return Err(error(line_no, "unexpected indentation"));
+        }
+
+        let (key, raw_value) = line
+            .split_once(':')
+            .ok_or_else(|| error(line_no, "expected `key: value`"))?;
+        let key = key.trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            return Err(error(line_no, "invalid key"));
+        }
+        if fields.iter().any(|f| f.key == key) {
+            return Err(error(line_no, &format!("duplicate key `{}`", key)));
+        }
+        let (raw_value, comment) = split_comment(raw_value.trim());
+
+        // Indented lines (or "- " items) that belong to this key
+        let mut block = Vec::new();
+        while i < lines.len() {
+            let next = lines[i];
+            let continues = next.starts_with([' ', '\t'])
+                || next.starts_with("- ")
+                || (next.trim().is_empty()
+                    && lines[i + 1..]
+                        .iter()
+                        .find(|l| !l.trim().is_empty())
+                        .is_some_and(|l| l.starts_with([' ', '\t'])));
+            if !continues {
+                break;
+            }
+            block.push(next);
+            i += 1;
+        }
+
+        let is_list_key = LIST_KEYS.contains(&key);
+        let mut verbatim = None;
+        let value = if raw_value.starts_with(['|', '>']) || (raw_value.is_empty() && !block.is_empty()) {
+            let items: Option<Vec<&str>> = block
+                .iter()
+                .filter(|l| !l.trim().is_empty())
+                .map(|l| l.trim().strip_prefix("- ").or((l.trim() == "-").then_some("")))
+                .collect();
+            // Items with their own comments are kept verbatim so the comments stay put
+            match items {
+                Some(items)
+                    if raw_value.is_empty()
+                        && items.iter().all(|item| split_comment(item).1.is_none()) =>
+                {
+                    let mut parsed = Vec::new();
+                    for (offset, item) in items.iter().enumerate() {
+                        parsed.push(parse_scalar(item, line_no + 1 + offset)?);
+                    }
+                    Value::List(parsed)
+                }
+                _ => Value::Raw(
+                    raw_value.to_string(),
+                    block.iter().map(|l| l.trim_end().to_string()).collect(),
+                ),
+            }
+        } else if !block.is_empty() {
+            return Err(error(line_no + 1, "unexpected indentation"));
+        } else if let Some(inner) = raw_value.strip_prefix('[') {
+            let inner = inner
+                .strip_suffix(']')
+                .ok_or_else(|| error(line_no, "list is not closed with ]"))?;
+            let mut items = Vec::new();
+            for item in split_inline_list(inner).map_err(|e| error(line_no, e))? {
+                items.push(parse_scalar(item.trim(), line_no)?);
+            }
+            Value::List(items)
+        } else if is_list_key && !raw_value.starts_with(['"', '\'']) {
+            Value::List(
+                raw_value
+                    .split(',')
+                    .map(|t| t.trim().to_string())
+                    .filter(|t| !t.is_empty())
+                    .collect(),
+            )
+        } else {
+            if !KEY_ORDER.contains(&key) && !raw_value.starts_with(['"', '\'']) {
+                verbatim = Some(raw_value.to_string());
+            }
+            Value::Scalar(parse_scalar(raw_value, line_no)?)
+        };
+
+        if KEY_ORDER.contains(&key) && !is_list_key && matches!(value, Value::List(_)) {
+            return Err(error(line_no, &format!("`{}` must be a single value, not a list", key)));
+        }
+
+        fields.push(Field {
+            comments: std::mem::take(&mut comments),
+            key: key.to_string(),
+            value,
+            verbatim,
+            comment: comment.map(str::to_string),
+        });
+    }
+
+    if !comments.is_empty() {
+        // Trailing comments stay at the end
+        fields.push(Field {
+            comments,
+            key: String::new(),
+            value: Value::Scalar(String::new()),
+            verbatim: None,
+            comment: None,
+        });
+    }
+    Ok(fields)
+}
+
+/// Unquote a scalar value; plain values are returned trimmed
+fn parse_scalar(raw: &str, line_no: usize) -> Result<String, String> {
+    if let Some(inner) = raw.strip_prefix('"') {
+        let inner = inner
+            .strip_suffix('"')
+            .filter(|_| raw.len() > 1)
+            .ok_or_else(|| error(line_no, "unterminated double-quoted string"))?;
+        let mut value = String::new();
+        let mut chars = inner.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                value.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some(c @ ('"' | '\\' | '/')) => value.push(c),
+                _ => return Err(error(line_no, "invalid escape in double-quoted string")),
+            }
+        }
+        return Ok(value);
+    }
+    if let Some(inner) = raw.strip_prefix('\'') {
+        let inner = inner
+            .strip_suffix('\'')
+            .filter(|_| raw.len() > 1)
+            .ok_or_else(|| error(line_no, "unterminated single-quoted string"))?;
+        return Ok(inner.replace("''", "'"));
+    }
+    Ok(raw.trim().to_string())
+}
+
+/// Split a trailing ` # comment` off a value. A `#` only starts a comment at the start of the
+/// value or after whitespace, and not inside a quoted value or a quoted list item.
+fn split_comment(raw: &str) -> (&str, Option<&str>) {
+    let quotable = raw.starts_with(['"', '\'', '[']);
+    let mut quote = None;
+    let mut prev = ' ';
+    let mut chars = raw.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match (quote, c) {
+            (Some('"'), '\\') => {
+                chars.next();
+            }
+            (Some(q), _) if c == q => quote = None,
+            (None, '"' | '\'') if quotable => quote = Some(c),
+            (None, '#') if prev.is_whitespace() => {
+                return (raw[..i].trim_end(), Some(raw[i..].trim_end()));
+            }
+            _ => {}
+        }
+        prev = c;
+    }
+    (raw, None)
+}
+
+/// Split the inside of `[a, "b, c"]` at commas outside quotes
+fn split_inline_list(inner: &str) -> Result<Vec<&str>, &'static str> {
+    let mut items = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, ',') => {
+                items.push(&inner[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if quote.is_some() {
+        return Err("unterminated string in list");
+    }
+    items.push(&inner[start..]);
+    Ok(items.into_iter().filter(|item| !item.trim().is_empty()).collect())
+}
+
+fn order_fields(mut fields: Vec<Field>) -> Vec<Field> {
+    let mut ordered = Vec::new();
+    for key in KEY_ORDER {
+        if let Some(i) = fields.iter().position(|f| f.key == *key) {
+            ordered.push(fields.remove(i));
+        }
+    }
+    ordered.extend(fields);
+    ordered
+}
+
+fn render_field(field: &Field) -> Vec<String> {
+    let mut lines = field.comments.clone();
+    if field.key.is_empty() {
+        return lines;
+    }
+
+    match &field.value {
+        Value::Scalar(value) => {
+            let value = match &field.verbatim {
+                Some(raw) => raw.clone(),
+                None => quote_scalar(value),
+            };
+            if value.is_empty() {
+                lines.push(format!("{}:", field.key));
+            } else {
+                lines.push(format!("{}: {}", field.key, value));
+            }
+        }
+        Value::List(items) if items.is_empty() => lines.push(format!("{}: []", field.key)),
+        Value::List(items) => {
+            lines.push(format!("{}:", field.key));
+            lines.extend(items.iter().map(|item| format!("  - {}", quote_scalar(item))));
+        }
+        Value::Raw(header, block) => {
+            if header.is_empty() {
+                lines.push(format!("{}:", field.key));
+            } else {
+                lines.push(format!("{}: {}", field.key, header));
+            }
+            lines.extend(block.iter().cloned());
+        }
+    }
+    if let Some(comment) = &field.comment {
+        let key_line = &mut lines[field.comments.len()];
+        key_line.push(' ');
+        key_line.push_str(comment);
+    }
+    lines
+}
+
+/// Plain when that reads back as the same string, double-quoted otherwise
+fn quote_scalar(value: &str) -> String {
+    let ambiguous = matches!(
+        value.to_ascii_lowercase().as_str(),
+        "true" | "false" | "yes" | "no" | "on" | "off" | "null" | "~"
+    ) || value.parse::<f64>().is_ok();
+    let needs_quotes = value.is_empty()
+        || ambiguous
+        || value != value.trim()
+        || value.starts_with([
+            '-', '?', ':', ',', '[', ']', '{', '}', '#', '&', '*', '!', '|', '>', '\'', '"', '%',
+            '@', '`',
+        ])
+        || value.contains(": ")
+        || value.contains(" #")
+        || value.ends_with(':')
+        || value.contains(['\n', '\t']);
+    if !needs_quotes {
+        return value.to_string();
+    }
+
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t");
+    format!("\"{}\"", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(frontmatter: &str) -> Result<String, String> {
+        format_skill_frontmatter(&format!("---\n{}---\n# Body\n", frontmatter))
+    }
+
+    #[test]
+    fn known_keys_come_first_and_the_body_is_kept() {
+        let formatted = format("version: 2\ntags: [a, b]\nname: My Skill\n").unwrap();
+        assert_eq!(
+            formatted,
+            "---\nname: My Skill\ntags:\n  - a\n  - b\nversion: 2\n---\n# Body\n"
+        );
+    }
+
+    #[test]
+    fn ambiguous_values_are_quoted() {
+        let formatted = format("name: 'true'\ndescription: 'a: b'\nauthor: \"1.5\"\n").unwrap();
+        assert_eq!(
+            formatted,
+            "---\nname: \"true\"\ndescription: \"a: b\"\nauthor: \"1.5\"\n---\n# Body\n"
+        );
+    }
+
+    #[test]
+    fn comma_separated_tags_become_a_list() {
+        let formatted = format("tags: x, y\n").unwrap();
+        assert_eq!(formatted, "---\ntags:\n  - x\n  - y\n---\n# Body\n");
+    }
+
+    #[test]
+    fn trailing_comments_are_not_part_of_the_value() {
+        let formatted = format("description: Does X # note\nname: \"foo\" # quoted\n").unwrap();
+        assert_eq!(
+            formatted,
+            "---\nname: foo # quoted\ndescription: Does X # note\n---\n# Body\n"
+        );
+    }
+
+    #[test]
+    fn hashes_inside_values_are_kept() {
+        let formatted = format("name: \"C # notes\"\ndescription: Use C#\n").unwrap();
+        assert_eq!(
+            formatted,
+            "---\nname: \"C # notes\"\ndescription: Use C#\n---\n# Body\n"
+        );
+    }
+
+    #[test]
+    fn comments_on_lists_and_blocks_stay() {
+        let formatted = format("tags: [a] # one tag\nnotes: | # text\n  line\n").unwrap();
+        assert_eq!(
+            formatted,
+            "---\ntags: # one tag\n  - a\nnotes: | # text\n  line\n---\n# Body\n"
+        );
+
+        let items = "tags:\n  - a # first\n  - b\n";
+        assert_eq!(format(items).unwrap(), format!("---\n{}---\n# Body\n", items));
+    }
+
+    #[test]
+    fn comment_lines_move_with_their_key() {
+        let formatted = format("# who wrote it\nauthor: me\nname: x\n# end\n").unwrap();
+        assert_eq!(
+            formatted,
+            "---\nname: x\n# who wrote it\nauthor: me\n# end\n---\n# Body\n"
+        );
+    }
+
+    #[test]
+    fn crlf_documents_keep_crlf() {
+        let formatted = format_skill_frontmatter("---\r\nname: x\r\n---\r\nBody\r\n").unwrap();
+        assert_eq!(formatted, "---\r\nname: x\r\n---\r\nBody\r\n");
+    }
+
+    #[test]
+    fn invalid_frontmatter_reports_the_line() {
+        assert_eq!(
+            format("name: \"foo\n").unwrap_err(),
+            "frontmatter_error: line 2: unterminated double-quoted string"
+        );
+        assert_eq!(
+            format("name: a\nname: b\n").unwrap_err(),
+            "frontmatter_error: line 3: duplicate key `name`"
+        );
+        assert_eq!(
+            format("name: [a, b]\n").unwrap_err(),
+            "frontmatter_error: line 2: `name` must be a single value, not a list"
+        );
+        assert!(format_skill_frontmatter("# No frontmatter\n").is_err());
+        assert!(format_skill_frontmatter("---\nname: x\n").is_err());
+    }
+}
@@ -0,0 +1,41 @@
+// Locating the user's home directory, which tool skills dirs and ~/.skillhub hang off
+// dirs::home_dir() can come back empty for service accounts and in some sandboxes, so the
+// usual environment variables are tried before giving up
+
+use std::path::PathBuf;
+
+/// The user's home directory: the OS lookup first, then $HOME, then %USERPROFILE%.
+/// Fails with a "home_unavailable:" error saying how to fix it.
+pub(crate) fn home_dir() -> Result<PathBuf, String> {
+    dirs::home_dir()
+        .filter(|p| !p.as_os_str().is_empty())
+        .or_else(|| from_env("HOME"))
+        .or_else(|| from_env("USERPROFILE"))
+        .ok_or_else(|| {
+            "home_unavailable: Cannot find your home directory. Set HOME (USERPROFILE on Windows) \
+             to your user folder, or choose a folder to use instead"
+                .to_string()
+        })
+}
+
+/// An explicitly chosen base directory (standing in for the home directory) if given,
+/// otherwise the home directory
+pub(crate) fn base_dir_or_home(base_dir: Option<&str>) -> Result<PathBuf, String> {
+    let Some(dir) = base_dir.map(str::trim).filter(|d| !d.is_empty()) else {
+        return home_dir();
+    };
+    let path = PathBuf::from(dir);
+    if !path.is_absolute() {
+        return Err(format!("Base directory must be an absolute path: {}", dir));
+    }
+    if !path.is_dir() {
+        return Err(format!("Base directory does not exist: {}", dir));
+    }
+    Ok(path)
+}
+
+fn from_env(var: &str) -> Option<PathBuf> {
+    std::env::var_os(var)
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+}
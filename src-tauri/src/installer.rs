@@ -3,6 +3,8 @@
 
 use serde::{Deserialize, Serialize};
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use tokio::fs;
 
 // ============================================
@@ -36,6 +38,22 @@ pub struct ConfigStatus {
     pub api_key_preview: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigConflict {
+    pub kind: String, // "api_key_mismatch", "base_url_mismatch" or "orphaned_export"
+    pub variable: String, // e.g. "ANTHROPIC_API_KEY"
+    pub source: String,   // shell config file path, or "environment"
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShellInfo {
+    pub kind: String,        // file name of the shell, e.g. "zsh" or "bash"
+    pub path: String,
+    pub config_file: String, // the file SkillHub writes its exports to
+    pub source: String,      // "env" ($SHELL), "passwd" (login shell) or "default"
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InstallStep {
     pub id: String,
@@ -204,22 +222,40 @@ fn check_claude_code() -> DependencyInfo {
 // Config Detection
 // ============================================
 
+/// Mask an API key for display, logs and error messages (e.g. "sk-skillhub-...abcd").
+/// Short keys only keep their last 4 characters so they are never shown in full.
+pub fn redact(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    let tail: String = chars[chars.len().saturating_sub(4)..].iter().collect();
+
+    if chars.len() > 20 {
+        let head: String = chars[..12].iter().collect();
+        format!("{}...{}", head, tail)
+    } else if chars.len() > 8 {
+        format!("...{}", tail)
+    } else {
+        "****".to_string()
+    }
+}
+
+/// Replace every occurrence of an API key in a message with its redacted form
+fn redact_in(message: &str, key: &str) -> String {
+    if key.is_empty() {
+        message.to_string()
+    } else {
+        message.replace(key, &redact(key))
+    }
+}
+
 fn check_config() -> ConfigStatus {
     // First check our local config file (primary source)
     let local_config = read_skillhub_config();
     
-    if local_config.anthropic_api_key.is_some() {
-        let api_key = local_config.anthropic_api_key.clone().unwrap();
-        let api_key_preview = if api_key.len() > 20 {
-            Some(format!("{}...{}", &api_key[..12], &api_key[api_key.len()-4..]))
-        } else {
-            Some(api_key)
-        };
-        
+    if let Some(api_key) = &local_config.anthropic_api_key {
         return ConfigStatus {
-            base_url: local_config.anthropic_base_url,
+            base_url: local_config.anthropic_base_url.clone(),
             api_key_set: true,
-            api_key_preview,
+            api_key_preview: Some(redact(api_key)),
         };
     }
     
@@ -234,7 +270,7 @@ fn check_config() -> ConfigStatus {
 
 fn check_config_unix() -> ConfigStatus {
     // Check shell config files for ANTHROPIC_BASE_URL and ANTHROPIC_API_KEY
-    let home = dirs::home_dir();
+    let home = crate::home::home_dir().ok();
     if home.is_none() {
         return ConfigStatus {
             base_url: None,
@@ -294,13 +330,7 @@ fn check_config_unix() -> ConfigStatus {
     }
 
     let api_key_set = api_key.is_some();
-    let api_key_preview = api_key.map(|k| {
-        if k.len() > 20 {
-            format!("{}...{}", &k[..12], &k[k.len()-4..])
-        } else {
-            k
-        }
-    });
+    let api_key_preview = api_key.map(|k| redact(&k));
 
     ConfigStatus {
         base_url,
@@ -315,13 +345,7 @@ fn check_config_windows() -> ConfigStatus {
     let api_key = std::env::var("ANTHROPIC_API_KEY").ok();
 
     let api_key_set = api_key.is_some();
-    let api_key_preview = api_key.map(|k| {
-        if k.len() > 20 {
-            format!("{}...{}", &k[..12], &k[k.len()-4..])
-        } else {
-            k
-        }
-    });
+    let api_key_preview = api_key.map(|k| redact(&k));
 
     ConfigStatus {
         base_url,
@@ -330,6 +354,90 @@ fn check_config_windows() -> ConfigStatus {
     }
 }
 
+// Shell config files the Claude Code env vars are exported from (Unix)
+const SHELL_CONFIG_FILES: &[&str] = &[".zshrc", ".bashrc", ".bash_profile", ".profile"];
+const ANTHROPIC_ENV_VARS: &[&str] = &["ANTHROPIC_API_KEY", "ANTHROPIC_BASE_URL"];
+
+/// Value of the last `export NAME=value` line for a variable in a shell config file
+fn read_shell_export(content: &str, name: &str) -> Option<String> {
+    let prefix = format!("export {}=", name);
+    content
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix(&prefix))
+        .map(|value| value.trim_matches('"').trim_matches('\'').to_string())
+        .rfind(|value| !value.is_empty())
+}
+
+/// Compare the SkillHub config against the Anthropic variables exported in shell config
+/// files and set in the environment, reporting every place that disagrees with it.
+/// Key values are redacted in messages.
+pub fn detect_config_conflicts() -> Vec<ConfigConflict> {
+    let config = read_skillhub_config();
+
+    // (source, variable, value) for every place a variable is set
+    let mut found: Vec<(String, &str, String)> = Vec::new();
+    if get_platform() != "windows" {
+        if let Ok(home) = crate::home::home_dir() {
+            for file in SHELL_CONFIG_FILES {
+                let path = home.join(file);
+                let Ok(content) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+                for var in ANTHROPIC_ENV_VARS {
+                    if let Some(value) = read_shell_export(&content, var) {
+                        found.push((path.display().to_string(), var, value));
+                    }
+                }
+            }
+        }
+    }
+    for var in ANTHROPIC_ENV_VARS {
+        if let Ok(value) = std::env::var(var) {
+            if !value.is_empty() {
+                found.push(("environment".to_string(), var, value));
+            }
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    for (source, var, value) in found {
+        let is_key = var == "ANTHROPIC_API_KEY";
+        let expected = if is_key {
+            config.anthropic_api_key.as_deref()
+        } else {
+            config.anthropic_base_url.as_deref()
+        };
+        let shown = |v: &str| if is_key { redact(v) } else { v.to_string() };
+
+        let (kind, message) = match expected {
+            None => (
+                "orphaned_export",
+                format!("{} is set in {} but not in the SkillHub config", var, source),
+            ),
+            Some(expected) if expected != value => (
+                if is_key { "api_key_mismatch" } else { "base_url_mismatch" },
+                format!(
+                    "{} in {} is {} but SkillHub is configured with {}",
+                    var,
+                    source,
+                    shown(&value),
+                    shown(expected)
+                ),
+            ),
+            Some(_) => continue,
+        };
+
+        conflicts.push(ConfigConflict {
+            kind: kind.to_string(),
+            variable: var.to_string(),
+            source,
+            message,
+        });
+    }
+
+    conflicts
+}
+
 // ============================================
 // Main Check Function
 // ============================================
@@ -367,6 +475,81 @@ pub fn check_all_dependencies() -> DependencyStatus {
     }
 }
 
+// ============================================
+// Dependency Watching
+// ============================================
+
+const DEPENDENCY_WATCH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
+
+// Stop flag of the running dependency watcher, if any
+static DEPENDENCY_WATCHER: std::sync::Mutex<Option<Arc<AtomicBool>>> = std::sync::Mutex::new(None);
+
+/// The parts of a status whose change is worth telling the UI about (versions are not)
+fn dependency_fingerprint(status: &DependencyStatus) -> (bool, [bool; 4], bool, bool) {
+    (
+        status.all_ready,
+        [
+            status.package_manager.installed,
+            status.node.installed,
+            status.npm.installed,
+            status.claude_code.installed,
+        ],
+        status.config.api_key_set,
+        status.config.base_url.is_some(),
+    )
+}
+
+/// Re-check dependencies every few seconds in the background, calling `on_change` with the
+/// first status and then whenever readiness or any dependency's installed state changes.
+/// Starting a new watcher stops the previous one. Must be called from within the Tokio runtime.
+pub fn watch_dependencies<F>(on_change: F)
+where
+    F: Fn(&DependencyStatus) + Send + 'static,
+{
+    let stop = Arc::new(AtomicBool::new(false));
+    if let Ok(mut watcher) = DEPENDENCY_WATCHER.lock() {
+        if let Some(previous) = watcher.replace(stop.clone()) {
+            previous.store(true, Ordering::Relaxed);
+        }
+    }
+
+    tokio::spawn(async move {
+        let mut last = None;
+        while !stop.load(Ordering::Relaxed) {
+            // The checks run CLI commands, so keep them off the async workers
+            let status = match tokio::task::spawn_blocking(check_all_dependencies).await {
+                Ok(status) => status,
+                Err(e) => {
+                    tracing::warn!("Dependency check failed, stopping watcher: {}", e);
+                    break;
+                }
+            };
+
+            let fingerprint = dependency_fingerprint(&status);
+            if last != Some(fingerprint) && !stop.load(Ordering::Relaxed) {
+                on_change(&status);
+                last = Some(fingerprint);
+            }
+            tokio::time::sleep(DEPENDENCY_WATCH_INTERVAL).await;
+        }
+        tracing::debug!("Dependency watcher stopped");
+    });
+}
+
+/// Stop the dependency watcher; returns whether one was running
+pub fn stop_watching_dependencies() -> bool {
+    let Ok(mut watcher) = DEPENDENCY_WATCHER.lock() else {
+        return false;
+    };
+    match watcher.take() {
+        Some(stop) => {
+            stop.store(true, Ordering::Relaxed);
+            true
+        }
+        None => false,
+    }
+}
+
 // ============================================
 // Install Steps
 // ============================================
@@ -482,11 +665,19 @@ const SKILLHUB_BASE_URL: &str = "https://www.skillhub.club/api/v1/anthropic";
 pub struct SkillHubConfig {
     pub anthropic_base_url: Option<String>,
     pub anthropic_api_key: Option<String>,
+    pub close_behavior: Option<String>, // "tray" (default) or "quit"
+    #[serde(default)]
+    pub sync_ignore: Vec<String>, // gitignore-style patterns excluded from sync
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_tools: Vec<crate::tools::CustomTool>, // user-registered tools and path corrections
+    pub enabled_tools: Option<Vec<String>>, // None scans every supported tool
+    #[serde(default)]
+    pub offline_mode: bool, // never make network requests (air-gapped setups)
 }
 
 /// Get the SkillHub config directory path
-fn get_skillhub_config_dir() -> Result<std::path::PathBuf, String> {
-    let home = dirs::home_dir().ok_or("Cannot find home directory")?;
+pub(crate) fn get_skillhub_config_dir() -> Result<std::path::PathBuf, String> {
+    let home = crate::home::home_dir()?;
     Ok(home.join(".skillhub"))
 }
 
@@ -507,13 +698,98 @@ pub fn read_skillhub_config() -> SkillHubConfig {
     }
 
     match std::fs::read_to_string(&config_path) {
-        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            tracing::warn!("Config file {} is corrupt, using defaults: {}", config_path.display(), e);
+            SkillHubConfig::default()
+        }),
         Err(_) => SkillHubConfig::default(),
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigFileStatus {
+    pub status: String, // "missing", "valid" or "corrupt"
+    pub path: String,
+    pub error: Option<String>, // parse error with line and column, when corrupt
+}
+
+/// Check whether config.json exists and parses, without falling back to defaults
+pub fn validate_config_file() -> Result<ConfigFileStatus, String> {
+    let config_path = get_skillhub_config_path()?;
+    let status = |status: &str, error: Option<String>| ConfigFileStatus {
+        status: status.to_string(),
+        path: config_path.to_string_lossy().to_string(),
+        error,
+    };
+
+    let content = match std::fs::read_to_string(&config_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(status("missing", None)),
+        Err(e) => return Err(format!("Failed to read config: {}", e)),
+    };
+
+    Ok(match serde_json::from_str::<SkillHubConfig>(&content) {
+        Ok(_) => status("valid", None),
+        Err(e) => status("corrupt", Some(e.to_string())),
+    })
+}
+
+/// Replace a corrupt config.json with defaults, keeping the broken file as
+/// config.json.corrupt-<unix timestamp> so settings can be recovered by hand.
+/// Returns the backup path, or None if the config wasn't corrupt.
+pub async fn repair_config_file() -> Result<Option<String>, String> {
+    let _guard = lock_config().await;
+    if validate_config_file()?.status != "corrupt" {
+        return Ok(None);
+    }
+
+    let config_path = get_skillhub_config_path()?;
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let backup_path = config_path.with_file_name(format!("config.json.corrupt-{}", timestamp));
+
+    fs::rename(&config_path, &backup_path)
+        .await
+        .map_err(|e| format!("Failed to back up corrupt config: {}", e))?;
+    save_skillhub_config(&SkillHubConfig::default()).await?;
+
+    tracing::warn!("Reset corrupt config, previous file kept at {}", backup_path.display());
+    Ok(Some(backup_path.to_string_lossy().to_string()))
+}
+
+// Serializes config read-modify-write cycles so two settings changed at once don't lose an update
+static CONFIG_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Hold the config lock, for code that writes config.json without `update_skillhub_config`
+pub(crate) async fn lock_config() -> tokio::sync::MutexGuard<'static, ()> {
+    CONFIG_LOCK.lock().await
+}
+
+/// Load the config, apply `mutate` and save it, all under the config lock.
+/// Every config change goes through here.
+pub async fn update_skillhub_config<F>(mutate: F) -> Result<(), String>
+where
+    F: FnOnce(&mut SkillHubConfig),
+{
+    let _guard = lock_config().await;
+    // Saving over a corrupt file would silently replace the user's settings with defaults
+    if let Ok(ConfigFileStatus { status, error, .. }) = validate_config_file() {
+        if status == "corrupt" {
+            return Err(format!(
+                "Config file is corrupt ({}); repair it before changing settings",
+                error.unwrap_or_default()
+            ));
+        }
+    }
+    let mut config = read_skillhub_config();
+    mutate(&mut config);
+    save_skillhub_config(&config).await
+}
+
 /// Save SkillHub config to local file
-pub async fn save_skillhub_config(config: &SkillHubConfig) -> Result<(), String> {
+async fn save_skillhub_config(config: &SkillHubConfig) -> Result<(), String> {
     let config_dir = get_skillhub_config_dir()?;
     let config_path = get_skillhub_config_path()?;
 
@@ -532,9 +808,108 @@ pub async fn save_skillhub_config(config: &SkillHubConfig) -> Result<(), String>
         .await
         .map_err(|e| format!("Failed to write config: {}", e))?;
 
+    tracing::info!("Saved SkillHub config to {}", config_path.display());
+    Ok(())
+}
+
+/// Make sure the config file exists (writing defaults if absent) and open it in the OS default editor
+pub async fn open_config_file() -> Result<String, String> {
+    let config_path = get_skillhub_config_path()?;
+    if !config_path.exists() {
+        // Writes the defaults, since there is nothing to load
+        update_skillhub_config(|_| {}).await?;
+    }
+
+    let path = config_path.to_string_lossy().to_string();
+    crate::tools::open_folder_in_explorer(&path)?;
+    Ok(path)
+}
+
+/// Re-read the config file after external edits.
+/// Unlike `read_skillhub_config`, invalid JSON is reported instead of silently using defaults.
+/// The API key is redacted in the returned config.
+pub fn reload_config() -> Result<SkillHubConfig, String> {
+    let config_path = get_skillhub_config_path()?;
+    if !config_path.exists() {
+        return Ok(SkillHubConfig::default());
+    }
+
+    let content = std::fs::read_to_string(&config_path)
+        .map_err(|e| format!("Failed to read config: {}", e))?;
+    let mut config: SkillHubConfig = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse {}: {}", config_path.display(), e))?;
+
+    tracing::info!("Reloaded SkillHub config from {}", config_path.display());
+
+    config.anthropic_api_key = config.anthropic_api_key.as_deref().map(redact);
+    Ok(config)
+}
+
+/// Whether closing the main window should quit the app instead of hiding to tray
+pub fn should_quit_on_close() -> bool {
+    read_skillhub_config().close_behavior.as_deref() == Some("quit")
+}
+
+/// Set what happens when the main window is closed ("tray" or "quit")
+pub async fn set_close_behavior(behavior: &str) -> Result<(), String> {
+    if behavior != "tray" && behavior != "quit" {
+        return Err(format!("Invalid close behavior: {}", behavior));
+    }
+
+    update_skillhub_config(|config| config.close_behavior = Some(behavior.to_string())).await
+}
+
+/// Error returned by network commands while offline mode is on; the "offline:" prefix
+/// lets the UI tell it apart from a failed request and fall back to cached/local data
+const OFFLINE_ERROR: &str = "offline: Offline mode is enabled, network requests are disabled";
+
+/// Fail before making any request when offline mode is on
+pub fn ensure_online() -> Result<(), String> {
+    if is_offline_mode() {
+        return Err(OFFLINE_ERROR.to_string());
+    }
     Ok(())
 }
 
+/// Whether offline mode is on
+pub fn is_offline_mode() -> bool {
+    read_skillhub_config().offline_mode
+}
+
+/// Turn offline mode on or off
+pub async fn set_offline_mode(enabled: bool) -> Result<(), String> {
+    update_skillhub_config(|config| config.offline_mode = enabled).await
+}
+
+/// Get the user's sync ignore patterns
+pub fn get_sync_ignore() -> Vec<String> {
+    read_skillhub_config().sync_ignore
+}
+
+/// Replace the user's sync ignore patterns (gitignore-style, e.g. ".venv", "__pycache__/", "dist/")
+pub async fn set_sync_ignore(patterns: Vec<String>) -> Result<(), String> {
+    let patterns: Vec<String> = patterns
+        .into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    // Reject invalid globs up front instead of silently ignoring them at sync time
+    crate::sync::SyncIgnore::build(std::path::Path::new("."), &patterns)?;
+
+    update_skillhub_config(|config| config.sync_ignore = patterns).await
+}
+
+/// Get the tool ids detection is limited to (None means all tools)
+pub fn get_enabled_tools() -> Option<Vec<String>> {
+    read_skillhub_config().enabled_tools
+}
+
+/// Limit detection and scanning to the given tool ids, or pass None to scan every tool again
+pub async fn set_enabled_tools(tool_ids: Option<Vec<String>>) -> Result<(), String> {
+    update_skillhub_config(|config| config.enabled_tools = tool_ids).await
+}
+
 /// Get the Claude Code environment variables (for PTY spawn)
 pub fn get_claude_env_vars() -> Vec<(String, String)> {
     let config = read_skillhub_config();
@@ -550,13 +925,39 @@ pub fn get_claude_env_vars() -> Vec<(String, String)> {
     env_vars
 }
 
+// Process environment variables that affect how Claude Code runs
+const CLAUDE_ENV_PREFIXES: &[&str] = &["ANTHROPIC_", "CLAUDE_"];
+const CLAUDE_ENV_NAMES: &[&str] = &["PATH", "HOME", "SHELL", "HTTP_PROXY", "HTTPS_PROXY", "NO_PROXY"];
+
+/// The environment the PTY-launched Claude process gets: the relevant part of this process's
+/// environment with the SkillHub config values laid over it. Secrets are redacted.
+pub fn get_effective_claude_env() -> Vec<(String, String)> {
+    let mut env: std::collections::BTreeMap<String, String> = std::env::vars()
+        .filter(|(name, _)| {
+            let upper = name.to_uppercase();
+            CLAUDE_ENV_PREFIXES.iter().any(|p| upper.starts_with(p))
+                || CLAUDE_ENV_NAMES.contains(&upper.as_str())
+        })
+        .collect();
+    env.extend(get_claude_env_vars());
+
+    env.into_iter()
+        .map(|(name, value)| {
+            let upper = name.to_uppercase();
+            let secret = ["KEY", "TOKEN", "SECRET"].iter().any(|s| upper.contains(s));
+            let value = if secret { redact(&value) } else { value };
+            (name, value)
+        })
+        .collect()
+}
+
 pub async fn configure_claude_code(api_key: &str) -> Result<(), String> {
     // 1. Save to local config file (for immediate use)
-    let config = SkillHubConfig {
-        anthropic_base_url: Some(SKILLHUB_BASE_URL.to_string()),
-        anthropic_api_key: Some(api_key.to_string()),
-    };
-    save_skillhub_config(&config).await?;
+    update_skillhub_config(|config| {
+        config.anthropic_base_url = Some(SKILLHUB_BASE_URL.to_string());
+        config.anthropic_api_key = Some(api_key.to_string());
+    })
+    .await?;
 
     // 2. Also write to shell config (for terminal use)
     let platform = get_platform();
@@ -567,14 +968,67 @@ pub async fn configure_claude_code(api_key: &str) -> Result<(), String> {
     }
 }
 
-async fn configure_claude_code_unix(api_key: &str) -> Result<(), String> {
-    let home = dirs::home_dir().ok_or("Cannot find home directory")?;
-    
-    // Detect the user's shell and choose the appropriate config file
-    let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/zsh".to_string());
-    let config_file = if shell.contains("zsh") {
+/// The user's login shell from the password database, for when $SHELL isn't set
+/// (common for apps launched from the GUI)
+#[cfg(unix)]
+fn login_shell() -> Option<String> {
+    // getpwuid_r writes into our buffer, so it's safe to call from several threads at once;
+    // grow the buffer while it reports ERANGE
+    let mut buf_len = 1024;
+    while buf_len <= 1024 * 1024 {
+        let mut buf = vec![0 as libc::c_char; buf_len];
+        // SAFETY: passwd is plain data; getpwuid_r only fills it in and points its strings
+        // into buf, which outlives every read below
+        let mut pw: libc::passwd = unsafe { std::mem::zeroed() };
+        let mut found: *mut libc::passwd = std::ptr::null_mut();
+        let rc = unsafe {
+            libc::getpwuid_r(libc::getuid(), &mut pw, buf.as_mut_ptr(), buf.len(), &mut found)
+        };
+        if rc == libc::ERANGE {
+            buf_len *= 2;
+            continue;
+        }
+        if rc != 0 || found.is_null() || pw.pw_shell.is_null() {
+            return None;
+        }
+        // SAFETY: pw_shell is a NUL-terminated string inside buf
+        let shell = unsafe { std::ffi::CStr::from_ptr(pw.pw_shell) }
+            .to_string_lossy()
+            .to_string();
+        return Some(shell).filter(|s| !s.is_empty());
+    }
+    None
+}
+
+#[cfg(not(unix))]
+fn login_shell() -> Option<String> {
+    None
+}
+
+/// Work out the user's shell and the config file it reads: $SHELL first, then the login
+/// shell from the password database, then zsh as the macOS default
+pub fn detect_shell() -> Result<ShellInfo, String> {
+    if get_platform() == "windows" {
+        return Err("Shell detection is not supported on Windows".to_string());
+    }
+    let home = crate::home::home_dir()?;
+
+    let (path, source) = match std::env::var("SHELL").ok().filter(|s| !s.is_empty()) {
+        Some(shell) => (shell, "env"),
+        None => match login_shell() {
+            Some(shell) => (shell, "passwd"),
+            None => ("/bin/zsh".to_string(), "default"),
+        },
+    };
+
+    let kind = std::path::Path::new(&path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.clone());
+
+    let config_file = if kind.contains("zsh") {
         home.join(".zshrc")
-    } else if shell.contains("bash") {
+    } else if kind.contains("bash") {
         // On macOS, .bash_profile is preferred for login shells
         if cfg!(target_os = "macos") {
             home.join(".bash_profile")
@@ -585,40 +1039,140 @@ async fn configure_claude_code_unix(api_key: &str) -> Result<(), String> {
         home.join(".profile")
     };
 
-    // Read existing content
-    let existing_content = fs::read_to_string(&config_file)
-        .await
-        .unwrap_or_default();
+    Ok(ShellInfo {
+        kind,
+        path,
+        config_file: config_file.to_string_lossy().to_string(),
+        source: source.to_string(),
+    })
+}
 
+/// Comment line that marks the SkillHub block in the shell config
+const SHELL_CONFIG_MARKER: &str = "# SkillHub Claude Code Configuration";
+/// Trailing lines shown before and after in a config preview
+const PREVIEW_EXCERPT_LINES: usize = 8;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigDiff {
+    pub file: String, // shell config file, or "User environment variables" on Windows
+    pub before_excerpt: String,
+    pub after_excerpt: String,
+    pub lines_removed: Vec<String>,
+    pub lines_added: Vec<String>, // API keys are masked in every field
+}
+
+/// What configuring Claude Code does to a shell config: the new content plus the
+/// lines it drops and appends
+struct ShellConfigEdit {
+    content: String,
+    removed: Vec<String>,
+    added: Vec<String>,
+}
+
+fn edit_shell_config(existing_content: &str, api_key: &str) -> ShellConfigEdit {
     // Remove any existing ANTHROPIC_BASE_URL and ANTHROPIC_API_KEY lines
-    let filtered_lines: Vec<&str> = existing_content
-        .lines()
-        .filter(|line| {
+    let (removed, filtered_lines): (Vec<&str>, Vec<&str>) =
+        existing_content.lines().partition(|line| {
             let trimmed = line.trim();
-            !trimmed.starts_with("export ANTHROPIC_BASE_URL=")
-                && !trimmed.starts_with("export ANTHROPIC_API_KEY=")
-                && !trimmed.contains("# SkillHub Claude Code Configuration")
-        })
-        .collect();
+            trimmed.starts_with("export ANTHROPIC_BASE_URL=")
+                || trimmed.starts_with("export ANTHROPIC_API_KEY=")
+                || trimmed.contains(SHELL_CONFIG_MARKER)
+        });
 
     // Build new content
     let mut new_content = filtered_lines.join("\n");
-    
+
     // Ensure there's a newline at the end
     if !new_content.ends_with('\n') {
         new_content.push('\n');
     }
 
     // Add SkillHub configuration
-    new_content.push_str("\n# SkillHub Claude Code Configuration\n");
-    new_content.push_str(&format!("export ANTHROPIC_BASE_URL=\"{}\"\n", SKILLHUB_BASE_URL));
-    new_content.push_str(&format!("export ANTHROPIC_API_KEY=\"{}\"\n", api_key));
+    let added = vec![
+        String::new(),
+        SHELL_CONFIG_MARKER.to_string(),
+        format!("export ANTHROPIC_BASE_URL=\"{}\"", SKILLHUB_BASE_URL),
+        format!("export ANTHROPIC_API_KEY=\"{}\"", api_key),
+    ];
+    for line in &added {
+        new_content.push_str(line);
+        new_content.push('\n');
+    }
+
+    ShellConfigEdit {
+        content: new_content,
+        removed: removed.into_iter().map(|line| line.to_string()).collect(),
+        added: added.into_iter().filter(|line| !line.is_empty()).collect(),
+    }
+}
+
+/// Mask the value of an ANTHROPIC_API_KEY assignment in a config line
+fn redact_config_line(line: &str) -> String {
+    match line.split_once("ANTHROPIC_API_KEY=") {
+        Some((head, value)) => {
+            let key = value.trim().trim_matches(['"', '\'']);
+            format!("{}ANTHROPIC_API_KEY=\"{}\"", head, redact(key))
+        }
+        None => line.to_string(),
+    }
+}
+
+fn redacted_excerpt(content: &str) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    lines[lines.len().saturating_sub(PREVIEW_EXCERPT_LINES)..]
+        .iter()
+        .map(|line| redact_config_line(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Show what `configure_claude_code` would change, without writing anything
+pub async fn preview_claude_code_config(api_key: &str) -> Result<ConfigDiff, String> {
+    if get_platform() == "windows" {
+        return Ok(ConfigDiff {
+            file: "User environment variables".to_string(),
+            before_excerpt: String::new(),
+            after_excerpt: String::new(),
+            lines_removed: Vec::new(),
+            lines_added: vec![
+                format!("ANTHROPIC_BASE_URL={}", SKILLHUB_BASE_URL),
+                format!("ANTHROPIC_API_KEY={}", redact(api_key)),
+            ],
+        });
+    }
+
+    let shell = detect_shell()?;
+    let existing_content = fs::read_to_string(&shell.config_file)
+        .await
+        .unwrap_or_default();
+    let edit = edit_shell_config(&existing_content, api_key);
+
+    Ok(ConfigDiff {
+        file: shell.config_file,
+        before_excerpt: redacted_excerpt(&existing_content),
+        after_excerpt: redacted_excerpt(&edit.content),
+        lines_removed: edit.removed.iter().map(|l| redact_config_line(l)).collect(),
+        lines_added: edit.added.iter().map(|l| redact_config_line(l)).collect(),
+    })
+}
+
+async fn configure_claude_code_unix(api_key: &str) -> Result<(), String> {
+    // Write to the config file of the shell the user actually uses
+    let shell = detect_shell()?;
+    let config_file = std::path::PathBuf::from(&shell.config_file);
+
+    // Read existing content
+    let existing_content = fs::read_to_string(&config_file)
+        .await
+        .unwrap_or_default();
+    let edit = edit_shell_config(&existing_content, api_key);
 
     // Write back
-    fs::write(&config_file, new_content)
+    fs::write(&config_file, edit.content)
         .await
         .map_err(|e| format!("Failed to write config file: {}", e))?;
 
+    tracing::info!("Wrote Claude Code configuration to {}", config_file.display());
     Ok(())
 }
 
@@ -654,12 +1208,14 @@ async fn configure_claude_code_windows(api_key: &str) -> Result<(), String> {
         .map_err(|e| format!("Failed to set ANTHROPIC_API_KEY: {}", e))?;
 
     if !set_api_key.status.success() {
+        // PowerShell may echo the failing command, which contains the key
         return Err(format!(
             "Failed to set ANTHROPIC_API_KEY: {}",
-            String::from_utf8_lossy(&set_api_key.stderr)
+            redact_in(&String::from_utf8_lossy(&set_api_key.stderr), api_key)
         ));
     }
 
+    tracing::info!("Set Claude Code user environment variables");
     Ok(())
 }
 
@@ -674,7 +1230,7 @@ pub async fn remove_claude_code_config() -> Result<(), String> {
 }
 
 async fn remove_claude_code_config_unix() -> Result<(), String> {
-    let home = dirs::home_dir().ok_or("Cannot find home directory")?;
+    let home = crate::home::home_dir()?;
 
     // Check all common shell config files
     let config_files = vec![
@@ -700,7 +1256,7 @@ async fn remove_claude_code_config_unix() -> Result<(), String> {
                 let trimmed = line.trim();
                 !trimmed.starts_with("export ANTHROPIC_BASE_URL=")
                     && !trimmed.starts_with("export ANTHROPIC_API_KEY=")
-                    && !trimmed.contains("# SkillHub Claude Code Configuration")
+                    && !trimmed.contains(SHELL_CONFIG_MARKER)
             })
             .collect();
 
@@ -709,11 +1265,101 @@ async fn remove_claude_code_config_unix() -> Result<(), String> {
         fs::write(&config_file, new_content)
             .await
             .map_err(|e| format!("Failed to write config file: {}", e))?;
+
+        tracing::info!("Removed Claude Code configuration from {}", config_file.display());
     }
 
     Ok(())
 }
 
+/// Drop the SkillHub block from shell config content: the marker line, the ANTHROPIC_*
+/// exports right after it and the blank line before it. None if there is no block.
+fn strip_skillhub_block(content: &str) -> Option<String> {
+    let mut kept: Vec<&str> = Vec::new();
+    let mut in_block = false;
+    let mut found = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed == SHELL_CONFIG_MARKER {
+            if kept.last().is_some_and(|l| l.trim().is_empty()) {
+                kept.pop();
+            }
+            in_block = true;
+            found = true;
+            continue;
+        }
+        if in_block
+            && (trimmed.starts_with("export ANTHROPIC_BASE_URL=")
+                || trimmed.starts_with("export ANTHROPIC_API_KEY="))
+        {
+            continue;
+        }
+        in_block = false;
+        kept.push(line);
+    }
+    if !found {
+        return None;
+    }
+
+    let mut new_content = kept.join("\n");
+    if content.ends_with('\n') && !new_content.is_empty() {
+        new_content.push('\n');
+    }
+    Some(new_content)
+}
+
+/// Undo exactly what `configure_claude_code` set up: the API values in the SkillHub config,
+/// the SkillHub block in shell configs, and on Windows the user environment variables while
+/// they still point at SkillHub. ANTHROPIC_* settings the user made themselves are kept.
+/// Returns a description of each thing removed.
+pub async fn reset_claude_code_config() -> Result<Vec<String>, String> {
+    let mut removed = Vec::new();
+
+    let config = read_skillhub_config();
+    if config.anthropic_base_url.is_some() || config.anthropic_api_key.is_some() {
+        update_skillhub_config(|config| {
+            config.anthropic_base_url = None;
+            config.anthropic_api_key = None;
+        })
+        .await?;
+        removed.push(format!(
+            "Claude Code API settings in {}",
+            get_skillhub_config_path()?.display()
+        ));
+    }
+
+    if get_platform() == "windows" {
+        let output = Command::new("powershell")
+            .args([
+                "-Command",
+                "[Environment]::GetEnvironmentVariable('ANTHROPIC_BASE_URL', 'User')",
+            ])
+            .output()
+            .map_err(|e| format!("Failed to read ANTHROPIC_BASE_URL: {}", e))?;
+        if String::from_utf8_lossy(&output.stdout).trim() == SKILLHUB_BASE_URL {
+            remove_claude_code_config_windows().await?;
+            removed.push("User environment variables ANTHROPIC_BASE_URL and ANTHROPIC_API_KEY".to_string());
+        }
+        return Ok(removed);
+    }
+
+    let home = crate::home::home_dir()?;
+    for config_file in SHELL_CONFIG_FILES.iter().map(|f| home.join(f)) {
+        let Ok(content) = fs::read_to_string(&config_file).await else {
+            continue;
+        };
+        let Some(new_content) = strip_skillhub_block(&content) else {
+            continue;
+        };
+        fs::write(&config_file, new_content)
+            .await
+            .map_err(|e| format!("Failed to write config file: {}", e))?;
+        removed.push(format!("SkillHub block in {}", config_file.display()));
+    }
+
+    Ok(removed)
+}
+
 async fn remove_claude_code_config_windows() -> Result<(), String> {
     // Remove user environment variables
     let remove_base_url = Command::new("powershell")
@@ -753,14 +1399,18 @@ async fn remove_claude_code_config_windows() -> Result<(), String> {
 // API Key Validation
 // ============================================
 
+/// `valid` means the key authenticates; `usable` means it can also make billed requests.
+/// A key with no balance (HTTP 402) is valid but not usable.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiKeyValidationResult {
     pub valid: bool,
+    pub usable: bool,
     pub error_code: Option<String>,  // "invalid_key", "insufficient_balance", etc.
     pub message: Option<String>,
 }
 
 pub async fn validate_api_key(api_key: &str) -> Result<ApiKeyValidationResult, String> {
+    ensure_online()?;
     // Make a simple request to SkillHub API to validate the key
     let client = reqwest::Client::new();
     
@@ -770,38 +1420,47 @@ pub async fn validate_api_key(api_key: &str) -> Result<ApiKeyValidationResult, S
         .header("anthropic-version", "2023-06-01")
         .send()
         .await
-        .map_err(|e| format!("Failed to validate API key: {}", e))?;
+        .map_err(|e| redact_in(&format!("Failed to validate API key: {}", e), api_key))?;
 
     let status = response.status().as_u16();
-    
+    // Only an unexpected status needs the body for its message
+    let body = if matches!(status, 200 | 401 | 402) {
+        String::new()
+    } else {
+        response.text().await.unwrap_or_default()
+    };
+    Ok(validation_result(status, &body, api_key))
+}
+
+/// Map the status of the validation request to a result. `body` is only used for unexpected
+/// statuses, with the key redacted from it.
+fn validation_result(status: u16, body: &str, api_key: &str) -> ApiKeyValidationResult {
     match status {
-        200 => Ok(ApiKeyValidationResult {
+        200 => ApiKeyValidationResult {
             valid: true,
+            usable: true,
             error_code: None,
             message: None,
-        }),
-        401 => Ok(ApiKeyValidationResult {
+        },
+        401 => ApiKeyValidationResult {
             valid: false,
+            usable: false,
             error_code: Some("invalid_key".to_string()),
             message: Some("Invalid API key".to_string()),
-        }),
-        402 => {
-            // Payment required - key is valid but insufficient balance
-            Ok(ApiKeyValidationResult {
-                valid: true,  // Key is valid, just no balance
-                error_code: Some("insufficient_balance".to_string()),
-                message: Some("API key is valid but your balance is insufficient. Please top up your wallet.".to_string()),
-            })
         },
-        _ => {
-            // Try to get error message from response body
-            let body = response.text().await.unwrap_or_default();
-            Ok(ApiKeyValidationResult {
-                valid: false,
-                error_code: Some(format!("http_{}", status)),
-                message: Some(format!("Validation failed: {}", body)),
-            })
-        }
+        // Payment required - key is valid but insufficient balance
+        402 => ApiKeyValidationResult {
+            valid: true, // Key is valid, just no balance
+            usable: false,
+            error_code: Some("insufficient_balance".to_string()),
+            message: Some("API key is valid but your balance is insufficient. Please top up your wallet.".to_string()),
+        },
+        _ => ApiKeyValidationResult {
+            valid: false,
+            usable: false,
+            error_code: Some(format!("http_{}", status)),
+            message: Some(redact_in(&format!("Validation failed: {}", body), api_key)),
+        },
     }
 }
 
@@ -877,3 +1536,57 @@ pub fn get_manual_install_instructions(step_id: &str) -> ManualInstallInstructio
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_KEY: &str = "sk-skillhub-0123456789abcdefghijklmnop";
+
+    #[test]
+    fn status_200_is_valid_and_usable() {
+        let result = validation_result(200, "", SAMPLE_KEY);
+        assert!(result.valid && result.usable);
+        assert_eq!(result.error_code, None);
+    }
+
+    #[test]
+    fn status_401_is_an_invalid_key() {
+        let result = validation_result(401, "", SAMPLE_KEY);
+        assert!(!result.valid && !result.usable);
+        assert_eq!(result.error_code.as_deref(), Some("invalid_key"));
+    }
+
+    #[test]
+    fn status_402_is_valid_but_not_usable() {
+        let result = validation_result(402, "", SAMPLE_KEY);
+        assert!(result.valid && !result.usable);
+        assert_eq!(result.error_code.as_deref(), Some("insufficient_balance"));
+        assert!(result.message.is_some());
+    }
+
+    #[test]
+    fn other_statuses_report_the_redacted_body() {
+        let body = format!("{{\"error\": \"bad key {}\"}}", SAMPLE_KEY);
+        let result = validation_result(500, &body, SAMPLE_KEY);
+        assert!(!result.valid && !result.usable);
+        assert_eq!(result.error_code.as_deref(), Some("http_500"));
+        let message = result.message.unwrap();
+        assert!(message.starts_with("Validation failed: "));
+        assert!(!message.contains(SAMPLE_KEY));
+    }
+
+    #[test]
+    fn api_key_never_appears_verbatim_in_errors() {
+        let error = redact_in(
+            &format!("Failed to validate API key: invalid header value {:?}", SAMPLE_KEY),
+            SAMPLE_KEY,
+        );
+        assert!(!error.contains(SAMPLE_KEY));
+        assert!(error.contains(&redact(SAMPLE_KEY)));
+
+        assert!(!redact(SAMPLE_KEY).contains(SAMPLE_KEY));
+        assert!(redact(SAMPLE_KEY).ends_with("mnop"));
+        assert_eq!(redact("short"), "****");
+    }
+}
@@ -0,0 +1,121 @@
+// Typed KOL (featured author) responses from the SkillHub API
+// The API uses camelCase; the app-facing types are snake_case like the rest of the commands
+
+use serde::{Deserialize, Serialize};
+
+use crate::SkillHubSkill;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Kol {
+    #[serde(default)]
+    pub id: String,
+    #[serde(alias = "githubUsername")]
+    pub username: String,
+    #[serde(default, alias = "displayName")]
+    pub display_name: String,
+    #[serde(default, alias = "avatarUrl")]
+    pub avatar_url: Option<String>,
+    #[serde(default, alias = "githubFollowers")]
+    pub followers: u64,
+    #[serde(default, alias = "skillCount")]
+    pub skills_count: u64,
+    #[serde(default)]
+    pub bio: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KolPagination {
+    #[serde(default)]
+    pub total: u64,
+    #[serde(default)]
+    pub limit: u64,
+    #[serde(default)]
+    pub offset: u64,
+    #[serde(default, alias = "hasMore")]
+    pub has_more: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KolList {
+    #[serde(default)]
+    pub kols: Vec<Kol>,
+    #[serde(default)]
+    pub pagination: KolPagination,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KolWithSkills {
+    pub kol: Kol,
+    pub total_stars: Option<u64>,
+    pub skills: Vec<SkillHubSkill>,
+    pub pagination: KolPagination,
+}
+
+/// KOL detail as returned by the API, the user object nests its counts under `stats`
+#[derive(Debug, Deserialize)]
+struct KolDetailResponse {
+    user: KolDetailUser,
+    #[serde(default)]
+    skills: Vec<SkillHubSkill>,
+    #[serde(default)]
+    pagination: KolPagination,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KolDetailUser {
+    #[serde(default)]
+    id: String,
+    github_username: String,
+    #[serde(default)]
+    display_name: String,
+    #[serde(default)]
+    avatar_url: Option<String>,
+    #[serde(default)]
+    bio: Option<String>,
+    #[serde(default)]
+    github_followers: u64,
+    #[serde(default)]
+    stats: Option<KolStats>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KolStats {
+    #[serde(default)]
+    total_skills: u64,
+    #[serde(default)]
+    total_stars: Option<u64>,
+}
+
+/// Parse a KOL list response
+pub fn parse_kol_list(data: serde_json::Value) -> Result<KolList, String> {
+    serde_json::from_value(data).map_err(|e| format!("Unexpected KOL list response: {}", e))
+}
+
+/// Parse a KOL detail response into the KOL and their skills
+pub fn parse_kol_detail(data: serde_json::Value) -> Result<KolWithSkills, String> {
+    let detail: KolDetailResponse = serde_json::from_value(data)
+        .map_err(|e| format!("Unexpected KOL detail response: {}", e))?;
+
+    let user = detail.user;
+    let (skills_count, total_stars) = match user.stats {
+        Some(stats) => (stats.total_skills, stats.total_stars),
+        None => (detail.pagination.total, None),
+    };
+
+    Ok(KolWithSkills {
+        kol: Kol {
+            id: user.id,
+            username: user.github_username,
+            display_name: user.display_name,
+            avatar_url: user.avatar_url,
+            followers: user.github_followers,
+            skills_count,
+            bio: user.bio,
+        },
+        total_stars,
+        skills: detail.skills,
+        pagination: detail.pagination,
+    })
+}
@@ -1,13 +1,39 @@
+mod backup;
+mod bundle;
+mod cache;
+mod catalog_cache;
+mod categories;
+mod dashboard;
+mod diagnostics;
+mod diff;
+mod disk;
+mod frontmatter;
+mod home;
 mod installer;
+mod kol;
+mod library;
+mod logging;
+mod merge;
+mod normalize;
+mod preflight;
+mod preview;
+mod projects;
+mod repo_skills;
+mod skill_backups;
+mod skill_stats;
+mod skill_update;
+mod snapshot;
 mod sync;
+#[cfg(test)]
+mod test_util;
 mod tools;
+mod tray;
+mod updater;
+mod verify;
+mod window_state;
 
 use serde::{Deserialize, Serialize};
-use tauri::{
-    menu::{Menu, MenuItem},
-    tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
-    Manager,
-};
+use tauri::{Emitter, Manager};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DetectedTool {
@@ -17,6 +43,17 @@ pub struct DetectedTool {
     pub skills_path: String,
     pub installed: bool,
     pub skills_count: usize,
+    pub version: Option<String>, // from the tool's CLI, None if it has none
+    pub enabled: bool,           // disabled tools are listed but not scanned
+    pub error: Option<String>, // e.g. the config path exists but is not a directory
+    pub supports_personal: bool, // false for project-only tools like Windsurf
+    pub supports_project: bool,
+    #[serde(default = "default_tool_scope")]
+    pub scope: String, // "personal" (home directory) or "project"
+}
+
+fn default_tool_scope() -> String {
+    "personal".to_string()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +63,9 @@ pub struct InstalledSkill {
     pub description: Option<String>,
     pub author: Option<String>,
     pub tool_id: String,
+    pub modified_at: Option<u64>, // Unix timestamp (seconds) of the last SKILL.md change
+    #[serde(default)]
+    pub linked: bool, // true when the skill folder is a symlink/junction to a shared copy
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,81 +73,480 @@ pub struct SkillHubSkill {
     pub id: String,
     pub name: String,
     pub slug: String,
+    #[serde(default)]
     pub description: String,
+    #[serde(default)]
     pub author: String,
+    #[serde(default)]
     pub category: String,
     pub simple_score: Option<f64>,
     pub simple_rating: Option<String>,
     pub github_stars: Option<i32>,
+    #[serde(default)]
     pub repo_url: String,
 }
 
 // Detect all supported AI coding tools
+// base_dir stands in for the home directory when it can't be found
 #[tauri::command]
-async fn detect_tools() -> Result<Vec<DetectedTool>, String> {
-    tools::detect_all_tools().await
+#[tracing::instrument(err)]
+async fn detect_tools(base_dir: Option<String>) -> Result<Vec<DetectedTool>, String> {
+    tools::detect_all_tools(base_dir.as_deref()).await
+}
+
+// List all supported tools and their expected layout, without scanning the disk
+#[tauri::command]
+#[tracing::instrument]
+fn list_supported_tools() -> Vec<tools::ToolInfo> {
+    tools::list_supported_tools()
+}
+
+// Register a tool SkillHub doesn't know, or correct a built-in tool's directories (same id)
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn register_custom_tool(tool: tools::CustomTool) -> Result<tools::ToolInfo, String> {
+    tools::register_custom_tool(tool).await
+}
+
+// Remove a registered custom tool
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn remove_custom_tool(tool_id: String) -> Result<(), String> {
+    tools::remove_custom_tool(&tool_id).await
 }
 
 // Get installed skills for a specific tool
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn get_installed_skills(tool_id: String) -> Result<Vec<InstalledSkill>, String> {
     tools::get_skills_for_tool(&tool_id).await
 }
 
+// One sorted page of installed skills, so the library view doesn't load them all
+// tool_ids: empty for all enabled tools; sort_by: "name", "modified_at" or "size"
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn list_installed_skills_paged(
+    tool_ids: Vec<String>,
+    sort_by: String,
+    page: usize,
+    limit: usize,
+) -> Result<tools::InstalledSkillsPage, String> {
+    tools::list_installed_skills_paged(&tool_ids, &sort_by, page, limit).await
+}
+
 // Install a skill from SkillHub to a specific tool
+// base_dir stands in for the home directory when it can't be found
 #[tauri::command]
+#[tracing::instrument(skip(app, skill_content), err)]
 async fn install_skill(
+    app: tauri::AppHandle,
     skill_content: String,
     skill_name: String,
     tool_ids: Vec<String>,
-) -> Result<Vec<String>, String> {
-    tools::install_skill_to_tools(&skill_content, &skill_name, &tool_ids).await
+    folder_name: Option<String>,
+    expected_sha256: Option<String>,
+    base_dir: Option<String>,
+) -> Result<Vec<tools::InstallResult>, String> {
+    let results = tools::install_skill_to_tools(
+        &skill_content,
+        &skill_name,
+        &tool_ids,
+        folder_name.as_deref(),
+        expected_sha256.as_deref(),
+        base_dir.as_deref(),
+    )
+    .await?;
+    notify_skills_changed(&app);
+    Ok(results)
 }
 
 // Install multiple files for a skill (supports multi-file skills from GitHub)
+// skill_md_only installs just the SKILL.md and is recorded in each copy's .skillhub.json,
+// which is created when skill_id names the catalog skill
 #[tauri::command]
+#[tracing::instrument(skip(app, files), err)]
+#[allow(clippy::too_many_arguments)] // each argument is a named field of the invoke payload
 async fn install_skill_files(
+    app: tauri::AppHandle,
     files: Vec<(String, String)>,
     skill_name: String,
     tool_ids: Vec<String>,
-) -> Result<Vec<String>, String> {
-    tools::install_skill_files_to_tools(&files, &skill_name, &tool_ids).await
+    folder_name: Option<String>,
+    expected_hashes: Option<std::collections::HashMap<String, String>>,
+    skill_md_only: Option<bool>,
+    skill_id: Option<String>,
+) -> Result<tools::SkillFilesInstallResult, String> {
+    let skill_md_only = skill_md_only.unwrap_or(false);
+    let (files, skipped_files) = if skill_md_only {
+        tools::skill_md_only_files(&files)?
+    } else {
+        (files, Vec::new())
+    };
+    let installed = tools::install_skill_files_to_tools(
+        &files,
+        &skill_name,
+        &tool_ids,
+        folder_name.as_deref(),
+        &expected_hashes.unwrap_or_default(),
+    )
+    .await?;
+    let catalog_files = catalog_paths(&files);
+    for path in &installed.installed_paths {
+        sync::record_install(
+            path,
+            skill_id.as_deref(),
+            &skill_name,
+            skill_md_only,
+            catalog_files.clone(),
+        )
+        .await?;
+    }
+    notify_skills_changed(&app);
+    Ok(tools::SkillFilesInstallResult::new(installed, skipped_files))
+}
+
+// Installed file paths as .skillhub.json records them in catalog_files
+fn catalog_paths(files: &[(String, String)]) -> Vec<String> {
+    let mut paths: Vec<String> = files
+        .iter()
+        .map(|(path, _)| verify::normalize_catalog_path(path))
+        .collect();
+    paths.sort();
+    paths
+}
+
+// Install a skill into several tools as links to one shared folder instead of copies
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn install_skill_symlinked(
+    app: tauri::AppHandle,
+    canonical_path: String,
+    tool_ids: Vec<String>,
+) -> Result<Vec<tools::LinkedInstallResult>, String> {
+    let results = tools::install_skill_symlinked(&canonical_path, &tool_ids).await?;
+    notify_skills_changed(&app);
+    Ok(results)
 }
 
-// Install a skill to a specific project directory
+// Copy personal skills of a tool into a project (all of them when slugs is empty)
+// on_conflict: "error", "skip", "overwrite" or "merge"
 #[tauri::command]
+#[tracing::instrument(err)]
+async fn copy_personal_skills_to_project(
+    tool_id: String,
+    project_path: String,
+    slugs: Vec<String>,
+    on_conflict: String,
+) -> Result<Vec<tools::ProjectSkillCopy>, String> {
+    let policy = tools::parse_conflict_policy(&on_conflict)?;
+    tools::copy_personal_skills_to_project(&tool_id, &project_path, &slugs, policy).await
+}
+
+// Install a multi-file skill into any directory the user picks
+// on_conflict: "error", "skip", "overwrite" or "merge"
+#[tauri::command]
+#[tracing::instrument(skip(files), err)]
+async fn install_skill_to_dir(
+    files: Vec<(String, String)>,
+    skill_name: String,
+    dest_dir: String,
+    on_conflict: String,
+    folder_name: Option<String>,
+) -> Result<String, String> {
+    let policy = tools::parse_conflict_policy(&on_conflict)?;
+    tools::install_skill_to_dir(&files, &skill_name, &dest_dir, folder_name.as_deref(), policy).await
+}
+
+// Skill counts and disk usage across all enabled tools, for the dashboard summary card
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn get_dashboard_stats() -> Result<dashboard::DashboardStats, String> {
+    dashboard::get_dashboard_stats().await
+}
+
+// How many catalog-installed skills have updates; slow, so kept out of get_dashboard_stats
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn get_dashboard_update_stats() -> Result<dashboard::UpdateStats, String> {
+    dashboard::get_dashboard_update_stats().await
+}
+
+// Install several catalog skills at once, emitting batch-install-progress after each one
+#[tauri::command]
+#[tracing::instrument(skip(app, skills))]
+async fn install_skills_batch(
+    app: tauri::AppHandle,
+    skills: Vec<tools::BatchSkill>,
+    tool_ids: Vec<String>,
+) -> Vec<tools::BatchInstallResult> {
+    let results = tools::install_skills_batch(&skills, &tool_ids, |progress| {
+        let _ = app.emit("batch-install-progress", progress);
+    })
+    .await;
+    notify_skills_changed(&app);
+    results
+}
+
+// Install a skill to a specific project directory, optionally adding it to the project's .gitignore
+#[tauri::command]
+#[tracing::instrument(skip(skill_content), err)]
 async fn install_skill_to_project(
     skill_content: String,
     skill_name: String,
     project_path: String,
     tool_id: String,
-) -> Result<String, String> {
-    tools::install_skill_to_project(&skill_content, &skill_name, &project_path, &tool_id).await
+    gitignore: Option<bool>,
+    folder_name: Option<String>,
+    expected_sha256: Option<String>,
+) -> Result<projects::ProjectInstallResult, String> {
+    let project_path = tools::resolve_path(&project_path)?;
+    let (path, verified) = tools::install_skill_to_project(
+        &skill_content,
+        &skill_name,
+        &project_path,
+        &tool_id,
+        folder_name.as_deref(),
+        expected_sha256.as_deref(),
+    )
+    .await?;
+    projects::record_project(&project_path).await;
+
+    let gitignore_updated = if gitignore.unwrap_or(false) {
+        projects::add_to_gitignore(&project_path, &path).await?
+    } else {
+        false
+    };
+    Ok(projects::ProjectInstallResult {
+        path,
+        gitignore_updated,
+        skipped_files: Vec::new(),
+        verified,
+        unverified_files: if verified { Vec::new() } else { vec!["SKILL.md".to_string()] },
+    })
 }
 
 // Install multiple files for a skill to a specific project directory
 #[tauri::command]
+#[tracing::instrument(skip(files), err)]
+#[allow(clippy::too_many_arguments)] // each argument is a named field of the invoke payload
 async fn install_skill_files_to_project(
     files: Vec<(String, String)>,
     skill_name: String,
     project_path: String,
     tool_id: String,
+    gitignore: Option<bool>,
+    skill_md_only: Option<bool>,
+    skill_id: Option<String>,
+    folder_name: Option<String>,
+    expected_hashes: Option<std::collections::HashMap<String, String>>,
+) -> Result<projects::ProjectInstallResult, String> {
+    let project_path = tools::resolve_path(&project_path)?;
+    let skill_md_only = skill_md_only.unwrap_or(false);
+    let (files, skipped_files) = if skill_md_only {
+        tools::skill_md_only_files(&files)?
+    } else {
+        (files, Vec::new())
+    };
+    let (path, unverified_files) = tools::install_skill_files_to_project(
+        &files,
+        &skill_name,
+        &project_path,
+        &tool_id,
+        folder_name.as_deref(),
+        &expected_hashes.unwrap_or_default(),
+    )
+    .await?;
+    sync::record_install(
+        &path,
+        skill_id.as_deref(),
+        &skill_name,
+        skill_md_only,
+        catalog_paths(&files),
+    )
+    .await?;
+    projects::record_project(&project_path).await;
+
+    let gitignore_updated = if gitignore.unwrap_or(false) {
+        projects::add_to_gitignore(&project_path, &path).await?
+    } else {
+        false
+    };
+    Ok(projects::ProjectInstallResult {
+        path,
+        gitignore_updated,
+        skipped_files,
+        verified: unverified_files.is_empty(),
+        unverified_files,
+    })
+}
+
+// Which tools a project is set up for (tool config dirs present in it), scope "project"
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn detect_project_tools(project_path: String) -> Result<Vec<DetectedTool>, String> {
+    tools::detect_project_tools(&project_path).await
+}
+
+// Dangling symlinks in a tool's skills directories (e.g. linked installs whose target is gone)
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn find_broken_symlinks(tool_id: String) -> Result<Vec<String>, String> {
+    tools::find_broken_symlinks(&tool_id).await
+}
+
+// Remove dangling symlinks; paths that aren't broken links are skipped
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn remove_broken_symlinks(paths: Vec<String>) -> Result<Vec<String>, String> {
+    tools::remove_broken_symlinks(&paths).await
+}
+
+// Store a skill duplicated across tools once: identical copies become links to the copy
+// in keep_tool_id (or fresh copies of it where links aren't possible)
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn consolidate_duplicate_skill(
+    app: tauri::AppHandle,
+    slug: String,
+    keep_tool_id: String,
+) -> Result<tools::ConsolidationReport, String> {
+    let report = tools::consolidate_duplicate_skill(&slug, &keep_tool_id).await?;
+    if !report.linked.is_empty() || !report.copied.is_empty() {
+        notify_skills_changed(&app);
+    }
+    Ok(report)
+}
+
+// Move a tool's skills directory elsewhere, linking the standard path to the new location
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn relocate_tool_skills(
+    app: tauri::AppHandle,
+    tool_id: String,
+    new_dir: String,
+    move_existing: bool,
 ) -> Result<String, String> {
-    tools::install_skill_files_to_project(&files, &skill_name, &project_path, &tool_id).await
+    let resolved = tools::relocate_tool_skills(&tool_id, &new_dir, move_existing).await?;
+    notify_skills_changed(&app);
+    Ok(resolved)
+}
+
+// Find skills in a tool's legacy location (e.g. ~/.claude/skills for Copilot) and,
+// with apply, move them to its recommended skills directory
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn migrate_legacy_skills(
+    app: tauri::AppHandle,
+    tool_id: String,
+    apply: bool,
+) -> Result<tools::MigrationPlan, String> {
+    let plan = tools::migrate_legacy_skills(&tool_id, apply).await?;
+    if !plan.migrated.is_empty() {
+        notify_skills_changed(&app);
+    }
+    Ok(plan)
+}
+
+// Where a skill would be installed for each tool, for "will install to ..." previews
+// scope: "personal" or "project" (with project_path); skill_content and folder_name
+// name the folder exactly as the install will
+#[tauri::command]
+#[tracing::instrument(skip(skill_content), err)]
+fn resolve_install_paths(
+    skill_name: String,
+    skill_content: Option<String>,
+    folder_name: Option<String>,
+    tool_ids: Vec<String>,
+    scope: String,
+    project_path: Option<String>,
+) -> Result<Vec<(String, String)>, String> {
+    tools::resolve_install_paths(
+        &skill_name,
+        skill_content.as_deref(),
+        folder_name.as_deref(),
+        &tool_ids,
+        &scope,
+        project_path.as_deref(),
+    )
+}
+
+// Expand ~ and canonicalize a user-supplied directory path
+#[tauri::command]
+#[tracing::instrument(err)]
+fn resolve_path(input: String) -> Result<String, String> {
+    tools::resolve_path(&input)
+}
+
+// List project directories skills have been installed into
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn list_skill_projects() -> Result<Vec<projects::SkillProject>, String> {
+    projects::list_skill_projects().await
+}
+
+// Remove a project from the remembered list (files are left untouched)
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn remove_skill_project(project_path: String) -> Result<(), String> {
+    projects::remove_skill_project(&project_path).await
+}
+
+// Branch and uncommitted changes of a project, to warn before installing into it
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn get_project_git_info(project_path: String) -> Result<projects::GitInfo, String> {
+    projects::get_project_git_info(&project_path).await
 }
 
 // Uninstall a skill from a specific tool
 #[tauri::command]
-async fn uninstall_skill(skill_path: String) -> Result<(), String> {
-    tools::uninstall_skill(&skill_path).await
+#[tracing::instrument(skip(app), err)]
+async fn uninstall_skill(app: tauri::AppHandle, skill_path: String) -> Result<(), String> {
+    tools::uninstall_skill(&skill_path).await?;
+    notify_skills_changed(&app);
+    Ok(())
+}
+
+// Tell listeners (the tray menu) that the installed skills changed
+fn notify_skills_changed(app: &tauri::AppHandle) {
+    let _ = app.emit("skills-changed", ());
+}
+
+// Uninstall every skill by an author (matched case-insensitively on the frontmatter author)
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn uninstall_skills_by_author(
+    app: tauri::AppHandle,
+    author: String,
+    tool_ids: Vec<String>,
+) -> Result<Vec<tools::UninstallResult>, String> {
+    let results = tools::uninstall_skills_by_author(&author, &tool_ids).await?;
+    notify_skills_changed(&app);
+    Ok(results)
 }
 
 // Read skill content from path (for syncing)
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn read_skill_content(skill_path: String) -> Result<String, String> {
     tools::read_skill_content(&skill_path).await
 }
 
+// Read only a skill's frontmatter metadata, for rendering cards without the full body
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn read_skill_metadata(skill_path: String) -> Result<tools::SkillMetadata, String> {
+    tools::read_skill_metadata(&skill_path).await
+}
+
+// Estimate a skill's character and token footprint, per file and in total
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn estimate_skill_size(skill_path: String) -> Result<tools::SkillSizeEstimate, String> {
+    tools::estimate_skill_size(&skill_path).await
+}
+
 // API base URL - can be overridden via SKILLHUB_API_URL environment variable
 // Default: https://www.skillhub.club (production)
 // For local development: SKILLHUB_API_URL=http://localhost:3000 npm run tauri dev
@@ -117,37 +556,138 @@ fn get_api_base_url() -> String {
     std::env::var("SKILLHUB_API_URL").unwrap_or_else(|_| DEFAULT_API_URL.to_string())
 }
 
-// Search skills from SkillHub API (using public desktop endpoint)
-#[tauri::command]
-async fn search_skills(query: String, limit: Option<i32>) -> Result<Vec<SkillHubSkill>, String> {
-    let limit = limit.unwrap_or(20);
-    let client = reqwest::Client::new();
+// Page size used by search_skills_streaming
+const SEARCH_PAGE_SIZE: i32 = 20;
+
+#[derive(Debug, Clone, Serialize)]
+struct SearchResultPage {
+    query: String,
+    page: u32,
+    skills: Vec<SkillHubSkill>,
+}
+
+async fn fetch_search_page(
+    client: &reqwest::Client,
+    query: &str,
+    limit: i32,
+    page: Option<u32>,
+) -> Result<Vec<SkillHubSkill>, String> {
+    installer::ensure_online()?;
     let base_url = get_api_base_url();
 
+    let mut body = serde_json::json!({
+        "query": query,
+        "limit": limit
+    });
+    if let Some(page) = page {
+        body["page"] = serde_json::json!(page);
+    }
+
     let response = client
         .post(format!("{}/api/v1/desktop/search", base_url))
-        .json(&serde_json::json!({
-            "query": query,
-            "limit": limit
-        }))
+        .json(&body)
         .send()
         .await
         .map_err(|e| format!("Failed to search skills: {}", e))?;
 
-    let data: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    let status = response.status();
+    let data = read_json_response(response, "Failed to search skills").await?;
 
     let skills: Vec<SkillHubSkill> = serde_json::from_value(
         data.get("skills").cloned().unwrap_or(serde_json::json!([]))
-    ).unwrap_or_default();
+    )
+    .map_err(|e| format!("parse_error: Failed to search skills: unexpected response (HTTP {}): {}", status, e))?;
 
     Ok(skills)
 }
 
+/// Read a JSON response body. Error statuses fail with the HTTP status, and bodies that
+/// aren't JSON (an HTML error page, a truncated response) fail with a "parse_error:" error
+/// instead of turning into an empty result.
+async fn read_json_response(
+    response: reqwest::Response,
+    context: &str,
+) -> Result<serde_json::Value, String> {
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("{}: HTTP {}", context, status));
+    }
+
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("{}: {}", context, e))?;
+    serde_json::from_str(&body)
+        .map_err(|e| format!("parse_error: {}: invalid JSON response (HTTP {}): {}", context, status, e))
+}
+
+// Search skills from SkillHub API (using public desktop endpoint)
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn search_skills(query: String, limit: Option<i32>) -> Result<Vec<SkillHubSkill>, String> {
+    let client = reqwest::Client::new();
+    fetch_search_page(&client, &query, limit.unwrap_or(20), None).await
+}
+
+// Search in pages of SEARCH_PAGE_SIZE, emitting search-result-page as each one arrives
+// so the UI fills in progressively; returns all results (up to total) at the end
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn search_skills_streaming(
+    app: tauri::AppHandle,
+    query: String,
+    total: Option<i32>,
+) -> Result<Vec<SkillHubSkill>, String> {
+    let total = total.unwrap_or(100).max(0) as usize;
+    let client = reqwest::Client::new();
+    let mut results: Vec<SkillHubSkill> = Vec::new();
+    let mut page = 1;
+
+    while results.len() < total {
+        let batch = match fetch_search_page(&client, &query, SEARCH_PAGE_SIZE, Some(page)).await {
+            Ok(batch) => batch,
+            // Keep what already reached the UI rather than failing the whole search
+            Err(e) if !results.is_empty() => {
+                tracing::warn!("Search stopped at page {}: {}", page, e);
+                break;
+            }
+            Err(e) => return Err(e),
+        };
+        let last_page = batch.len() < SEARCH_PAGE_SIZE as usize;
+
+        // A server that ignores `page` returns the first page again; stop once nothing is new
+        let remaining = total - results.len();
+        let new: Vec<SkillHubSkill> = batch
+            .into_iter()
+            .filter(|skill| !results.iter().any(|r| r.id == skill.id))
+            .take(remaining)
+            .collect();
+        if new.is_empty() {
+            break;
+        }
+
+        let _ = app.emit(
+            "search-result-page",
+            SearchResultPage {
+                query: query.clone(),
+                page,
+                skills: new.clone(),
+            },
+        );
+        results.extend(new);
+
+        if last_page {
+            break;
+        }
+        page += 1;
+    }
+
+    Ok(results)
+}
+
 // Get skill catalog from SkillHub API (using public desktop endpoint)
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn get_catalog(
     page: Option<i32>,
     limit: Option<i32>,
@@ -155,6 +695,7 @@ async fn get_catalog(
     sort_by: Option<String>,
     r#type: Option<String>, // "collections" for aggregator repos
 ) -> Result<serde_json::Value, String> {
+    installer::ensure_online()?;
     let client = reqwest::Client::new();
     let base_url = get_api_base_url();
 
@@ -181,21 +722,53 @@ async fn get_catalog(
         .await
         .map_err(|e| format!("Failed to get catalog: {}", e))?;
 
-    let data: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    read_json_response(response, "Failed to get catalog").await
+}
+
+// Catalog categories with skill counts, for the category filter (cached for a few minutes)
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn get_catalog_categories() -> Result<Vec<categories::CategoryInfo>, String> {
+    categories::get_catalog_categories().await
+}
 
-    Ok(data)
+// Save catalog skill summaries locally (optionally only some categories) for offline search
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn prefetch_catalog(
+    categories: Option<Vec<String>>,
+    max_skills: usize,
+) -> Result<catalog_cache::PrefetchReport, String> {
+    catalog_cache::prefetch_catalog(categories.as_deref(), max_skills).await
+}
+
+// Search the prefetched catalog, works offline
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn search_cached_catalog(query: String) -> Result<Vec<SkillHubSkill>, String> {
+    catalog_cache::search_cached_catalog(&query).await
+}
+
+// Browse the prefetched catalog a page at a time, optionally one category, works offline
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn get_cached_catalog_page(
+    page: u32,
+    limit: u32,
+    category: Option<String>,
+) -> Result<catalog_cache::CachedCatalogPage, String> {
+    catalog_cache::get_cached_catalog_page(page, limit, category.as_deref()).await
 }
 
 // Get KOL detail with skills from SkillHub API
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn get_kol_detail(
     username: String,
     include_skills: Option<bool>,
     skills_limit: Option<i32>,
-) -> Result<serde_json::Value, String> {
+) -> Result<kol::KolWithSkills, String> {
+    installer::ensure_online()?;
     let client = reqwest::Client::new();
     let base_url = get_api_base_url();
 
@@ -207,7 +780,7 @@ async fn get_kol_detail(
         skills_limit.unwrap_or(20)
     );
 
-    println!("[get_kol_detail] Fetching: {}", url);
+    tracing::debug!("Fetching KOL detail: {}", url);
 
     let response = client
         .get(&url)
@@ -215,25 +788,20 @@ async fn get_kol_detail(
         .await
         .map_err(|e| format!("Failed to get KOL detail: {}", e))?;
 
-    if !response.status().is_success() {
-        return Err(format!("Failed to get KOL detail: HTTP {}", response.status()));
-    }
-
-    let data: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    let data = read_json_response(response, "Failed to get KOL detail").await?;
 
-    Ok(data)
+    kol::parse_kol_detail(data)
 }
 
 // Get KOL list from SkillHub API
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn get_kol_list(
     limit: Option<i32>,
     offset: Option<i32>,
     sort: Option<String>,
-) -> Result<serde_json::Value, String> {
+) -> Result<kol::KolList, String> {
+    installer::ensure_online()?;
     let client = reqwest::Client::new();
     let base_url = get_api_base_url();
 
@@ -245,34 +813,26 @@ async fn get_kol_list(
         sort.unwrap_or_else(|| "followers".to_string())
     );
 
-    println!("[get_kol_list] Fetching: {}", url);
+    tracing::debug!("Fetching KOL list: {}", url);
 
     let response = client
         .get(&url)
         .send()
         .await
-        .map_err(|e| {
-            println!("[get_kol_list] Request failed: {}", e);
-            format!("Failed to get KOL list: {}", e)
-        })?;
+        .map_err(|e| format!("Failed to get KOL list: {}", e))?;
 
-    println!("[get_kol_list] Response status: {}", response.status());
+    tracing::debug!("KOL list response status: {}", response.status());
 
-    let data: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|e| {
-            println!("[get_kol_list] Parse failed: {}", e);
-            format!("Failed to parse response: {}", e)
-        })?;
+    let data = read_json_response(response, "Failed to get KOL list").await?;
 
-    println!("[get_kol_list] Success, got data");
-    Ok(data)
+    kol::parse_kol_list(data)
 }
 
 // Get skill detail from SkillHub API (using public desktop endpoint)
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn get_skill_detail(slug: String) -> Result<serde_json::Value, String> {
+    installer::ensure_online()?;
     let client = reqwest::Client::new();
     let base_url = get_api_base_url();
 
@@ -282,17 +842,21 @@ async fn get_skill_detail(slug: String) -> Result<serde_json::Value, String> {
         .await
         .map_err(|e| format!("Failed to get skill detail: {}", e))?;
 
-    let data: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    read_json_response(response, "Failed to get skill detail").await
+}
 
-    Ok(data)
+// Get a catalog skill's install counts, stars and rating (briefly cached)
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn get_skill_stats(skill_id: String) -> Result<skill_stats::SkillStats, String> {
+    skill_stats::get_skill_stats(&skill_id).await
 }
 
 // Get skill files tree structure from SkillHub API
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn get_skill_files(skill_id: String) -> Result<serde_json::Value, String> {
+    installer::ensure_online()?;
     let client = reqwest::Client::new();
     let base_url = get_api_base_url();
 
@@ -302,29 +866,54 @@ async fn get_skill_files(skill_id: String) -> Result<serde_json::Value, String>
         .await
         .map_err(|e| format!("Failed to get skill files: {}", e))?;
 
-    if !response.status().is_success() {
-        return Err(format!("Failed to get skill files: HTTP {}", response.status()));
-    }
+    read_json_response(response, "Failed to get skill files").await
+}
 
-    let data: serde_json::Value = response
-        .json()
+// Fetch all files of a catalog skill in one call (cached locally for re-installs)
+// skill_md_only returns just SKILL.md, matching install_skill_files' option
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn fetch_skill_bundle(
+    skill_id: String,
+    refresh: Option<bool>,
+    skill_md_only: Option<bool>,
+) -> Result<Vec<(String, String)>, String> {
+    bundle::fetch_skill_bundle(&skill_id, refresh.unwrap_or(false), skill_md_only.unwrap_or(false))
         .await
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+}
 
-    Ok(data)
+// Delete everything in ~/.skillhub/cache; settings and installed skills are untouched
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn clear_all_caches() -> Result<cache::CacheClearReport, String> {
+    cache::clear_all_caches().await
 }
 
+// Hosts that may be fetched directly, bypassing the SkillHub proxy
+const DIRECT_FETCH_HOSTS: &[&str] = &["github.com", "raw.githubusercontent.com"];
+
 // Get file content from GitHub (proxied through SkillHub API)
+// With fetch_url_direct, GitHub URLs are fetched directly first and the proxy is the fallback
 #[tauri::command]
-async fn get_remote_file_content(raw_url: String) -> Result<String, String> {
+#[tracing::instrument(err)]
+async fn get_remote_file_content(
+    raw_url: String,
+    fetch_url_direct: Option<bool>,
+) -> Result<String, String> {
+    installer::ensure_online()?;
+    if fetch_url_direct.unwrap_or(false) {
+        match fetch_github_raw(&raw_url).await {
+            Ok(content) => return Ok(content),
+            Err(e) => tracing::warn!("Direct fetch failed, falling back to proxy: {}", e),
+        }
+    }
+
     let client = reqwest::Client::new();
     let base_url = get_api_base_url();
 
-    let response = client
-        .get(&format!("{}/api/v1/skills/file-content?url={}", base_url, urlencoding::encode(&raw_url)))
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch file content: {}", e))?;
+    let request = client
+        .get(&format!("{}/api/v1/skills/file-content?url={}", base_url, urlencoding::encode(&raw_url)));
+    let response = send_with_rate_limit_retry(request).await?;
 
     if !response.status().is_success() {
         return Err(format!("Failed to fetch file content: HTTP {}", response.status()));
@@ -338,20 +927,171 @@ async fn get_remote_file_content(raw_url: String) -> Result<String, String> {
     Ok(content)
 }
 
+// Longest we'll wait for a GitHub rate limit to reset before retrying
+const RATE_LIMIT_MAX_WAIT_SECS: u64 = 5;
+
+/// Rate-limit details read from a 403/429 response
+struct RateLimit {
+    wait_secs: u64,
+    reset_at: u64, // Unix timestamp (seconds)
+}
+
+/// Check whether a response is a GitHub rate limit (passed through by the proxy).
+/// A 403 only counts when the rate-limit headers say so, otherwise it's a permissions error.
+fn rate_limit_info(response: &reqwest::Response) -> Option<RateLimit> {
+    let status = response.status();
+    if status != reqwest::StatusCode::TOO_MANY_REQUESTS && status != reqwest::StatusCode::FORBIDDEN {
+        return None;
+    }
+
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+    };
+    let retry_after = header("retry-after");
+    let reset = header("x-ratelimit-reset");
+    let exhausted = header("x-ratelimit-remaining") == Some(0);
+
+    if status == reqwest::StatusCode::FORBIDDEN && retry_after.is_none() && !exhausted {
+        return None;
+    }
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let wait_secs = retry_after
+        .or_else(|| reset.map(|r| r.saturating_sub(now)))
+        .unwrap_or(1);
+
+    Some(RateLimit {
+        wait_secs,
+        reset_at: reset.unwrap_or(now + wait_secs),
+    })
+}
+
+/// Send a request, and if it hits a rate limit wait for the reset (capped) and retry once.
+/// A second rate limit fails with a `rate_limited:` error carrying the reset time.
+async fn send_with_rate_limit_retry(request: reqwest::RequestBuilder) -> Result<reqwest::Response, String> {
+    let retry = request.try_clone();
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch file content: {}", e))?;
+
+    let (Some(limit), Some(retry)) = (rate_limit_info(&response), retry) else {
+        return Ok(response);
+    };
+
+    let wait_secs = limit.wait_secs.min(RATE_LIMIT_MAX_WAIT_SECS);
+    tracing::warn!("Rate limited, retrying in {}s", wait_secs);
+    tokio::time::sleep(std::time::Duration::from_secs(wait_secs)).await;
+
+    let response = retry
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch file content: {}", e))?;
+
+    match rate_limit_info(&response) {
+        Some(limit) => Err(format!(
+            "rate_limited: GitHub rate limit reached, resets at {} (in {}s)",
+            limit.reset_at, limit.wait_secs
+        )),
+        None => Ok(response),
+    }
+}
+
+// Get file content directly from GitHub (only github.com and raw.githubusercontent.com)
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn get_github_raw(raw_url: String) -> Result<String, String> {
+    fetch_github_raw(&raw_url).await
+}
+
+// Find every skill (folder with a SKILL.md) in a GitHub repo, for the user to pick which to install
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn discover_skills_in_repo(repo_url: String) -> Result<repo_skills::RepoSkillDiscovery, String> {
+    repo_skills::discover_skills_in_repo(&repo_url).await
+}
+
+async fn fetch_github_raw(raw_url: &str) -> Result<String, String> {
+    installer::ensure_online()?;
+    let url = reqwest::Url::parse(raw_url).map_err(|e| format!("Invalid URL: {}", e))?;
+
+    // Allowlist the host to avoid turning this into an SSRF primitive
+    let host = url.host_str().unwrap_or_default();
+    if url.scheme() != "https" || !DIRECT_FETCH_HOSTS.contains(&host) {
+        return Err(format!("URL not allowed for direct fetch: {}", raw_url));
+    }
+
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::custom(|attempt| {
+            let allowed = attempt.url().scheme() == "https"
+                && DIRECT_FETCH_HOSTS.contains(&attempt.url().host_str().unwrap_or_default());
+            if allowed && attempt.previous().len() < 5 {
+                attempt.follow()
+            } else {
+                attempt.stop()
+            }
+        }))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch file content: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch file content: HTTP {}", response.status()));
+    }
+
+    response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))
+}
+
 // Open a folder in the system file explorer
 #[tauri::command]
+#[tracing::instrument(err)]
 fn open_folder(path: String) -> Result<(), String> {
     tools::open_folder_in_explorer(&path)
 }
 
+// Open a docs/help link in the default browser (http and https only)
+#[tauri::command]
+#[tracing::instrument(err)]
+fn open_external_url(url: String) -> Result<(), String> {
+    tools::open_external_url(&url)
+}
+
 // Get folder tree structure for visualization
 #[tauri::command]
-async fn get_folder_tree(path: String, max_depth: Option<usize>) -> Result<tools::FileNode, String> {
-    tools::get_folder_tree(&path, max_depth.unwrap_or(5)).await
+#[tracing::instrument(err)]
+async fn get_folder_tree(
+    path: String,
+    max_depth: Option<usize>,
+    request_id: Option<String>,
+) -> Result<tools::FileNode, String> {
+    tools::get_folder_tree(&path, max_depth.unwrap_or(5), request_id.as_deref()).await
+}
+
+// Stop a get_folder_tree call that was started with the same request id
+#[tauri::command]
+#[tracing::instrument]
+fn cancel_folder_tree(request_id: String) {
+    tools::cancel_folder_tree(&request_id)
 }
 
 // Read a single file's content
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn read_file(path: String) -> Result<String, String> {
     tools::read_file_content(&path).await
 }
@@ -368,8 +1108,9 @@ pub struct ClaudeDirectories {
 
 // Get Claude Code directory paths
 #[tauri::command]
+#[tracing::instrument(err)]
 fn get_claude_directories() -> Result<ClaudeDirectories, String> {
-    let home = dirs::home_dir().ok_or("Cannot find home directory")?;
+    let home = home::home_dir()?;
     let claude_dir = home.join(".claude");
     
     Ok(ClaudeDirectories {
@@ -389,28 +1130,77 @@ fn check_path_exists(path: String) -> bool {
 
 // Get directory structure for a specific AI coding tool
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn get_tool_directories(tool_id: String) -> Result<tools::ToolDirectories, String> {
     tools::get_tool_directories(&tool_id).await
 }
 
+// Create a tool's config and skills directories if they are missing
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn ensure_tool_directories(tool_id: String) -> Result<Vec<String>, String> {
+    tools::ensure_tool_directories(&tool_id).await
+}
+
+// List folders in a tool's skills directory that aren't recognized as skills
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn scan_skill_health(tool_id: String) -> Result<Vec<tools::UnrecognizedEntry>, String> {
+    tools::scan_skill_health(&tool_id).await
+}
+
+// List skill backups across a tool's skills directories
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn list_skill_backups_all(tool_id: String) -> Result<Vec<skill_backups::SkillBackup>, String> {
+    skill_backups::list_skill_backups_all(&tool_id).await
+}
+
+// Delete skill backups beyond the retention policy and report the space reclaimed
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn prune_skill_backups(
+    tool_id: String,
+    keep_latest: usize,
+    older_than_days: Option<u64>,
+) -> Result<skill_backups::PruneResult, String> {
+    skill_backups::prune_skill_backups(&tool_id, keep_latest, older_than_days).await
+}
+
+// Count installed skills per category and per tag for the local library filter
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn get_installed_categories() -> Result<Vec<tools::CategoryCount>, String> {
+    tools::get_installed_categories().await
+}
+
 // Copy a skill from source to destination directory
+// on_conflict: "error" (default), "skip", "overwrite" or "merge"
 #[tauri::command]
-async fn copy_skill(source_path: String, dest_dir: String) -> Result<String, String> {
-    tools::copy_skill(&source_path, &dest_dir).await
+#[tracing::instrument(err)]
+async fn copy_skill(
+    source_path: String,
+    dest_dir: String,
+    on_conflict: Option<String>,
+) -> Result<tools::CopySkillResult, String> {
+    let policy = tools::parse_conflict_policy(on_conflict.as_deref().unwrap_or("error"))?;
+    tools::copy_skill(&source_path, &dest_dir, policy).await
 }
 
 // List skills in a directory (for import picker)
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn list_skills_in_dir(dir_path: String) -> Result<Vec<InstalledSkill>, String> {
     tools::list_skills_in_dir(&dir_path).await
 }
 
 // Install skill to ~/.claude/skills/ temporarily for playground
 #[tauri::command]
+#[tracing::instrument(skip(content), err)]
 async fn install_temp_skill(skill_name: String, content: String) -> Result<String, String> {
     use tokio::fs;
 
-    let home = dirs::home_dir().ok_or("Cannot find home directory")?;
+    let home = home::home_dir()?;
     let skills_dir = home.join(".claude").join("skills").join(&skill_name);
 
     fs::create_dir_all(&skills_dir)
@@ -425,8 +1215,24 @@ async fn install_temp_skill(skill_name: String, content: String) -> Result<Strin
     Ok(skills_dir.to_string_lossy().to_string())
 }
 
+// Parse and validate a skill for preview without writing anything to disk
+#[tauri::command]
+#[tracing::instrument(skip(content))]
+fn preview_skill(content: String) -> preview::PreviewResult {
+    preview::preview_skill(&content)
+}
+
+// Reformat a SKILL.md's frontmatter canonically (key order, quoting, list syntax)
+// leaving the body untouched; invalid frontmatter fails with "frontmatter_error: line <n>: ..."
+#[tauri::command]
+#[tracing::instrument(skip(content), err)]
+fn format_skill_frontmatter(content: String) -> Result<String, String> {
+    frontmatter::format_skill_frontmatter(&content)
+}
+
 // Uninstall temp skill from ~/.claude/skills/
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn uninstall_temp_skill(skill_path: String) -> Result<(), String> {
     use tokio::fs;
 
@@ -441,6 +1247,7 @@ async fn uninstall_temp_skill(skill_path: String) -> Result<(), String> {
 
 // Write skill content to temp file (legacy, kept for compatibility)
 #[tauri::command]
+#[tracing::instrument(skip(content), err)]
 async fn write_temp_skill(skill_id: String, content: String) -> Result<String, String> {
     use tokio::fs;
 
@@ -459,6 +1266,7 @@ async fn write_temp_skill(skill_id: String, content: String) -> Result<String, S
 
 // Cleanup temp skill file (legacy)
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn cleanup_temp_skill(path: String) -> Result<(), String> {
     use tokio::fs;
 
@@ -480,6 +1288,42 @@ fn check_dependencies() -> installer::DependencyStatus {
     installer::check_all_dependencies()
 }
 
+// Re-check dependencies in the background, emitting dependencies-changed with the full
+// status whenever readiness or an installed state changes (replaces polling check_dependencies)
+#[tauri::command]
+async fn watch_dependencies(app: tauri::AppHandle) {
+    installer::watch_dependencies(move |status| {
+        let _ = app.emit("dependencies-changed", status);
+    });
+}
+
+// Stop the watcher started by watch_dependencies; returns whether one was running
+#[tauri::command]
+fn stop_watching_dependencies() -> bool {
+    installer::stop_watching_dependencies()
+}
+
+// Check permissions, PATH and disk space before the guided install, with a fix for each problem
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn preflight_install() -> Result<preflight::PreflightReport, String> {
+    preflight::preflight_install().await
+}
+
+// The PATH entries the app process sees (GUI apps often get a shorter PATH than a terminal)
+#[tauri::command]
+#[tracing::instrument]
+fn get_effective_path() -> Vec<String> {
+    preflight::get_effective_path()
+}
+
+// Whether npm's global bin directory, where `claude` gets installed, is on the app's PATH
+#[tauri::command]
+#[tracing::instrument]
+async fn path_contains_npm_global() -> bool {
+    preflight::path_contains_npm_global().await
+}
+
 // Get installation steps for missing dependencies
 #[tauri::command]
 fn get_install_steps() -> Vec<installer::InstallStep> {
@@ -488,24 +1332,50 @@ fn get_install_steps() -> Vec<installer::InstallStep> {
 
 // Get a specific installation command
 #[tauri::command]
+#[tracing::instrument(err)]
 fn get_install_command(step_id: String) -> Result<installer::InstallStep, String> {
     installer::get_install_command(&step_id)
 }
 
 // Configure Claude Code to use SkillHub API
 #[tauri::command]
+#[tracing::instrument(skip(api_key), err)]
 async fn configure_claude_code(api_key: String) -> Result<(), String> {
     installer::configure_claude_code(&api_key).await
 }
 
+// Show the shell config changes configure_claude_code would make, without writing them
+#[tauri::command]
+#[tracing::instrument(skip(api_key), err)]
+async fn preview_claude_code_config(api_key: String) -> Result<installer::ConfigDiff, String> {
+    installer::preview_claude_code_config(&api_key).await
+}
+
 // Remove Claude Code configuration
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn remove_claude_code_config() -> Result<(), String> {
     installer::remove_claude_code_config().await
 }
 
+// Remove what SkillHub set up for one tool, optionally its skills (snapshotted first)
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn reset_tool(
+    app: tauri::AppHandle,
+    tool_id: String,
+    remove_skills: bool,
+) -> Result<tools::ToolResetReport, String> {
+    let report = tools::reset_tool(&tool_id, remove_skills).await?;
+    if !report.removed.is_empty() {
+        notify_skills_changed(&app);
+    }
+    Ok(report)
+}
+
 // Validate API key against SkillHub API
 #[tauri::command]
+#[tracing::instrument(skip(api_key), err)]
 async fn validate_api_key(api_key: String) -> Result<installer::ApiKeyValidationResult, String> {
     installer::validate_api_key(&api_key).await
 }
@@ -522,42 +1392,342 @@ fn get_claude_env_vars() -> Vec<(String, String)> {
     installer::get_claude_env_vars()
 }
 
+// Show the environment the PTY-launched Claude process gets, with secrets redacted
+#[tauri::command]
+#[tracing::instrument]
+fn get_effective_claude_env() -> Vec<(String, String)> {
+    installer::get_effective_claude_env()
+}
+
+// Set whether closing the window hides to tray or quits the app
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn set_close_behavior(behavior: String) -> Result<(), String> {
+    installer::set_close_behavior(&behavior).await
+}
+
+// Detect the user's shell and which config file SkillHub writes exports to
+#[tauri::command]
+#[tracing::instrument(err)]
+fn detect_shell() -> Result<installer::ShellInfo, String> {
+    installer::detect_shell()
+}
+
+// Report where shell exports or environment variables disagree with the SkillHub config
+#[tauri::command]
+#[tracing::instrument]
+fn detect_config_conflicts() -> Vec<installer::ConfigConflict> {
+    installer::detect_config_conflicts()
+}
+
+// Open ~/.skillhub/config.json in the default editor (created with defaults if missing)
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn open_config_file() -> Result<String, String> {
+    installer::open_config_file().await
+}
+
+// Back up config, favorites, projects and tools settings into one JSON file
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn export_settings(dest_path: String, include_secrets: Option<bool>) -> Result<Vec<String>, String> {
+    backup::export_settings(&dest_path, include_secrets.unwrap_or(false)).await
+}
+
+// Restore settings from a backup, replacing or merging with the current ones
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn import_settings(src_path: String, merge: bool) -> Result<Vec<String>, String> {
+    backup::import_settings(&src_path, merge).await
+}
+
+// Re-read the config file after manual edits, reporting parse errors
+#[tauri::command]
+#[tracing::instrument(err)]
+fn reload_config() -> Result<installer::SkillHubConfig, String> {
+    installer::reload_config()
+}
+
+// Get the tool ids detection is limited to (null means all tools)
+#[tauri::command]
+fn get_enabled_tools() -> Option<Vec<String>> {
+    installer::get_enabled_tools()
+}
+
+// Limit detection and scanning to the given tools (null scans every tool)
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn set_enabled_tools(tool_ids: Option<Vec<String>>) -> Result<(), String> {
+    installer::set_enabled_tools(tool_ids).await
+}
+
+// Whether offline mode (no network requests at all) is on
+#[tauri::command]
+fn get_offline_mode() -> bool {
+    installer::is_offline_mode()
+}
+
+// Turn offline mode on or off; network commands then fail with an "offline:" error
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn set_offline_mode(enabled: bool) -> Result<(), String> {
+    installer::set_offline_mode(enabled).await
+}
+
+// Get the gitignore-style patterns excluded from sync
+#[tauri::command]
+fn get_sync_ignore() -> Vec<String> {
+    installer::get_sync_ignore()
+}
+
+// Replace the gitignore-style patterns excluded from sync
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn set_sync_ignore(patterns: Vec<String>) -> Result<(), String> {
+    installer::set_sync_ignore(patterns).await
+}
+
+// ============================================
+// Updater Commands
+// ============================================
+
+// Check whether a newer app version is available
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn check_for_update(app: tauri::AppHandle) -> Result<updater::UpdateInfo, String> {
+    updater::check_for_update(&app).await
+}
+
+// Download and install the latest app version
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn install_update(app: tauri::AppHandle) -> Result<(), String> {
+    updater::install_update(&app).await
+}
+
+// ============================================
+// Diagnostics Commands
+// ============================================
+
+// Collect environment info for bug reports (never includes the API key)
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn collect_diagnostics(app: tauri::AppHandle) -> Result<diagnostics::Diagnostics, String> {
+    diagnostics::collect_diagnostics(&app.package_info().version.to_string()).await
+}
+
+// Write the diagnostics report to a JSON file
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn export_diagnostics(app: tauri::AppHandle, dest_path: String) -> Result<(), String> {
+    diagnostics::export_diagnostics(&app.package_info().version.to_string(), &dest_path).await
+}
+
 // ============================================
 // Sync Commands
 // ============================================
 
 // Collect all files from a skill directory with SHA-256 hashes
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn collect_skill_files_for_sync(path: String) -> Result<Vec<sync::SyncFile>, String> {
     sync::collect_files(&path).await
 }
 
-// Write pulled files to local directory
+// Write pulled files to local directory and report what changed
 #[tauri::command]
-async fn write_synced_files(path: String, files: Vec<sync::SyncFile>) -> Result<(), String> {
+#[tracing::instrument(skip(files), err)]
+async fn write_synced_files(
+    path: String,
+    files: Vec<sync::SyncFile>,
+) -> Result<sync::SyncReport, String> {
     sync::write_files(&path, &files).await
 }
 
+// Write pulled files, backing up or keeping locally modified files per on_conflict
+#[tauri::command]
+#[tracing::instrument(skip(files), err)]
+async fn write_synced_files_safe(
+    path: String,
+    files: Vec<sync::SyncFile>,
+    on_conflict: String,
+) -> Result<Vec<sync::SyncConflict>, String> {
+    sync::write_files_safe(&path, &files, &on_conflict).await
+}
+
+// Get the available and total space of the volume containing a path
+#[tauri::command]
+#[tracing::instrument(err)]
+fn check_disk_space(path: String) -> Result<disk::DiskInfo, String> {
+    disk::check_disk_space(&path)
+}
+
+// Fix an imported skill's layout so SKILL.md sits at the folder root
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn normalize_skill(skill_path: String) -> Result<normalize::NormalizeReport, String> {
+    normalize::normalize_skill(&skill_path).await
+}
+
+// Report a SKILL.md's BOM, line endings and whether it is valid UTF-8
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn check_skill_encoding(skill_path: String) -> Result<normalize::EncodingReport, String> {
+    normalize::check_skill_encoding(&skill_path).await
+}
+
+// Rewrite a SKILL.md as UTF-8 with LF line endings and no BOM
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn normalize_skill_encoding(skill_path: String) -> Result<normalize::NormalizeReport, String> {
+    normalize::normalize_skill_encoding(&skill_path).await
+}
+
+// Check whether ~/.skillhub/config.json is missing, valid or corrupt
+#[tauri::command]
+#[tracing::instrument(err)]
+fn validate_config_file() -> Result<installer::ConfigFileStatus, String> {
+    installer::validate_config_file()
+}
+
+// Back up a corrupt config.json and start over with defaults; returns the backup path
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn repair_config_file() -> Result<Option<String>, String> {
+    installer::repair_config_file().await
+}
+
+// Check whether skills can be written into a tool's skills directory
+#[tauri::command]
+#[tracing::instrument(err)]
+fn check_tool_writable(tool_id: String) -> Result<disk::WritableStatus, String> {
+    tools::check_tool_writable(&tool_id)
+}
+
+// Compare an installed skill with its canonical catalog version, file by file
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn verify_against_catalog(skill_path: String) -> Result<verify::VerifyResult, String> {
+    verify::verify_against_catalog(&skill_path).await
+}
+
+// Compare two skills file by file and return unified diffs
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn diff_skills(path_a: String, path_b: String) -> Result<Vec<diff::FileDiff>, String> {
+    diff::diff_skills(&path_a, &path_b).await
+}
+
+// Merge one skill's files into another, resolving differing files by strategy
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn merge_skills(
+    base_path: String,
+    incoming_path: String,
+    strategy: String,
+) -> Result<merge::MergeReport, String> {
+    merge::merge_skills(&base_path, &incoming_path, &strategy).await
+}
+
 // Read .skillhub.json metadata from skill directory
 #[tauri::command]
+#[tracing::instrument(err)]
 async fn read_sync_meta(path: String) -> Result<Option<sync::SyncMeta>, String> {
     sync::read_meta(&path).await
 }
 
-// Write .skillhub.json metadata file
+// Write .skillhub.json metadata file, keeping the install details of an existing one
 #[tauri::command]
+#[tracing::instrument(skip(meta), err)]
 async fn write_sync_meta(path: String, meta: sync::SyncMeta) -> Result<(), String> {
-    sync::write_meta(&path, &meta).await
+    sync::merge_meta(&path, meta).await
+}
+
+// Update a catalog skill to the latest version, replacing only the files that came
+// from the catalog so files the user added are kept
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn update_skill_in_place(skill_path: String) -> Result<skill_update::InPlaceUpdateReport, String> {
+    skill_update::update_skill_in_place(&skill_path).await
 }
 
 // Save binary data (Git ZIP export) to disk
 #[tauri::command]
+#[tracing::instrument(skip(data), err)]
 async fn save_export_file(data: Vec<u8>, save_path: String) -> Result<(), String> {
     sync::save_export(&data, &save_path).await
 }
 
+// List files skipped during scans because their names aren't valid UTF-8
+#[tauri::command]
+fn get_non_utf8_entries() -> Vec<String> {
+    sync::get_non_utf8_entries()
+}
+
+// Export all skills of a tool as a ZIP library with a manifest
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn export_library(tool_id: String, dest_path: String) -> Result<library::LibraryManifest, String> {
+    library::export_library(&tool_id, &dest_path).await
+}
+
+// Import a ZIP library produced by export_library into the given tools
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn import_library(
+    app: tauri::AppHandle,
+    zip_path: String,
+    tool_ids: Vec<String>,
+    on_conflict: String,
+) -> Result<Vec<library::ImportSkillResult>, String> {
+    let results = library::import_library(&zip_path, &tool_ids, &on_conflict).await?;
+    notify_skills_changed(&app);
+    Ok(results)
+}
+
+// Archive the selected tools' skills directories into ~/.skillhub/snapshots as a restore point
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn snapshot_skills(tool_ids: Vec<String>, label: String) -> Result<snapshot::SnapshotInfo, String> {
+    snapshot::snapshot_skills(&tool_ids, &label).await
+}
+
+// List saved snapshots, newest first
+#[tauri::command]
+#[tracing::instrument(err)]
+async fn list_snapshots() -> Result<Vec<snapshot::SnapshotInfo>, String> {
+    snapshot::list_snapshots().await
+}
+
+// Roll skills back to a snapshot; on_conflict: "error", "skip", "overwrite" or "merge"
+#[tauri::command]
+#[tracing::instrument(skip(app), err)]
+async fn restore_snapshot(
+    app: tauri::AppHandle,
+    snapshot_path: String,
+    on_conflict: String,
+) -> Result<Vec<snapshot::SnapshotRestoreResult>, String> {
+    let results = snapshot::restore_snapshot(&snapshot_path, &on_conflict).await?;
+    notify_skills_changed(&app);
+    Ok(results)
+}
+
+// Get the path of the current log file (rotated daily in ~/.skillhub/logs)
+#[tauri::command]
+fn get_log_path() -> Result<String, String> {
+    logging::get_log_file().map(|file| file.to_string_lossy().to_string())
+}
+
+// Change the log level at runtime
+#[tauri::command]
+fn set_log_level(level: String) -> Result<(), String> {
+    logging::set_log_level(&level)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    logging::init();
+
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
@@ -567,95 +1737,170 @@ pub fn run() {
         .plugin(tauri_plugin_process::init())
         .invoke_handler(tauri::generate_handler![
             detect_tools,
+            list_supported_tools,
+            register_custom_tool,
+            remove_custom_tool,
             get_installed_skills,
+            list_installed_skills_paged,
             install_skill,
             install_skill_files,
+            install_skill_symlinked,
+            install_skill_to_dir,
+            copy_personal_skills_to_project,
+            get_dashboard_stats,
+            get_dashboard_update_stats,
+            install_skills_batch,
             install_skill_to_project,
             install_skill_files_to_project,
+            resolve_path,
+            resolve_install_paths,
+            detect_project_tools,
+            find_broken_symlinks,
+            remove_broken_symlinks,
+            consolidate_duplicate_skill,
+            relocate_tool_skills,
+            migrate_legacy_skills,
+            list_skill_projects,
+            remove_skill_project,
+            get_project_git_info,
             uninstall_skill,
+            uninstall_skills_by_author,
             read_skill_content,
+            read_skill_metadata,
+            estimate_skill_size,
             search_skills,
+            search_skills_streaming,
             get_catalog,
+            get_catalog_categories,
+            prefetch_catalog,
+            search_cached_catalog,
+            get_cached_catalog_page,
             get_kol_list,
             get_kol_detail,
             get_skill_detail,
+            get_skill_stats,
             get_skill_files,
+            fetch_skill_bundle,
+            clear_all_caches,
             get_remote_file_content,
+            get_github_raw,
+            discover_skills_in_repo,
             open_folder,
+            open_external_url,
             get_folder_tree,
+            cancel_folder_tree,
             read_file,
             get_claude_directories,
             check_path_exists,
             get_tool_directories,
+            ensure_tool_directories,
+            get_installed_categories,
+            scan_skill_health,
+            list_skill_backups_all,
+            prune_skill_backups,
             copy_skill,
             list_skills_in_dir,
             install_temp_skill,
+            preview_skill,
+            format_skill_frontmatter,
             uninstall_temp_skill,
             write_temp_skill,
             cleanup_temp_skill,
             // Sync commands
             collect_skill_files_for_sync,
             write_synced_files,
+            write_synced_files_safe,
+            diff_skills,
+            merge_skills,
+            verify_against_catalog,
+            check_disk_space,
+            check_tool_writable,
+            validate_config_file,
+            repair_config_file,
+            normalize_skill,
+            check_skill_encoding,
+            normalize_skill_encoding,
             read_sync_meta,
             write_sync_meta,
+            update_skill_in_place,
             save_export_file,
+            get_non_utf8_entries,
+            export_library,
+            get_log_path,
+            set_log_level,
+            import_library,
+            snapshot_skills,
+            list_snapshots,
+            restore_snapshot,
+            // Updater commands
+            check_for_update,
+            install_update,
+            // Diagnostics commands
+            collect_diagnostics,
+            export_diagnostics,
             // Installer commands
             check_dependencies,
+            watch_dependencies,
+            stop_watching_dependencies,
+            preflight_install,
+            get_effective_path,
+            path_contains_npm_global,
             get_install_steps,
             get_install_command,
             configure_claude_code,
+            preview_claude_code_config,
             remove_claude_code_config,
+            reset_tool,
             validate_api_key,
             get_manual_install_instructions,
             get_claude_env_vars,
+            get_effective_claude_env,
+            set_close_behavior,
+            detect_config_conflicts,
+            detect_shell,
+            open_config_file,
+            reload_config,
+            export_settings,
+            import_settings,
+            get_enabled_tools,
+            set_enabled_tools,
+            get_offline_mode,
+            set_offline_mode,
+            get_sync_ignore,
+            set_sync_ignore,
         ])
         .setup(|app| {
-            // Create tray menu
-            let show_item = MenuItem::with_id(app, "show", "Show SkillHub", true, None::<&str>)?;
-            let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
-            let menu = Menu::with_items(app, &[&show_item, &quit_item])?;
-
-            // Create tray icon
-            let _tray = TrayIconBuilder::new()
-                .icon(app.default_window_icon().unwrap().clone())
-                .menu(&menu)
-                .show_menu_on_left_click(false)
-                .on_menu_event(|app, event| match event.id.as_ref() {
-                    "show" => {
-                        if let Some(window) = app.get_webview_window("main") {
-                            let _ = window.show();
-                            let _ = window.set_focus();
-                        }
-                    }
-                    "quit" => {
-                        app.exit(0);
-                    }
-                    _ => {}
-                })
-                .on_tray_icon_event(|tray, event| {
-                    if let TrayIconEvent::Click {
-                        button: MouseButton::Left,
-                        button_state: MouseButtonState::Up,
-                        ..
-                    } = event
-                    {
-                        let app = tray.app_handle();
-                        if let Some(window) = app.get_webview_window("main") {
-                            let _ = window.show();
-                            let _ = window.set_focus();
-                        }
-                    }
-                })
-                .build(app)?;
+            // Restore the main window's last size and position
+            if let Some(window) = app.get_webview_window("main") {
+                window_state::restore_window_state(&window);
+            }
+
+            // Create tray icon and menu
+            tray::create_tray(app)?;
 
             Ok(())
         })
-        .on_window_event(|window, event| {
-            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
-                // Hide window instead of closing when close button is clicked
-                let _ = window.hide();
-                api.prevent_close();
+        .on_window_event(|window, event| match event {
+            tauri::WindowEvent::CloseRequested { api, .. } => {
+                // Save right away in case a debounced save is still pending
+                if let Some(webview_window) = window.get_webview_window(window.label()) {
+                    window_state::save_window_state(&webview_window);
+                }
+                if installer::should_quit_on_close() {
+                    window.app_handle().exit(0);
+                } else {
+                    // Hide window instead of closing when close button is clicked
+                    let _ = window.hide();
+                    api.prevent_close();
+                }
+            }
+            tauri::WindowEvent::Moved(_) | tauri::WindowEvent::Resized(_) => {
+                // Persist geometry so the next launch reopens where the user left it
+                if let Some(webview_window) = window.get_webview_window(window.label()) {
+                    window_state::schedule_save_window_state(webview_window);
+                }
             }
+            _ => {}
         })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,601 @@
+// Skill library export/import as a single ZIP bundle
+// The archive contains a top-level manifest.json plus one folder per skill under skills/
+// Files whose content appears more than once are stored once under blobs/<sha256>
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Cursor, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use tokio::fs;
+
+use crate::{sync, tools};
+use crate::tools::ConflictPolicy;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const SKILLS_PREFIX: &str = "skills";
+const BLOBS_PREFIX: &str = "blobs";
+const MANIFEST_VERSION: u32 = 2;
+/// Smaller duplicates are stored inline; the manifest reference would cost about as much
+const DEDUPE_MIN_SIZE: usize = 256;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryManifest {
+    pub version: u32,
+    pub tool_id: String,
+    pub exported_at: u64,
+    pub skills: Vec<LibraryManifestEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryManifestEntry {
+    pub slug: String,
+    pub name: String,
+    pub author: Option<String>,
+    pub content_hash: String,
+    pub files: Vec<String>,
+    /// Files stored in blobs/ instead of the skill folder, as relative path -> content hash
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub shared_files: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSkillResult {
+    pub slug: String,
+    pub name: String,
+    pub hash_verified: bool,
+    pub installed_paths: Vec<String>,
+    pub skipped_tools: Vec<String>,
+    pub warnings: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+/// A skill's files as (relative_path, bytes), relative paths use forward slashes
+pub(crate) type SkillFiles = Vec<(String, Vec<u8>)>;
+
+/// Compute a single hash over all files of a skill.
+/// Files are sorted by path so the result doesn't depend on directory order.
+pub(crate) fn compute_skill_hash(files: &[(String, Vec<u8>)]) -> String {
+    let mut entries: Vec<(&str, String)> = files
+        .iter()
+        .map(|(path, content)| (path.as_str(), sync::hash_content(content)))
+        .collect();
+    entries.sort();
+
+    let mut combined = String::new();
+    for (path, hash) in entries {
+        combined.push_str(path);
+        combined.push('\0');
+        combined.push_str(&hash);
+        combined.push('\n');
+    }
+
+    sync::hash_content(combined.as_bytes())
+}
+
+/// Read all files of a skill (folder or single .md file)
+pub(crate) async fn read_skill_files(skill_path: &Path) -> Result<SkillFiles, String> {
+    let mut files = Vec::new();
+
+    if skill_path.is_dir() {
+        read_dir_files(skill_path, skill_path, &mut files, true).await?;
+    } else {
+        let content = fs::read(skill_path)
+            .await
+            .map_err(|e| format!("Failed to read file {}: {}", skill_path.display(), e))?;
+        files.push(("SKILL.md".to_string(), content));
+    }
+
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(files)
+}
+
+/// Read every file under a folder, sorted by path. Unlike `read_skill_files` nothing is
+/// skipped (hidden files, .git, backups) and links are followed; dangling links are left out.
+pub(crate) async fn read_all_files(dir: &Path) -> Result<SkillFiles, String> {
+    let mut files = Vec::new();
+    read_dir_files(dir, dir, &mut files, false).await?;
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(files)
+}
+
+#[async_recursion::async_recursion]
+async fn read_dir_files(
+    root: &Path,
+    current: &Path,
+    files: &mut SkillFiles,
+    filtered: bool,
+) -> Result<(), String> {
+    let mut entries = fs::read_dir(current)
+        .await
+        .map_err(|e| format!("Failed to read directory {}: {}", current.display(), e))?;
+
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .map_err(|e| format!("Failed to read entry: {}", e))?
+    {
+        let entry_path = entry.path();
+        match sync::checked_file_name(&entry_path) {
+            Some(name) if filtered && sync::should_skip(&name) => continue,
+            Some(_) => {}
+            None if filtered => continue,
+            None => return Err(format!("Unsupported file name: {}", entry_path.display())),
+        }
+
+        let metadata = if filtered {
+            entry.metadata().await
+        } else {
+            fs::metadata(&entry_path).await
+        };
+        let file_type = match metadata {
+            Ok(metadata) => metadata.file_type(),
+            Err(_) if !filtered && entry_path.is_symlink() => continue,
+            Err(e) => return Err(format!("Failed to get file type: {}", e)),
+        };
+
+        if file_type.is_dir() {
+            read_dir_files(root, &entry_path, files, filtered).await?;
+        } else if file_type.is_file() {
+            let content = fs::read(&entry_path)
+                .await
+                .map_err(|e| format!("Failed to read file {}: {}", entry_path.display(), e))?;
+
+            let relative = entry_path
+                .strip_prefix(root)
+                .map_err(|e| format!("Failed to compute relative path: {}", e))?;
+            files.push((sync::to_portable_path(relative), content));
+        }
+    }
+
+    Ok(())
+}
+
+/// Export all skills of a tool into a ZIP with a manifest and save it to dest_path
+pub async fn export_library(tool_id: &str, dest_path: &str) -> Result<LibraryManifest, String> {
+    let skills = tools::get_skills_for_tool(tool_id).await?;
+
+    let mut manifest = LibraryManifest {
+        version: MANIFEST_VERSION,
+        tool_id: tool_id.to_string(),
+        exported_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        skills: Vec::new(),
+    };
+    let mut bundle: Vec<(String, SkillFiles)> = Vec::new();
+
+    for skill in skills {
+        let skill_path = Path::new(&skill.path);
+        let base_slug = if skill_path.is_dir() {
+            skill_path.file_name()
+        } else {
+            skill_path.file_stem()
+        }
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "skill".to_string());
+
+        // Folder skills and loose .md files can share a name, keep slugs unique
+        let mut slug = base_slug.clone();
+        let mut suffix = 2;
+        while bundle.iter().any(|(s, _)| s == &slug) {
+            slug = format!("{}-{}", base_slug, suffix);
+            suffix += 1;
+        }
+
+        let files = read_skill_files(skill_path).await?;
+
+        manifest.skills.push(LibraryManifestEntry {
+            slug: slug.clone(),
+            name: skill.name,
+            author: skill.author,
+            content_hash: compute_skill_hash(&files),
+            files: files.iter().map(|(path, _)| path.clone()).collect(),
+            shared_files: BTreeMap::new(),
+        });
+        bundle.push((slug, files));
+    }
+
+    let blobs = dedupe_files(&mut manifest, &mut bundle);
+    let data = write_zip(&manifest, &bundle, &blobs)?;
+    sync::save_export(&data, dest_path).await?;
+
+    Ok(manifest)
+}
+
+/// Move files whose content occurs more than once out of the bundle, recording them in
+/// each skill's `shared_files`. Returns the unique contents keyed by hash.
+fn dedupe_files(
+    manifest: &mut LibraryManifest,
+    bundle: &mut [(String, SkillFiles)],
+) -> BTreeMap<String, Vec<u8>> {
+    let mut occurrences: HashMap<String, usize> = HashMap::new();
+    for (_, files) in bundle.iter() {
+        for (_, content) in files.iter().filter(|(_, c)| c.len() >= DEDUPE_MIN_SIZE) {
+            *occurrences.entry(sync::hash_content(content)).or_default() += 1;
+        }
+    }
+
+    let mut blobs = BTreeMap::new();
+    for (entry, (_, files)) in manifest.skills.iter_mut().zip(bundle.iter_mut()) {
+        files.retain(|(path, content)| {
+            if content.len() < DEDUPE_MIN_SIZE {
+                return true;
+            }
+            let hash = sync::hash_content(content);
+            if occurrences.get(&hash).copied().unwrap_or(0) < 2 {
+                return true;
+            }
+            entry.shared_files.insert(path.clone(), hash.clone());
+            blobs.entry(hash).or_insert_with(|| content.clone());
+            false
+        });
+    }
+
+    if !blobs.is_empty() {
+        tracing::info!("Export stores {} shared files once", blobs.len());
+    }
+    blobs
+}
+
+fn write_zip(
+    manifest: &LibraryManifest,
+    bundle: &[(String, SkillFiles)],
+    blobs: &BTreeMap<String, Vec<u8>>,
+) -> Result<Vec<u8>, String> {
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+
+    let manifest_json = serde_json::to_string_pretty(manifest)
+        .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+    zip.start_file(MANIFEST_FILE, options)
+        .map_err(|e| format!("Failed to write manifest: {}", e))?;
+    zip.write_all(manifest_json.as_bytes())
+        .map_err(|e| format!("Failed to write manifest: {}", e))?;
+
+    for (slug, files) in bundle {
+        for (filepath, content) in files {
+            let entry_name = format!("{}/{}/{}", SKILLS_PREFIX, slug, filepath);
+            zip.start_file(entry_name.as_str(), options)
+                .map_err(|e| format!("Failed to add {} to archive: {}", entry_name, e))?;
+            zip.write_all(content)
+                .map_err(|e| format!("Failed to add {} to archive: {}", entry_name, e))?;
+        }
+    }
+
+    for (hash, content) in blobs {
+        let entry_name = format!("{}/{}", BLOBS_PREFIX, hash);
+        zip.start_file(entry_name.as_str(), options)
+            .map_err(|e| format!("Failed to add {} to archive: {}", entry_name, e))?;
+        zip.write_all(content)
+            .map_err(|e| format!("Failed to add {} to archive: {}", entry_name, e))?;
+    }
+
+    let cursor = zip
+        .finish()
+        .map_err(|e| format!("Failed to finish archive: {}", e))?;
+
+    Ok(cursor.into_inner())
+}
+
+/// Convert an archive entry name into a relative path, rejecting anything that
+/// could escape the destination directory (absolute paths, "..", drive prefixes)
+pub(crate) fn safe_relative_path(name: &str) -> Option<PathBuf> {
+    let path = Path::new(name);
+    let mut result = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => result.push(part),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+
+    if result.as_os_str().is_empty() {
+        None
+    } else {
+        Some(result)
+    }
+}
+
+/// Read the manifest and the files of every skill from a library archive.
+/// Shared files are copied back into every skill that references them.
+fn read_library_archive(data: Vec<u8>) -> Result<(LibraryManifest, HashMap<String, SkillFiles>), String> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(data))
+        .map_err(|e| format!("Failed to open library archive: {}", e))?;
+
+    let manifest: LibraryManifest = {
+        let mut file = archive
+            .by_name(MANIFEST_FILE)
+            .map_err(|_| "Library archive has no manifest.json".to_string())?;
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .map_err(|e| format!("Failed to read manifest: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse manifest: {}", e))?
+    };
+    // A newer format may store files in ways this version doesn't know to read back
+    if manifest.version > MANIFEST_VERSION {
+        return Err(format!(
+            "This library was exported by a newer version of SkillHub (format {}, this version \
+             reads up to {}). Update SkillHub to import it.",
+            manifest.version, MANIFEST_VERSION
+        ));
+    }
+
+    let mut skills: HashMap<String, SkillFiles> = HashMap::new();
+    let mut blobs: HashMap<String, Vec<u8>> = HashMap::new();
+
+    for i in 0..archive.len() {
+        let mut file = archive
+            .by_index(i)
+            .map_err(|e| format!("Failed to read archive entry: {}", e))?;
+        if file.is_dir() {
+            continue;
+        }
+
+        let name = file.name().to_string();
+        if name == MANIFEST_FILE {
+            continue;
+        }
+
+        // Guard against zip-slip: every entry must stay inside skills/<slug>/
+        let relative = safe_relative_path(&name)
+            .ok_or_else(|| format!("Unsafe path in library archive: {}", name))?;
+        let mut components = relative.components();
+        match components.next().map(|c| c.as_os_str()) {
+            Some(prefix) if prefix == SKILLS_PREFIX => {}
+            Some(prefix) if prefix == BLOBS_PREFIX => {
+                if let (Some(hash), None) = (components.next(), components.next()) {
+                    let mut content = Vec::new();
+                    file.read_to_end(&mut content)
+                        .map_err(|e| format!("Failed to read {} from archive: {}", name, e))?;
+                    blobs.insert(hash.as_os_str().to_string_lossy().to_string(), content);
+                }
+                continue;
+            }
+            _ => continue,
+        }
+        let slug = match components.next() {
+            Some(c) => c.as_os_str().to_string_lossy().to_string(),
+            None => continue,
+        };
+        let filepath = components
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join("/");
+        if filepath.is_empty() {
+            continue;
+        }
+
+        let mut content = Vec::new();
+        file.read_to_end(&mut content)
+            .map_err(|e| format!("Failed to read {} from archive: {}", name, e))?;
+
+        skills.entry(slug).or_default().push((filepath, content));
+    }
+
+    // A missing blob leaves the file out, which the content hash check then reports
+    for entry in &manifest.skills {
+        for (filepath, hash) in &entry.shared_files {
+            match blobs.get(hash) {
+                Some(content) => skills
+                    .entry(entry.slug.clone())
+                    .or_default()
+                    .push((filepath.clone(), content.clone())),
+                None => tracing::warn!("Library archive is missing shared file {}", hash),
+            }
+        }
+    }
+
+    Ok((manifest, skills))
+}
+
+/// Import a library ZIP produced by export_library into the given tools
+pub async fn import_library(
+    zip_path: &str,
+    tool_ids: &[String],
+    on_conflict: &str,
+) -> Result<Vec<ImportSkillResult>, String> {
+    let policy = tools::parse_conflict_policy(on_conflict)?;
+
+    let data = fs::read(zip_path)
+        .await
+        .map_err(|e| format!("Failed to read library archive: {}", e))?;
+    let (manifest, mut archive_skills) = read_library_archive(data)?;
+
+    // Check space for every target up front so an import never stops halfway
+    let required_bytes: u64 = archive_skills
+        .values()
+        .flatten()
+        .map(|(_, content)| content.len() as u64)
+        .sum();
+    for tool_id in tool_ids {
+        if let Ok(dir) = tools::get_tool_skills_dir(tool_id) {
+            crate::disk::ensure_free_space(&dir, required_bytes)?;
+        }
+    }
+
+    let mut results = Vec::new();
+
+    for entry in manifest.skills {
+        let mut result = ImportSkillResult {
+            slug: entry.slug.clone(),
+            name: entry.name.clone(),
+            hash_verified: false,
+            installed_paths: Vec::new(),
+            skipped_tools: Vec::new(),
+            warnings: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        // The slug becomes a folder name, so it must be a single safe component
+        let slug_is_safe = safe_relative_path(&entry.slug)
+            .map(|p| p.components().count() == 1)
+            .unwrap_or(false);
+        if !slug_is_safe {
+            result.errors.push(format!("Unsafe skill slug: {}", entry.slug));
+            results.push(result);
+            continue;
+        }
+
+        let Some(mut files) = archive_skills.remove(&entry.slug) else {
+            result.errors.push("Skill files are missing from the archive".to_string());
+            results.push(result);
+            continue;
+        };
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+
+        result.hash_verified = compute_skill_hash(&files) == entry.content_hash;
+        if !result.hash_verified {
+            result
+                .warnings
+                .push("Content hash does not match the manifest".to_string());
+        }
+
+        for tool_id in tool_ids {
+            let skill_dir = match tools::get_tool_skills_dir(tool_id) {
+                Ok(dir) => dir.join(&entry.slug),
+                Err(e) => {
+                    result.errors.push(format!("{}: {}", tool_id, e));
+                    continue;
+                }
+            };
+
+            if skill_dir.exists() {
+                match policy {
+                    ConflictPolicy::Error => {
+                        result
+                            .errors
+                            .push(format!("{}: skill '{}' already exists", tool_id, entry.slug));
+                        continue;
+                    }
+                    ConflictPolicy::Skip => {
+                        result.skipped_tools.push(tool_id.clone());
+                        continue;
+                    }
+                    ConflictPolicy::Overwrite => {
+                        if let Err(e) = fs::remove_dir_all(&skill_dir).await {
+                            result
+                                .errors
+                                .push(format!("{}: failed to remove existing skill: {}", tool_id, e));
+                            continue;
+                        }
+                    }
+                    ConflictPolicy::Merge => {}
+                }
+            }
+
+            match write_skill_files(&skill_dir, &files).await {
+                Ok(()) => result
+                    .installed_paths
+                    .push(skill_dir.to_string_lossy().to_string()),
+                Err(e) => result.errors.push(format!("{}: {}", tool_id, e)),
+            }
+        }
+
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+/// Write a skill's files into a directory, creating parent directories as needed
+pub(crate) async fn write_skill_files(skill_dir: &Path, files: &[(String, Vec<u8>)]) -> Result<(), String> {
+    for (filepath, content) in files {
+        let relative = safe_relative_path(filepath)
+            .ok_or_else(|| format!("Unsafe file path: {}", filepath))?;
+        let file_path = tools::long_path_safe(skill_dir.join(relative))?;
+
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+
+        fs::write(&file_path, content)
+            .await
+            .map_err(|e| format!("Failed to write file {}: {}", filepath, e))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest_for(bundle: &[(String, SkillFiles)], version: u32) -> LibraryManifest {
+        LibraryManifest {
+            version,
+            tool_id: "claude".to_string(),
+            exported_at: 0,
+            skills: bundle
+                .iter()
+                .map(|(slug, files)| LibraryManifestEntry {
+                    slug: slug.clone(),
+                    name: slug.clone(),
+                    author: None,
+                    content_hash: compute_skill_hash(files),
+                    files: files.iter().map(|(path, _)| path.clone()).collect(),
+                    shared_files: BTreeMap::new(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn shared_files_are_stored_once_and_restored_on_import() {
+        let shared = vec![b'x'; DEDUPE_MIN_SIZE * 2];
+        let original: Vec<(String, SkillFiles)> = vec![
+            (
+                "alpha".to_string(),
+                vec![
+                    ("SKILL.md".to_string(), b"# Alpha\n".to_vec()),
+                    ("scripts/common.py".to_string(), shared.clone()),
+                ],
+            ),
+            (
+                "beta".to_string(),
+                vec![
+                    ("SKILL.md".to_string(), b"# Beta\n".to_vec()),
+                    ("lib/common.py".to_string(), shared.clone()),
+                ],
+            ),
+        ];
+        let mut manifest = manifest_for(&original, MANIFEST_VERSION);
+        let mut bundle = original.clone();
+
+        let blobs = dedupe_files(&mut manifest, &mut bundle);
+        let data = write_zip(&manifest, &bundle, &blobs).unwrap();
+
+        assert_eq!(blobs.len(), 1);
+        let archive = zip::ZipArchive::new(Cursor::new(data.clone())).unwrap();
+        assert!(!archive.file_names().any(|name| name.ends_with("common.py")));
+
+        let (imported, mut skills) = read_library_archive(data).unwrap();
+        for (slug, mut expected) in original {
+            let mut restored = skills.remove(&slug).unwrap();
+            restored.sort();
+            expected.sort();
+            assert_eq!(restored, expected);
+
+            let entry = imported.skills.iter().find(|e| e.slug == slug).unwrap();
+            assert_eq!(compute_skill_hash(&restored), entry.content_hash);
+        }
+    }
+
+    #[test]
+    fn archives_from_a_newer_version_are_rejected() {
+        let bundle = vec![(
+            "alpha".to_string(),
+            vec![("SKILL.md".to_string(), b"# Alpha\n".to_vec())],
+        )];
+        let manifest = manifest_for(&bundle, MANIFEST_VERSION + 1);
+        let data = write_zip(&manifest, &bundle, &BTreeMap::new()).unwrap();
+
+        let error = read_library_archive(data).unwrap_err();
+        assert!(error.contains("newer version"), "{}", error);
+    }
+}
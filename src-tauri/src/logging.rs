@@ -0,0 +1,86 @@
+// Structured logging to a daily-rotated file under ~/.skillhub/logs/
+// Packaged GUI builds have no visible console, so the log file is what users attach to bug reports
+
+use std::sync::OnceLock;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{fmt, reload, Registry};
+
+const LOG_FILE_PREFIX: &str = "skillhub";
+const LOG_FILE_SUFFIX: &str = "log";
+const MAX_LOG_FILES: usize = 7;
+
+// Keeps the background writer alive for the lifetime of the app
+static LOG_GUARD: OnceLock<WorkerGuard> = OnceLock::new();
+static LEVEL_HANDLE: OnceLock<reload::Handle<LevelFilter, Registry>> = OnceLock::new();
+
+/// Get the log directory path (~/.skillhub/logs)
+pub fn get_log_dir() -> Result<std::path::PathBuf, String> {
+    Ok(crate::installer::get_skillhub_config_dir()?.join("logs"))
+}
+
+/// Path of the log file being written: the newest `skillhub.<date>.log` in the log directory
+pub fn get_log_file() -> Result<std::path::PathBuf, String> {
+    let dir = get_log_dir()?;
+    let entries = std::fs::read_dir(&dir)
+        .map_err(|e| format!("Failed to read log directory {}: {}", dir.display(), e))?;
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            name.starts_with(LOG_FILE_PREFIX) && name.ends_with(LOG_FILE_SUFFIX)
+        })
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+        .ok_or_else(|| format!("No log file has been written in {} yet", dir.display()))
+}
+
+/// Initialize logging to stdout and to the rotating log file.
+/// Falls back to stdout only if the log directory can't be created.
+pub fn init() {
+    let (level_layer, level_handle) = reload::Layer::new(LevelFilter::INFO);
+
+    let file_layer = get_log_dir().ok().and_then(|dir| {
+        std::fs::create_dir_all(&dir).ok()?;
+        let appender = RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix(LOG_FILE_PREFIX)
+            .filename_suffix(LOG_FILE_SUFFIX)
+            .max_log_files(MAX_LOG_FILES)
+            .build(&dir)
+            .ok()?;
+        let (writer, guard) = tracing_appender::non_blocking(appender);
+        let _ = LOG_GUARD.set(guard);
+        Some(fmt::layer().with_writer(writer).with_ansi(false))
+    });
+
+    let initialized = tracing_subscriber::registry()
+        .with(level_layer)
+        .with(file_layer)
+        .with(fmt::layer())
+        .try_init()
+        .is_ok();
+
+    if initialized {
+        let _ = LEVEL_HANDLE.set(level_handle);
+    }
+}
+
+/// Change the log level at runtime ("trace", "debug", "info", "warn", "error" or "off")
+pub fn set_log_level(level: &str) -> Result<(), String> {
+    let filter: LevelFilter = level
+        .parse()
+        .map_err(|_| format!("Invalid log level: {}", level))?;
+
+    let handle = LEVEL_HANDLE.get().ok_or("Logging is not initialized")?;
+    handle
+        .modify(|current| *current = filter)
+        .map_err(|e| format!("Failed to set log level: {}", e))?;
+
+    tracing::info!("Log level set to {}", filter);
+    Ok(())
+}
@@ -0,0 +1,102 @@
+// File-level merge of one skill into another, e.g. to reconcile two tools' copies of a skill
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::fs;
+
+use crate::{library, sync};
+
+/// How to resolve a file that exists in both skills with different contents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MergeStrategy {
+    PreferIncoming,
+    PreferBase,
+    OnlyAddNew,
+}
+
+fn parse_merge_strategy(value: &str) -> Result<MergeStrategy, String> {
+    match value {
+        "prefer_incoming" => Ok(MergeStrategy::PreferIncoming),
+        "prefer_base" => Ok(MergeStrategy::PreferBase),
+        "only_add_new" => Ok(MergeStrategy::OnlyAddNew),
+        _ => Err(format!("Invalid merge strategy: {}", value)),
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MergeReport {
+    pub added: Vec<String>,       // only in incoming, copied into base
+    pub overwritten: Vec<String>, // differed, replaced with the incoming version
+    pub kept: Vec<String>,        // differed, base version kept
+}
+
+/// Merge the files of `incoming_path` into the skill folder at `base_path`.
+/// Files only in incoming are always added and files only in base are left alone;
+/// `strategy` decides files that differ ("prefer_incoming" overwrites them, while
+/// "prefer_base" and "only_add_new" keep the base version).
+pub async fn merge_skills(
+    base_path: &str,
+    incoming_path: &str,
+    strategy: &str,
+) -> Result<MergeReport, String> {
+    let strategy = parse_merge_strategy(strategy)?;
+
+    let base_dir = Path::new(base_path);
+    if !base_dir.is_dir() {
+        return Err(format!("Base skill is not a folder: {}", base_path));
+    }
+    let incoming_dir = Path::new(incoming_path);
+    if !incoming_dir.exists() {
+        return Err(format!("Path does not exist: {}", incoming_path));
+    }
+
+    let base_files = library::read_skill_files(base_dir).await?;
+    let incoming_files = library::read_skill_files(incoming_dir).await?;
+
+    let mut report = MergeReport::default();
+    for (filepath, content) in incoming_files {
+        let existing = base_files
+            .iter()
+            .find(|(path, _)| *path == filepath)
+            .map(|(_, content)| content);
+
+        match existing {
+            Some(existing) if *existing == content => continue,
+            Some(_) if strategy != MergeStrategy::PreferIncoming => {
+                report.kept.push(filepath);
+                continue;
+            }
+            Some(_) => {
+                write_merged_file(base_dir, &filepath, &content).await?;
+                report.overwritten.push(filepath);
+            }
+            None => {
+                write_merged_file(base_dir, &filepath, &content).await?;
+                report.added.push(filepath);
+            }
+        }
+    }
+
+    tracing::info!(
+        "Merged {} into {}: {} added, {} overwritten, {} kept",
+        incoming_path,
+        base_path,
+        report.added.len(),
+        report.overwritten.len(),
+        report.kept.len()
+    );
+    Ok(report)
+}
+
+async fn write_merged_file(base_dir: &Path, filepath: &str, content: &[u8]) -> Result<(), String> {
+    // Never write outside the base skill, whatever the incoming folder contains
+    let dest = base_dir.join(sync::from_portable_path(filepath)?);
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create directory: {}", e))?;
+    }
+    fs::write(&dest, content)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", filepath, e))
+}
@@ -0,0 +1,254 @@
+// Fixing up imported skills that don't follow the standard layout (SKILL.md at the folder root)
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::sync;
+
+const SKILL_FILE: &str = "SKILL.md";
+const README_FILE: &str = "README.md";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NormalizeReport {
+    pub changes: Vec<String>, // in the order applied; empty if the skill was already standard
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncodingReport {
+    pub file: String,
+    pub has_bom: bool,
+    pub line_ending: String, // "lf", "crlf", "cr", "mixed" or "none" (single line)
+    pub is_valid_utf8: bool,
+}
+
+/// Bring a skill folder into the standard layout: the primary markdown file is found
+/// (SKILL.md in any case, else the only *.md, else README.md), hoisted to the folder root
+/// if the skill is nested one level down, and renamed to SKILL.md.
+/// Ambiguous folders (several candidate files) are refused without changing anything.
+pub async fn normalize_skill(skill_path: &str) -> Result<NormalizeReport, String> {
+    let root = Path::new(skill_path);
+    if !root.is_dir() {
+        return Err(format!("Skill is not a folder: {}", skill_path));
+    }
+
+    let mut report = NormalizeReport::default();
+    let mut entries = visible_entries(root).await?;
+
+    if find_primary_markdown(&entries)?.is_none() {
+        // The real skill may sit one level down, e.g. the top folder of an extracted archive
+        let nested = match entries.as_slice() {
+            [only] if only.is_dir() => only.clone(),
+            _ => return Err(format!("No skill markdown file found in {}", skill_path)),
+        };
+        if find_primary_markdown(&visible_entries(&nested).await?)?.is_none() {
+            return Err(format!("No skill markdown file found in {}", skill_path));
+        }
+
+        hoist_contents(root, &nested).await?;
+        report.changes.push(format!(
+            "Moved the contents of {}/ up to the skill folder",
+            sync::checked_file_name(&nested).unwrap_or_default()
+        ));
+        entries = visible_entries(root).await?;
+    }
+
+    let primary = find_primary_markdown(&entries)?
+        .ok_or_else(|| format!("No skill markdown file found in {}", skill_path))?;
+    let name = sync::checked_file_name(&primary).unwrap_or_default();
+    if name != SKILL_FILE {
+        // Go through a temporary name so case-only renames work on case-insensitive filesystems
+        let temp = root.join(".skillhub-normalize-skill-md");
+        fs::rename(&primary, &temp)
+            .await
+            .map_err(|e| format!("Failed to rename {}: {}", name, e))?;
+        fs::rename(&temp, root.join(SKILL_FILE))
+            .await
+            .map_err(|e| format!("Failed to rename {} to {}: {}", name, SKILL_FILE, e))?;
+        report.changes.push(format!("Renamed {} to {}", name, SKILL_FILE));
+    }
+
+    tracing::info!("Normalized {}: {} changes", skill_path, report.changes.len());
+    Ok(report)
+}
+
+/// Entries of a folder other than hidden ones (.skillhub.json, .DS_Store, ...), sorted
+async fn visible_entries(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut entries = fs::read_dir(dir)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+
+    let mut paths = Vec::new();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if sync::checked_file_name(&path).is_some_and(|name| !name.starts_with('.')) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Pick the skill's primary markdown file among a folder's entries.
+/// Ok(None) means there is no markdown file at all; several candidates is an error.
+fn find_primary_markdown(entries: &[PathBuf]) -> Result<Option<PathBuf>, String> {
+    let markdown: Vec<(&PathBuf, String)> = entries
+        .iter()
+        .filter(|path| path.is_file())
+        .filter_map(|path| Some((path, sync::checked_file_name(path)?)))
+        .filter(|(_, name)| {
+            Path::new(name)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+        })
+        .collect();
+
+    let named = |wanted: &str| -> Vec<&(&PathBuf, String)> {
+        markdown
+            .iter()
+            .filter(|(_, name)| name.eq_ignore_ascii_case(wanted))
+            .collect()
+    };
+    let ambiguous = |candidates: &[&(&PathBuf, String)]| {
+        let names: Vec<&str> = candidates.iter().map(|(_, name)| name.as_str()).collect();
+        format!(
+            "Can't tell which file is the skill ({}); rename one to {}",
+            names.join(", "),
+            SKILL_FILE
+        )
+    };
+
+    let skill_files = named(SKILL_FILE);
+    match skill_files.as_slice() {
+        [(path, _)] => return Ok(Some((*path).clone())),
+        [] => {}
+        candidates => return Err(ambiguous(candidates)),
+    }
+
+    if let [(path, _)] = markdown.as_slice() {
+        return Ok(Some((*path).clone()));
+    }
+    if markdown.is_empty() {
+        return Ok(None);
+    }
+
+    match named(README_FILE).as_slice() {
+        [(path, _)] => Ok(Some((*path).clone())),
+        _ => Err(ambiguous(&markdown.iter().collect::<Vec<_>>())),
+    }
+}
+
+/// Move everything in `nested` (hidden files included) into `root` and remove `nested`.
+/// Name clashes are checked first so a conflict doesn't leave the skill half moved.
+async fn hoist_contents(root: &Path, nested: &Path) -> Result<(), String> {
+    let mut children = Vec::new();
+    let mut entries = fs::read_dir(nested)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", nested.display(), e))?;
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        let name = sync::checked_file_name(&path)
+            .ok_or_else(|| format!("Invalid file name in {}", nested.display()))?;
+        let dest = root.join(&name);
+        if dest.exists() && dest != nested {
+            return Err(format!("Cannot move {} up: {} already exists", name, dest.display()));
+        }
+        children.push(name);
+    }
+
+    // Move the nested folder aside first, in case it contains an entry with its own name
+    let staging = root.join(".skillhub-normalize-staging");
+    fs::rename(nested, &staging)
+        .await
+        .map_err(|e| format!("Failed to move {}: {}", nested.display(), e))?;
+
+    for name in children {
+        fs::rename(staging.join(&name), root.join(&name))
+            .await
+            .map_err(|e| format!("Failed to move {}: {}", name, e))?;
+    }
+    fs::remove_dir(&staging)
+        .await
+        .map_err(|e| format!("Failed to remove {}: {}", staging.display(), e))
+}
+
+/// The SKILL.md a path refers to: the path itself if it's a file, else the folder's SKILL.md
+/// (in any case)
+async fn skill_md_path(skill_path: &str) -> Result<PathBuf, String> {
+    let path = Path::new(skill_path);
+    if path.is_file() {
+        return Ok(path.to_path_buf());
+    }
+    visible_entries(path)
+        .await?
+        .into_iter()
+        .find(|entry| {
+            entry.is_file()
+                && sync::checked_file_name(entry).is_some_and(|n| n.eq_ignore_ascii_case(SKILL_FILE))
+        })
+        .ok_or_else(|| format!("No {} found in {}", SKILL_FILE, skill_path))
+}
+
+/// Check a skill's SKILL.md for a UTF-8 BOM, Windows or old Mac line endings and bytes
+/// that aren't UTF-8 (typically Windows-1252 from older Windows editors)
+pub async fn check_skill_encoding(skill_path: &str) -> Result<EncodingReport, String> {
+    let file = skill_md_path(skill_path).await?;
+    let bytes = fs::read(&file)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+
+    let crlf = bytes.windows(2).filter(|w| w == b"\r\n").count();
+    let cr = bytes.iter().filter(|b| **b == b'\r').count() - crlf;
+    let lf = bytes.iter().filter(|b| **b == b'\n').count() - crlf;
+    let line_ending = match (lf > 0, crlf > 0, cr > 0) {
+        (false, false, false) => "none",
+        (true, false, false) => "lf",
+        (false, true, false) => "crlf",
+        (false, false, true) => "cr",
+        _ => "mixed",
+    };
+
+    Ok(EncodingReport {
+        file: file.to_string_lossy().to_string(),
+        has_bom: bytes.starts_with(UTF8_BOM),
+        line_ending: line_ending.to_string(),
+        is_valid_utf8: std::str::from_utf8(&bytes).is_ok(),
+    })
+}
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Rewrite a skill's SKILL.md as UTF-8 without a BOM and with LF line endings.
+/// Content that isn't valid UTF-8 is read as Windows-1252, what Windows editors save by default.
+pub async fn normalize_skill_encoding(skill_path: &str) -> Result<NormalizeReport, String> {
+    let file = skill_md_path(skill_path).await?;
+    let bytes = fs::read(&file)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+    let mut report = NormalizeReport::default();
+
+    let mut content = match String::from_utf8(bytes) {
+        Ok(content) => content,
+        Err(e) => {
+            report.changes.push("Converted from Windows-1252 to UTF-8".to_string());
+            let (decoded, _) = encoding_rs::WINDOWS_1252.decode_without_bom_handling(e.as_bytes());
+            decoded.into_owned()
+        }
+    };
+    if let Some(rest) = content.strip_prefix('\u{FEFF}') {
+        content = rest.to_string();
+        report.changes.push("Removed the byte order mark".to_string());
+    }
+    if content.contains('\r') {
+        content = content.replace("\r\n", "\n").replace('\r', "\n");
+        report.changes.push("Converted line endings to LF".to_string());
+    }
+
+    if !report.changes.is_empty() {
+        fs::write(&file, content)
+            .await
+            .map_err(|e| format!("Failed to write {}: {}", file.display(), e))?;
+        tracing::info!("Normalized encoding of {}: {}", file.display(), report.changes.join(", "));
+    }
+    Ok(report)
+}
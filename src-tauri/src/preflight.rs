@@ -0,0 +1,175 @@
+// Checks run before the guided dependency install, so a failing install is reported as a
+// specific, fixable problem (no permission, npm not on PATH, full disk) instead of "try again"
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::{disk, tools};
+
+/// Free space wanted on the home volume for Node.js plus Claude Code
+const MIN_FREE_BYTES: u64 = 500 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreflightIssue {
+    pub id: String, // "home_not_writable", "npm_prefix_not_writable", "npm_bin_not_on_path", "low_disk_space"
+    pub message: String,
+    pub remediation: String,
+    pub blocking: bool, // the install will fail, rather than succeed but not be found afterwards
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreflightReport {
+    pub ready: bool, // no blocking issues
+    pub issues: Vec<PreflightIssue>,
+    pub npm_prefix: Option<String>, // None when npm isn't installed yet
+}
+
+/// npm's global prefix (`npm prefix -g`), or None if npm can't be run or hangs
+pub(crate) async fn npm_global_prefix() -> Option<PathBuf> {
+    let output = tools::run_cli("npm", &["prefix", "-g"]).await?;
+    let prefix = output.trim();
+    (!prefix.is_empty()).then(|| PathBuf::from(prefix))
+}
+
+/// Where npm puts globally installed commands: the prefix itself on Windows, prefix/bin elsewhere
+pub(crate) fn npm_global_bin(prefix: &Path) -> PathBuf {
+    if cfg!(windows) {
+        prefix.to_path_buf()
+    } else {
+        prefix.join("bin")
+    }
+}
+
+/// The directories on this process's PATH, in order
+pub(crate) fn path_entries() -> Vec<PathBuf> {
+    std::env::var_os("PATH")
+        .map(|path| std::env::split_paths(&path).filter(|p| !p.as_os_str().is_empty()).collect())
+        .unwrap_or_default()
+}
+
+/// Whether `dir` is on PATH, ignoring trailing separators (and case on Windows)
+pub(crate) fn path_contains(dir: &Path) -> bool {
+    let normalize = |p: &Path| {
+        let s = p.to_string_lossy();
+        let s = s.trim_end_matches(['/', '\\']);
+        if cfg!(windows) {
+            s.to_lowercase()
+        } else {
+            s.to_string()
+        }
+    };
+    let wanted = normalize(dir);
+    path_entries().iter().any(|entry| normalize(entry) == wanted)
+}
+
+/// PATH as this process sees it. Apps started from the GUI often get a shorter PATH than a
+/// terminal, which makes installed tools look missing.
+pub fn get_effective_path() -> Vec<String> {
+    path_entries()
+        .iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect()
+}
+
+/// Whether npm's global bin directory (where `claude` is installed) is on PATH.
+/// False when npm itself can't be found.
+pub async fn path_contains_npm_global() -> bool {
+    npm_global_prefix()
+        .await
+        .is_some_and(|prefix| path_contains(&npm_global_bin(&prefix)))
+}
+
+/// Check that the guided install can succeed: the home directory and npm's global prefix
+/// are writable, npm's global bin is on PATH, and the disk has room.
+/// The disk checks are kept off the async workers.
+pub async fn preflight_install() -> Result<PreflightReport, String> {
+    let npm_prefix = npm_global_prefix().await;
+    tokio::task::spawn_blocking(move || check_install_preconditions(npm_prefix))
+        .await
+        .map_err(|e| format!("Failed to run install preflight: {}", e))?
+}
+
+fn check_install_preconditions(npm_prefix: Option<PathBuf>) -> Result<PreflightReport, String> {
+    let home = crate::home::home_dir()?;
+    let mut issues = Vec::new();
+
+    let home_status = disk::check_writable(&home);
+    if !home_status.writable {
+        issues.push(PreflightIssue {
+            id: "home_not_writable".to_string(),
+            message: home_status
+                .reason
+                .unwrap_or_else(|| format!("Cannot write to {}", home.display())),
+            remediation: format!(
+                "Make sure your user account owns {} and it isn't on a read-only or synced drive",
+                home.display()
+            ),
+            blocking: true,
+        });
+    }
+
+    // Without npm the guided install sets up Node.js first, which brings its own prefix
+    if let Some(prefix) = &npm_prefix {
+        let prefix_status = disk::check_writable(prefix);
+        if !prefix_status.writable {
+            issues.push(PreflightIssue {
+                id: "npm_prefix_not_writable".to_string(),
+                message: format!(
+                    "npm installs global packages into {}, which needs administrator rights",
+                    prefix.display()
+                ),
+                remediation: if cfg!(windows) {
+                    "Run SkillHub as administrator, or run `npm config set prefix %APPDATA%\\npm` to install into your user folder".to_string()
+                } else {
+                    "Run `npm config set prefix ~/.npm-global` and add ~/.npm-global/bin to your PATH, or install Node.js with a version manager such as nvm".to_string()
+                },
+                blocking: true,
+            });
+        }
+
+        let bin = npm_global_bin(prefix);
+        if !path_contains(&bin) {
+            issues.push(PreflightIssue {
+                id: "npm_bin_not_on_path".to_string(),
+                message: format!(
+                    "{} is not on PATH, so `claude` won't be found after it's installed",
+                    bin.display()
+                ),
+                remediation: if cfg!(windows) {
+                    format!(
+                        "Add {} to your user Path in System Properties > Environment Variables, then restart SkillHub",
+                        bin.display()
+                    )
+                } else {
+                    format!(
+                        "Add `export PATH=\"{}:$PATH\"` to your shell config, then restart SkillHub",
+                        bin.display()
+                    )
+                },
+                blocking: false,
+            });
+        }
+    }
+
+    if let Ok(info) = disk::check_disk_space(&home.to_string_lossy()) {
+        if info.available_bytes < MIN_FREE_BYTES {
+            issues.push(PreflightIssue {
+                id: "low_disk_space".to_string(),
+                message: format!(
+                    "Only {} MB free, the install needs about {} MB",
+                    info.available_bytes / (1024 * 1024),
+                    MIN_FREE_BYTES / (1024 * 1024)
+                ),
+                remediation: "Free up disk space on the drive with your home folder".to_string(),
+                blocking: true,
+            });
+        }
+    }
+
+    tracing::info!("Install preflight found {} issues", issues.len());
+    Ok(PreflightReport {
+        ready: !issues.iter().any(|issue| issue.blocking),
+        issues,
+        npm_prefix: npm_prefix.map(|p| p.to_string_lossy().to_string()),
+    })
+}
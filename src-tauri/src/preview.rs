@@ -0,0 +1,132 @@
+// In-memory preview of a SKILL.md, for the catalog detail page
+// Nothing is written to disk, unlike the playground's temp installs
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use crate::tools::{self, SkillMetadata};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewSection {
+    pub heading: String, // empty for text before the first heading
+    pub level: usize,    // 1 for "#", 2 for "##", ...; 0 for text before the first heading
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewResult {
+    pub metadata: SkillMetadata,
+    pub sections: Vec<PreviewSection>,
+    pub referenced_files: Vec<String>, // relative links, e.g. "scripts/run.py"
+    pub issues: Vec<String>,
+    pub valid: bool,
+}
+
+/// Parse and check a SKILL.md without installing it anywhere
+pub fn preview_skill(content: &str) -> PreviewResult {
+    let metadata = tools::extract_metadata(content);
+    let (body, mut issues) = split_frontmatter(content);
+
+    if metadata.name.as_deref().unwrap_or_default().is_empty() {
+        issues.push("Skill has no name (add `name:` to the frontmatter)".to_string());
+    }
+    if metadata.description.as_deref().unwrap_or_default().is_empty() {
+        issues.push("Skill has no description (add `description:` to the frontmatter)".to_string());
+    }
+    if body.trim().is_empty() {
+        issues.push("Skill body is empty".to_string());
+    }
+
+    PreviewResult {
+        metadata,
+        sections: parse_sections(body),
+        referenced_files: referenced_files(body),
+        valid: issues.is_empty(),
+        issues,
+    }
+}
+
+/// Split off the frontmatter, returning the body and any problems with the frontmatter
+fn split_frontmatter(content: &str) -> (&str, Vec<String>) {
+    let Some(rest) = content.strip_prefix("---") else {
+        return (content, vec!["Missing frontmatter (--- block at the top)".to_string()]);
+    };
+
+    match rest.find("\n---") {
+        Some(end) => {
+            let body = &rest[end + 4..];
+            (body.strip_prefix('\n').unwrap_or(body), Vec::new())
+        }
+        None => ("", vec!["Frontmatter is not closed with ---".to_string()]),
+    }
+}
+
+/// Split the body into sections at each heading, ignoring `#` lines inside code blocks
+fn parse_sections(body: &str) -> Vec<PreviewSection> {
+    let mut sections = Vec::new();
+    let mut current = PreviewSection {
+        heading: String::new(),
+        level: 0,
+        body: String::new(),
+    };
+    let mut in_code_block = false;
+
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+
+        let level = line.chars().take_while(|c| *c == '#').count();
+        let is_heading = !in_code_block
+            && (1..=6).contains(&level)
+            && line[level..].starts_with(' ');
+
+        if is_heading {
+            let previous = std::mem::replace(
+                &mut current,
+                PreviewSection {
+                    heading: line[level..].trim().to_string(),
+                    level,
+                    body: String::new(),
+                },
+            );
+            if previous.level > 0 || !previous.body.trim().is_empty() {
+                sections.push(previous);
+            }
+        } else {
+            current.body.push_str(line);
+            current.body.push('\n');
+        }
+    }
+
+    if current.level > 0 || !current.body.trim().is_empty() {
+        sections.push(current);
+    }
+    for section in &mut sections {
+        section.body = section.body.trim().to_string();
+    }
+    sections
+}
+
+/// Relative file paths linked from the body, e.g. `[script](scripts/run.py)`
+fn referenced_files(body: &str) -> Vec<String> {
+    static LINK: OnceLock<Regex> = OnceLock::new();
+    let link = LINK.get_or_init(|| Regex::new(r"\]\(([^)\s]+)").expect("valid link regex"));
+
+    let mut files: Vec<String> = link
+        .captures_iter(body)
+        .filter_map(|caps| {
+            let target = caps.get(1)?.as_str();
+            let path = target.split('#').next().unwrap_or_default();
+            let external = target.contains("://") || target.starts_with("mailto:");
+            if external || path.is_empty() || path.starts_with('/') {
+                return None;
+            }
+            Some(path.trim_start_matches("./").to_string())
+        })
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
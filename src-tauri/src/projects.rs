@@ -0,0 +1,235 @@
+// Project directories skills have been installed into
+// Remembered in ~/.skillhub/projects.json so they can be found again from the app
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::fs;
+
+use crate::tools;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillProject {
+    pub path: String,
+    pub exists: bool,
+    pub skills_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectInstallResult {
+    pub path: String,
+    pub gitignore_updated: bool, // true if an entry was added to the project's .gitignore
+    pub skipped_files: Vec<String>, // left out by a SKILL.md-only install
+    pub verified: bool,             // false if any file came without an expected hash
+    pub unverified_files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GitInfo {
+    pub is_repo: bool,
+    pub current_branch: Option<String>, // None on a detached HEAD
+    pub is_dirty: bool,                 // uncommitted changes, including untracked files
+}
+
+/// Get the projects file path
+fn get_projects_path() -> Result<std::path::PathBuf, String> {
+    Ok(crate::installer::get_skillhub_config_dir()?.join("projects.json"))
+}
+
+/// Read the remembered project paths
+async fn read_projects() -> Vec<String> {
+    let Ok(path) = get_projects_path() else {
+        return Vec::new();
+    };
+
+    match fs::read_to_string(&path).await {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    }
+}
+
+async fn write_projects(projects: &[String]) -> Result<(), String> {
+    let path = get_projects_path()?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+
+    let content = serde_json::to_string_pretty(projects)
+        .map_err(|e| format!("Failed to serialize projects: {}", e))?;
+
+    fs::write(&path, content)
+        .await
+        .map_err(|e| format!("Failed to write projects: {}", e))
+}
+
+/// Remember a project a skill was installed into (best effort, installs never fail because of it)
+pub async fn record_project(project_path: &str) {
+    let mut projects = read_projects().await;
+    if projects.iter().any(|p| p == project_path) {
+        return;
+    }
+
+    projects.push(project_path.to_string());
+    if let Err(e) = write_projects(&projects).await {
+        tracing::warn!("Failed to record project {}: {}", project_path, e);
+    }
+}
+
+/// List remembered projects with whether they still exist and how many skills they contain
+pub async fn list_skill_projects() -> Result<Vec<SkillProject>, String> {
+    let mut projects = Vec::new();
+
+    for path in read_projects().await {
+        let exists = Path::new(&path).is_dir();
+        let skills_count = if exists {
+            tools::get_project_skills(&path).await.len()
+        } else {
+            0
+        };
+
+        projects.push(SkillProject {
+            path,
+            exists,
+            skills_count,
+        });
+    }
+
+    Ok(projects)
+}
+
+/// Forget a project (it is only removed from the list, nothing on disk is touched)
+pub async fn remove_skill_project(project_path: &str) -> Result<(), String> {
+    let mut projects = read_projects().await;
+    projects.retain(|p| p != project_path);
+    write_projects(&projects).await
+}
+
+/// Make sure an installed skill folder is listed in the project's root .gitignore,
+/// creating the file if needed. Returns false if an equivalent entry was already there.
+pub async fn add_to_gitignore(project_path: &str, installed_path: &str) -> Result<bool, String> {
+    let project_dir = Path::new(project_path);
+    let installed = Path::new(installed_path);
+    // Single-file installs report the SKILL.md path; ignore the whole skill folder
+    let skill_dir = if installed.is_file() {
+        installed.parent().unwrap_or(installed)
+    } else {
+        installed
+    };
+
+    let relative = skill_dir
+        .strip_prefix(project_dir)
+        .map_err(|_| format!("{} is not inside {}", installed_path, project_path))?;
+    let relative = crate::sync::to_portable_path(relative);
+    let entry = format!("/{}/", relative.trim_matches('/'));
+
+    let gitignore_path = project_dir.join(".gitignore");
+    let existing = match fs::read_to_string(&gitignore_path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Failed to read .gitignore: {}", e)),
+    };
+
+    // Treat "/.claude/skills/x/", ".claude/skills/x" etc. as the same entry
+    let normalize = |line: &str| line.trim().trim_matches('/').to_string();
+    if existing.lines().any(|line| normalize(line) == normalize(&entry)) {
+        return Ok(false);
+    }
+
+    let mut content = existing;
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(&entry);
+    content.push('\n');
+
+    fs::write(&gitignore_path, content)
+        .await
+        .map_err(|e| format!("Failed to write .gitignore: {}", e))?;
+
+    Ok(true)
+}
+
+/// Git state of a project, so a project install can warn about the branch it lands on.
+/// Uses the git CLI; without it the branch is read from .git/HEAD and `is_dirty` is false.
+pub async fn get_project_git_info(project_path: &str) -> Result<GitInfo, String> {
+    let project_dir = Path::new(project_path);
+    if !project_dir.is_dir() {
+        return Err(format!("Project directory does not exist: {}", project_path));
+    }
+
+    let output = tokio::process::Command::new("git")
+        .arg("-C")
+        .arg(project_dir)
+        .args(["status", "--porcelain=v1", "--branch"])
+        // Read-only query, don't take the index lock
+        .env("GIT_OPTIONAL_LOCKS", "0")
+        .output()
+        .await;
+    let output = match output {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::debug!("git is not available ({}), reading .git/HEAD", e);
+            return Ok(read_git_head(project_dir).await);
+        }
+    };
+    if !output.status.success() {
+        // Exits with an error outside a work tree
+        return Ok(GitInfo::default());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let current_branch = lines
+        .next()
+        .and_then(|line| line.strip_prefix("## "))
+        .and_then(parse_status_branch);
+    Ok(GitInfo {
+        is_repo: true,
+        current_branch,
+        is_dirty: lines.any(|line| !line.trim().is_empty()),
+    })
+}
+
+/// Branch name from the header of `git status --branch`, e.g. "main...origin/main [ahead 1]"
+/// or "No commits yet on main"; None for "HEAD (no branch)"
+fn parse_status_branch(header: &str) -> Option<String> {
+    let header = header
+        .strip_prefix("No commits yet on ")
+        .or_else(|| header.strip_prefix("Initial commit on "))
+        .unwrap_or(header);
+    if header.starts_with("HEAD (no branch)") {
+        return None;
+    }
+    let branch = header.split("...").next()?.split(' ').next()?;
+    (!branch.is_empty()).then(|| branch.to_string())
+}
+
+/// Fallback when git isn't installed: a .git directory or file marks a repository and
+/// HEAD names the branch
+async fn read_git_head(project_dir: &Path) -> GitInfo {
+    let git_path = project_dir.join(".git");
+    let git_dir = if git_path.is_dir() {
+        git_path
+    } else {
+        // Worktrees and submodules have a .git file pointing at the real directory
+        match fs::read_to_string(&git_path).await {
+            Ok(content) => match content.trim().strip_prefix("gitdir:") {
+                Some(dir) => project_dir.join(dir.trim()),
+                None => return GitInfo::default(),
+            },
+            Err(_) => return GitInfo::default(),
+        }
+    };
+
+    let head = fs::read_to_string(git_dir.join("HEAD")).await.unwrap_or_default();
+    GitInfo {
+        is_repo: true,
+        current_branch: head
+            .trim()
+            .strip_prefix("ref: refs/heads/")
+            .map(|branch| branch.to_string()),
+        is_dirty: false,
+    }
+}
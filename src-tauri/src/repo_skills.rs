@@ -0,0 +1,192 @@
+// Finding the skills in a GitHub repository, for installing from a repo URL
+// A repo can hold one skill at its root, one a few folders deep, or many (a monorepo);
+// every folder with a SKILL.md is offered as a candidate for the user to pick from
+
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{installer, tools};
+
+/// Folders below the starting path searched for a SKILL.md (0 = the starting folder only)
+const MAX_DISCOVERY_DEPTH: usize = 4;
+/// Most candidates returned for one repo
+const MAX_CANDIDATES: usize = 50;
+/// Max SKILL.md files fetched at once for metadata
+const FETCH_CONCURRENCY: usize = 8;
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillCandidate {
+    pub path: String, // folder in the repo holding SKILL.md, "" for the repo root
+    pub skill_md_path: String,
+    pub metadata: Option<tools::SkillMetadata>, // None if SKILL.md couldn't be fetched
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoSkillDiscovery {
+    pub owner: String,
+    pub repo: String,
+    pub branch: String,
+    pub candidates: Vec<SkillCandidate>, // shallowest first
+    pub truncated: bool, // more skills exist than were returned, or GitHub cut the tree short
+}
+
+/// A GitHub URL split into its parts; the path may come from /tree/<branch>/<path>
+/// or a catalog-style #dir~sub hash
+struct RepoRef {
+    owner: String,
+    repo: String,
+    branch: Option<String>,
+    path: String,
+}
+
+fn parse_repo_url(repo_url: &str) -> Option<RepoRef> {
+    let (url, hash) = match repo_url.split_once('#') {
+        Some((url, hash)) => (url, Some(hash.replace('~', "/"))),
+        None => (repo_url, None),
+    };
+    let rest = url.split("github.com/").nth(1)?;
+    let mut parts = rest.split('/').filter(|p| !p.is_empty());
+    let owner = parts.next()?.to_string();
+    let repo = parts.next()?.trim_end_matches(".git").to_string();
+    if repo.is_empty() {
+        return None;
+    }
+
+    let (branch, tree_path) = match parts.next() {
+        Some("tree" | "blob") => (parts.next().map(str::to_string), parts.collect::<Vec<_>>().join("/")),
+        _ => (None, String::new()),
+    };
+    let path = hash.unwrap_or(tree_path).trim_matches('/').to_string();
+    Some(RepoRef { owner, repo, branch, path })
+}
+
+/// List the skills in a GitHub repo: every SKILL.md at most MAX_DISCOVERY_DEPTH folders
+/// below the URL's path, with its frontmatter. Capped at MAX_CANDIDATES.
+pub async fn discover_skills_in_repo(repo_url: &str) -> Result<RepoSkillDiscovery, String> {
+    installer::ensure_online()?;
+    let repo_ref =
+        parse_repo_url(repo_url).ok_or_else(|| format!("Not a GitHub repository URL: {}", repo_url))?;
+    let client = reqwest::Client::new();
+
+    let branch = match repo_ref.branch.clone() {
+        Some(branch) => branch,
+        None => {
+            let info = github_api(&client, &format!("repos/{}/{}", repo_ref.owner, repo_ref.repo)).await?;
+            info.get("default_branch")
+                .and_then(|b| b.as_str())
+                .unwrap_or("main")
+                .to_string()
+        }
+    };
+
+    // One request for the whole tree rather than walking folder by folder
+    let tree = github_api(
+        &client,
+        &format!(
+            "repos/{}/{}/git/trees/{}?recursive=1",
+            repo_ref.owner,
+            repo_ref.repo,
+            urlencoding::encode(&branch)
+        ),
+    )
+    .await?;
+    let mut truncated = tree.get("truncated").and_then(|t| t.as_bool()).unwrap_or(false);
+
+    let prefix = if repo_ref.path.is_empty() {
+        String::new()
+    } else {
+        format!("{}/", repo_ref.path)
+    };
+    let mut skill_dirs: Vec<String> = tree
+        .get("tree")
+        .and_then(|t| t.as_array())
+        .map(|entries| entries.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter(|entry| entry.get("type").and_then(|t| t.as_str()) == Some("blob"))
+        .filter_map(|entry| entry.get("path").and_then(|p| p.as_str()))
+        .filter_map(|path| {
+            let (dir, file) = path.rsplit_once('/').unwrap_or(("", path));
+            if !file.eq_ignore_ascii_case("SKILL.md") {
+                return None;
+            }
+            let relative = if dir == repo_ref.path {
+                ""
+            } else {
+                dir.strip_prefix(&prefix)?
+            };
+            let depth = relative.split('/').filter(|p| !p.is_empty()).count();
+            (depth <= MAX_DISCOVERY_DEPTH).then(|| path.to_string())
+        })
+        .collect();
+
+    skill_dirs.sort_by(|a, b| {
+        a.matches('/')
+            .count()
+            .cmp(&b.matches('/').count())
+            .then_with(|| a.cmp(b))
+    });
+    if skill_dirs.len() > MAX_CANDIDATES {
+        skill_dirs.truncate(MAX_CANDIDATES);
+        truncated = true;
+    }
+
+    let candidates = stream::iter(skill_dirs)
+        .map(|skill_md_path| {
+            let raw_url = format!(
+                "https://raw.githubusercontent.com/{}/{}/{}/{}",
+                repo_ref.owner, repo_ref.repo, branch, skill_md_path
+            );
+            async move {
+                let metadata = match crate::fetch_github_raw(&raw_url).await {
+                    Ok(content) => Some(tools::extract_metadata(&content)),
+                    Err(e) => {
+                        tracing::warn!("Failed to fetch {}: {}", skill_md_path, e);
+                        None
+                    }
+                };
+                SkillCandidate {
+                    path: skill_md_path
+                        .rsplit_once('/')
+                        .map(|(dir, _)| dir.to_string())
+                        .unwrap_or_default(),
+                    skill_md_path,
+                    metadata,
+                }
+            }
+        })
+        .buffered(FETCH_CONCURRENCY)
+        .collect()
+        .await;
+
+    Ok(RepoSkillDiscovery {
+        owner: repo_ref.owner,
+        repo: repo_ref.repo,
+        branch,
+        candidates,
+        truncated,
+    })
+}
+
+/// GET a GitHub REST API path; GitHub rejects requests without a User-Agent
+async fn github_api(client: &reqwest::Client, path: &str) -> Result<serde_json::Value, String> {
+    let response = client
+        .get(format!("{}/{}", GITHUB_API_URL, path))
+        .header("User-Agent", "SkillHub-Desktop")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to query GitHub: {}", e))?;
+
+    if let Some(limit) = crate::rate_limit_info(&response) {
+        return Err(format!(
+            "rate_limited: GitHub rate limit reached, resets at {} (in {}s)",
+            limit.reset_at, limit.wait_secs
+        ));
+    }
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err("Repository or branch not found on GitHub".to_string());
+    }
+    crate::read_json_response(response, "Failed to query GitHub").await
+}
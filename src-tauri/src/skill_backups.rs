@@ -0,0 +1,165 @@
+// Skill backups left behind in a tool's skills directories, and pruning them
+// Backups are the `<file>.local.bak` copies a conflict-aware pull saves inside a skill
+// before replacing a locally modified file; interrupted installs can also leave
+// `.skillhub-previous-*` copies at the skills root
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::{sync, tools};
+
+const PREVIOUS_PREFIX: &str = ".skillhub-previous-";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillBackup {
+    pub path: String,
+    pub owner: String,           // skill folder the backup belongs to, or the skills directory
+    pub created_at: Option<u64>, // Unix timestamp (seconds)
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PruneResult {
+    pub removed: Vec<String>,
+    pub bytes_reclaimed: u64,
+}
+
+/// List every backup in a tool's skills directories, newest first
+pub async fn list_skill_backups_all(tool_id: &str) -> Result<Vec<SkillBackup>, String> {
+    let mut backups = Vec::new();
+
+    for skills_dir in tools::existing_skills_dirs(tool_id)? {
+        let Ok(mut entries) = fs::read_dir(&skills_dir).await else {
+            continue;
+        };
+
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            let Some(name) = sync::checked_file_name(&path) else {
+                continue;
+            };
+
+            if name.starts_with(PREVIOUS_PREFIX) {
+                backups.push(describe_backup(&path, &skills_dir).await);
+            } else if is_real_dir(&path).await {
+                collect_backups_in(path.clone(), &path, &mut backups).await;
+            }
+        }
+    }
+
+    backups.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+    Ok(backups)
+}
+
+/// Delete backups beyond the retention policy: for each skill the `keep_latest` newest are
+/// kept, and of the rest only those older than `older_than_days` (if given) are removed
+pub async fn prune_skill_backups(
+    tool_id: &str,
+    keep_latest: usize,
+    older_than_days: Option<u64>,
+) -> Result<PruneResult, String> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let cutoff = older_than_days.map(|days| now.saturating_sub(days.saturating_mul(24 * 60 * 60)));
+
+    // Already sorted newest first, so each group is too
+    let mut by_owner: BTreeMap<String, Vec<SkillBackup>> = BTreeMap::new();
+    for backup in list_skill_backups_all(tool_id).await? {
+        by_owner.entry(backup.owner.clone()).or_default().push(backup);
+    }
+
+    let mut result = PruneResult::default();
+    for backup in by_owner.into_values().flat_map(|group| group.into_iter().skip(keep_latest)) {
+        let old_enough = match (cutoff, backup.created_at) {
+            (Some(cutoff), Some(created_at)) => created_at < cutoff,
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+        if !old_enough {
+            continue;
+        }
+
+        let path = Path::new(&backup.path);
+        let removed = if path.is_dir() {
+            fs::remove_dir_all(path).await
+        } else {
+            fs::remove_file(path).await
+        };
+        match removed {
+            Ok(()) => {
+                result.bytes_reclaimed += backup.size_bytes;
+                result.removed.push(backup.path);
+            }
+            Err(e) => tracing::warn!("Failed to remove backup {}: {}", backup.path, e),
+        }
+    }
+
+    tracing::info!(
+        "Pruned {} backups for {}, reclaimed {} bytes",
+        result.removed.len(),
+        tool_id,
+        result.bytes_reclaimed
+    );
+    Ok(result)
+}
+
+/// Collect the `*.local.bak` files anywhere in a skill folder (linked folders are skipped,
+/// their backups are listed where the skill really lives)
+#[async_recursion::async_recursion]
+async fn collect_backups_in(dir: PathBuf, owner: &Path, backups: &mut Vec<SkillBackup>) {
+    let Ok(mut entries) = fs::read_dir(&dir).await else {
+        return;
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if is_real_dir(&path).await {
+            collect_backups_in(path, owner, backups).await;
+        } else if sync::checked_file_name(&path).is_some_and(|n| n.ends_with(sync::BACKUP_SUFFIX)) {
+            backups.push(describe_backup(&path, owner).await);
+        }
+    }
+}
+
+/// A directory that isn't a symlink or junction
+async fn is_real_dir(path: &Path) -> bool {
+    fs::symlink_metadata(path).await.is_ok_and(|m| m.is_dir())
+}
+
+async fn describe_backup(path: &Path, owner: &Path) -> SkillBackup {
+    let created_at = fs::metadata(path)
+        .await
+        .ok()
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs());
+
+    SkillBackup {
+        path: path.to_string_lossy().to_string(),
+        owner: owner.to_string_lossy().to_string(),
+        created_at,
+        size_bytes: disk_usage(path.to_path_buf()).await,
+    }
+}
+
+/// Total size of a file or directory tree in bytes (symlinks are not followed)
+#[async_recursion::async_recursion]
+pub(crate) async fn disk_usage(path: PathBuf) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(&path).await else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+
+    let mut total = 0;
+    if let Ok(mut entries) = fs::read_dir(&path).await {
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            total += disk_usage(entry.path()).await;
+        }
+    }
+    total
+}
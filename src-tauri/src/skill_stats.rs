@@ -0,0 +1,73 @@
+// Popularity numbers for a catalog skill, for the browse and detail views
+// There is no separate stats endpoint; the numbers come from the skill detail response,
+// and any the server doesn't send are left empty
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::installer;
+
+/// How long a skill's stats are reused before asking the server again
+const STATS_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+static STATS_CACHE: Mutex<Option<HashMap<String, (Instant, SkillStats)>>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkillStats {
+    pub install_count: Option<u64>,
+    pub weekly_installs: Option<u64>,
+    pub stars: Option<u64>, // GitHub stars of the skill's repo
+    pub rating: Option<f64>,
+}
+
+/// Stats for a catalog skill (id or slug), served from memory for STATS_CACHE_TTL
+pub async fn get_skill_stats(skill_id: &str) -> Result<SkillStats, String> {
+    if let Ok(cache) = STATS_CACHE.lock() {
+        if let Some((fetched_at, stats)) = cache.as_ref().and_then(|c| c.get(skill_id)) {
+            if fetched_at.elapsed() < STATS_CACHE_TTL {
+                return Ok(stats.clone());
+            }
+        }
+    }
+    installer::ensure_online()?;
+
+    let response = reqwest::Client::new()
+        .get(format!(
+            "{}/api/v1/desktop/skills/{}",
+            crate::get_api_base_url(),
+            urlencoding::encode(skill_id)
+        ))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to get skill stats: {}", e))?;
+    let data = crate::read_json_response(response, "Failed to get skill stats").await?;
+    let skill = data.get("skill").unwrap_or(&data);
+
+    // Field names differ between API versions, so each value has a few spellings
+    let number = |keys: &[&str]| {
+        keys.iter().find_map(|key| {
+            let value = skill.get(*key).or_else(|| skill.get("stats")?.get(*key))?;
+            value
+                .as_f64()
+                .or_else(|| value.as_str().and_then(|s| s.trim().parse().ok()))
+        })
+    };
+    let count = |keys: &[&str]| number(keys).filter(|n| *n >= 0.0).map(|n| n as u64);
+
+    let stats = SkillStats {
+        install_count: count(&["install_count", "installCount", "installs", "downloads"]),
+        weekly_installs: count(&["weekly_installs", "weeklyInstalls", "installs_7d"]),
+        stars: count(&["github_stars", "githubStars", "stars"]),
+        rating: number(&["rating", "average_rating", "averageRating"]),
+    };
+
+    if let Ok(mut cache) = STATS_CACHE.lock() {
+        let cache = cache.get_or_insert_with(HashMap::new);
+        // Drop expired entries so browsing many skills doesn't grow the cache without bound
+        cache.retain(|_, (fetched_at, _)| fetched_at.elapsed() < STATS_CACHE_TTL);
+        cache.insert(skill_id.to_string(), (Instant::now(), stats.clone()));
+    }
+    Ok(stats)
+}
@@ -0,0 +1,88 @@
+// Updating an installed catalog skill without losing files the user added to it
+// Only files that came from the catalog (listed in .skillhub.json) are replaced or removed
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tokio::fs;
+
+use crate::{bundle, library, sync, verify};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InPlaceUpdateReport {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub removed: Vec<String>, // catalog files the new version no longer has
+    pub unchanged: usize,
+    pub preserved: Vec<String>, // user-added files left as they were
+    pub backed_up: Vec<String>, // user files replaced by a new catalog file, saved as *.local.bak
+}
+
+/// Update a catalog skill from the latest bundle, overwriting only catalog files.
+/// Skills installed before catalog files were recorded have no list to go by; for those,
+/// files in the new bundle are written and nothing is removed.
+pub async fn update_skill_in_place(skill_path: &str) -> Result<InPlaceUpdateReport, String> {
+    let root = Path::new(skill_path);
+    if !root.is_dir() {
+        return Err(format!("Skill is not a folder: {}", skill_path));
+    }
+    let mut meta = sync::read_meta(skill_path)
+        .await?
+        .ok_or("Skill has no .skillhub.json, its catalog source is unknown")?;
+
+    let incoming = bundle::fetch_skill_bundle(&meta.skill_id, true, meta.skill_md_only).await?;
+    let incoming: BTreeMap<String, Vec<u8>> = incoming
+        .into_iter()
+        .map(|(path, content)| (verify::normalize_catalog_path(&path), content.into_bytes()))
+        .collect();
+
+    let local: BTreeMap<String, Vec<u8>> = library::read_skill_files(root).await?.into_iter().collect();
+    let previous: Option<BTreeSet<&String>> =
+        (!meta.catalog_files.is_empty()).then(|| meta.catalog_files.iter().collect());
+    let is_catalog_file = |path: &String| previous.as_ref().is_none_or(|p| p.contains(path));
+
+    let mut report = InPlaceUpdateReport::default();
+    let mut to_write = Vec::new();
+    for (path, content) in &incoming {
+        match local.get(path) {
+            Some(existing) if existing == content => {
+                report.unchanged += 1;
+                continue;
+            }
+            Some(_) if !is_catalog_file(path) => {
+                sync::resolve_conflict(&root.join(path), "backup").await?;
+                report.backed_up.push(path.clone());
+            }
+            Some(_) => report.updated.push(path.clone()),
+            None => report.created.push(path.clone()),
+        }
+        to_write.push((path.clone(), content.clone()));
+    }
+    library::write_skill_files(root, &to_write).await?;
+
+    for path in local.keys().filter(|path| !incoming.contains_key(*path)) {
+        if previous.as_ref().is_some_and(|p| p.contains(path)) {
+            fs::remove_file(root.join(path))
+                .await
+                .map_err(|e| format!("Failed to remove {}: {}", path, e))?;
+            report.removed.push(path.clone());
+        } else {
+            report.preserved.push(path.clone());
+        }
+    }
+
+    // The catalog has no version numbers for skills, so the sync time records the update
+    meta.catalog_files = incoming.into_keys().collect();
+    meta.synced_at = sync::iso8601_now();
+    sync::write_meta(skill_path, &meta).await?;
+
+    tracing::info!(
+        "Updated {} in place: {} created, {} updated, {} removed, {} user files kept",
+        skill_path,
+        report.created.len(),
+        report.updated.len(),
+        report.removed.len(),
+        report.preserved.len()
+    );
+    Ok(report)
+}
@@ -0,0 +1,384 @@
+// Restore points for whole skills directories, taken before bulk syncs or migrations
+// A snapshot is a ZIP in ~/.skillhub/snapshots/ with a manifest.json and the contents of
+// each tool's skills directory under tools/<tool id>/
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read, Write};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::library::{self, SkillFiles};
+use crate::tools::{self, ConflictPolicy};
+use crate::{installer, sync};
+
+const SNAPSHOTS_DIR: &str = "snapshots";
+const MANIFEST_FILE: &str = "manifest.json";
+const TOOLS_PREFIX: &str = "tools";
+const SNAPSHOT_VERSION: u32 = 1;
+/// Per-skill backups are left out; the snapshot already is one
+const BACKUPS_PREFIX: &str = ".skillhub-backups/";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotTool {
+    pub tool_id: String,
+    pub skills_dir: String, // where the skills were taken from
+    pub entries: Vec<String>, // top-level skill folders and files
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SnapshotManifest {
+    version: u32,
+    label: String,
+    created_at: u64,
+    tools: Vec<SnapshotTool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotInfo {
+    pub path: String,
+    pub label: String,
+    pub created_at: u64, // Unix timestamp (seconds)
+    pub size_bytes: u64,
+    pub tools: Vec<SnapshotTool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnapshotRestoreResult {
+    pub tool_id: String,
+    pub restored: Vec<String>,
+    pub skipped: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+fn snapshots_dir() -> Result<PathBuf, String> {
+    Ok(installer::get_skillhub_config_dir()?.join(SNAPSHOTS_DIR))
+}
+
+/// Archive the skills directories of the given tools into
+/// ~/.skillhub/snapshots/<label>-<timestamp>.zip
+pub async fn snapshot_skills(tool_ids: &[String], label: &str) -> Result<SnapshotInfo, String> {
+    create_snapshot(tool_ids, label, false).await
+}
+
+/// Archive one tool's skills directory exactly as it is on disk, including hidden entries,
+/// .skillhub.json, .git folders and per-skill backups. Taken before the directory is
+/// deleted; fails if any file can't be read, so nothing is removed that wasn't archived.
+pub async fn snapshot_skills_dir_full(tool_id: &str, label: &str) -> Result<SnapshotInfo, String> {
+    create_snapshot(&[tool_id.to_string()], label, true).await
+}
+
+async fn create_snapshot(tool_ids: &[String], label: &str, full: bool) -> Result<SnapshotInfo, String> {
+    if tool_ids.is_empty() {
+        return Err("No tools selected for the snapshot".to_string());
+    }
+    // The label becomes part of the file name
+    let label: String = label
+        .trim()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect();
+    let label = if label.is_empty() { "snapshot".to_string() } else { label };
+    let created_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let mut manifest = SnapshotManifest {
+        version: SNAPSHOT_VERSION,
+        label: label.clone(),
+        created_at,
+        tools: Vec::new(),
+    };
+    let mut bundle: Vec<(String, SkillFiles)> = Vec::new();
+
+    for tool_id in tool_ids {
+        let skills_dir = tools::get_tool_skills_dir(tool_id)?;
+        let (entries, files) = if full {
+            read_skills_dir_full(&skills_dir).await?
+        } else {
+            read_skills_dir(&skills_dir).await?
+        };
+        manifest.tools.push(SnapshotTool {
+            tool_id: tool_id.clone(),
+            skills_dir: skills_dir.to_string_lossy().to_string(),
+            entries,
+        });
+        bundle.push((tool_id.clone(), files));
+    }
+
+    let data = write_snapshot_zip(&manifest, &bundle)?;
+    let path = snapshots_dir()?.join(format!("{}-{}.zip", label, created_at));
+    sync::save_export(&data, &path.to_string_lossy()).await?;
+
+    tracing::info!(
+        "Saved snapshot {} ({} tools, {} bytes)",
+        path.display(),
+        manifest.tools.len(),
+        data.len()
+    );
+    Ok(SnapshotInfo {
+        path: path.to_string_lossy().to_string(),
+        label,
+        created_at,
+        size_bytes: data.len() as u64,
+        tools: manifest.tools,
+    })
+}
+
+/// Every skill in a skills directory, as the top-level entry names and all files with
+/// paths relative to the directory. Linked skills are read through their link.
+async fn read_skills_dir(skills_dir: &Path) -> Result<(Vec<String>, SkillFiles), String> {
+    let mut names = Vec::new();
+    let mut files = Vec::new();
+
+    let mut entries = match fs::read_dir(skills_dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok((names, files)),
+        Err(e) => return Err(format!("Failed to read {}: {}", skills_dir.display(), e)),
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        // Hidden entries include interrupted-install leftovers (.skillhub-previous-*)
+        match sync::checked_file_name(&path) {
+            Some(name) if !name.starts_with('.') && !sync::should_skip(&name) => names.push(name),
+            _ => continue,
+        }
+    }
+    names.sort();
+
+    for name in &names {
+        let path = skills_dir.join(name);
+        if path.is_dir() {
+            for (relative, content) in library::read_skill_files(&path).await? {
+                if !relative.starts_with(BACKUPS_PREFIX) {
+                    files.push((format!("{}/{}", name, relative), content));
+                }
+            }
+        } else {
+            let content = fs::read(&path)
+                .await
+                .map_err(|e| format!("Failed to read file {}: {}", path.display(), e))?;
+            files.push((name.clone(), content));
+        }
+    }
+
+    Ok((names, files))
+}
+
+/// Everything in a skills directory, nothing filtered
+async fn read_skills_dir_full(skills_dir: &Path) -> Result<(Vec<String>, SkillFiles), String> {
+    if fs::symlink_metadata(skills_dir).await.is_err() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    let files = library::read_all_files(skills_dir).await?;
+    let mut names: Vec<String> = files
+        .iter()
+        .filter_map(|(path, _)| path.split('/').next())
+        .map(str::to_string)
+        .collect();
+    names.sort();
+    names.dedup();
+    Ok((names, files))
+}
+
+fn write_snapshot_zip(
+    manifest: &SnapshotManifest,
+    bundle: &[(String, SkillFiles)],
+) -> Result<Vec<u8>, String> {
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+
+    let manifest_json = serde_json::to_string_pretty(manifest)
+        .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+    zip.start_file(MANIFEST_FILE, options)
+        .map_err(|e| format!("Failed to write manifest: {}", e))?;
+    zip.write_all(manifest_json.as_bytes())
+        .map_err(|e| format!("Failed to write manifest: {}", e))?;
+
+    for (tool_id, files) in bundle {
+        for (filepath, content) in files {
+            let entry_name = format!("{}/{}/{}", TOOLS_PREFIX, tool_id, filepath);
+            zip.start_file(entry_name.as_str(), options)
+                .map_err(|e| format!("Failed to add {} to archive: {}", entry_name, e))?;
+            zip.write_all(content)
+                .map_err(|e| format!("Failed to add {} to archive: {}", entry_name, e))?;
+        }
+    }
+
+    let cursor = zip
+        .finish()
+        .map_err(|e| format!("Failed to finish archive: {}", e))?;
+    Ok(cursor.into_inner())
+}
+
+fn read_manifest(archive: &mut zip::ZipArchive<Cursor<Vec<u8>>>) -> Result<SnapshotManifest, String> {
+    let mut file = archive
+        .by_name(MANIFEST_FILE)
+        .map_err(|_| "Snapshot has no manifest.json".to_string())?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .map_err(|e| format!("Failed to read manifest: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse manifest: {}", e))
+}
+
+/// List saved snapshots, newest first. Unreadable archives are skipped with a warning.
+pub async fn list_snapshots() -> Result<Vec<SnapshotInfo>, String> {
+    let dir = snapshots_dir()?;
+    let mut entries = match fs::read_dir(&dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read snapshots directory: {}", e)),
+    };
+
+    let mut snapshots = Vec::new();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != "zip") {
+            continue;
+        }
+        let Ok(data) = fs::read(&path).await else {
+            continue;
+        };
+        let size_bytes = data.len() as u64;
+        let manifest = zip::ZipArchive::new(Cursor::new(data))
+            .map_err(|e| e.to_string())
+            .and_then(|mut archive| read_manifest(&mut archive));
+        match manifest {
+            Ok(manifest) => snapshots.push(SnapshotInfo {
+                path: path.to_string_lossy().to_string(),
+                label: manifest.label,
+                created_at: manifest.created_at,
+                size_bytes,
+                tools: manifest.tools,
+            }),
+            Err(e) => tracing::warn!("Skipping unreadable snapshot {}: {}", path.display(), e),
+        }
+    }
+
+    snapshots.sort_by_key(|s| std::cmp::Reverse(s.created_at));
+    Ok(snapshots)
+}
+
+/// Put the skills from a snapshot back into each tool's current skills directory.
+/// `on_conflict` ("error", "skip", "overwrite" or "merge") decides what happens to a
+/// skill that exists both in the snapshot and on disk; other skills on disk are kept.
+pub async fn restore_snapshot(
+    snapshot_path: &str,
+    on_conflict: &str,
+) -> Result<Vec<SnapshotRestoreResult>, String> {
+    let policy = tools::parse_conflict_policy(on_conflict)?;
+    let data = fs::read(snapshot_path)
+        .await
+        .map_err(|e| format!("Failed to read snapshot: {}", e))?;
+    let mut archive = zip::ZipArchive::new(Cursor::new(data))
+        .map_err(|e| format!("Failed to open snapshot: {}", e))?;
+    let manifest = read_manifest(&mut archive)?;
+
+    // tool id -> top-level entry -> files relative to the skills directory
+    let mut contents: BTreeMap<String, BTreeMap<String, SkillFiles>> = BTreeMap::new();
+    for i in 0..archive.len() {
+        let mut file = archive
+            .by_index(i)
+            .map_err(|e| format!("Failed to read snapshot entry: {}", e))?;
+        if file.is_dir() || file.name() == MANIFEST_FILE {
+            continue;
+        }
+        let name = file.name().to_string();
+        let relative = library::safe_relative_path(&name)
+            .ok_or_else(|| format!("Unsafe path in snapshot: {}", name))?;
+        let parts: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect();
+        let [prefix, tool_id, entry, ..] = parts.as_slice() else {
+            continue;
+        };
+        if prefix != TOOLS_PREFIX {
+            continue;
+        }
+
+        let mut content = Vec::new();
+        file.read_to_end(&mut content)
+            .map_err(|e| format!("Failed to read {} from snapshot: {}", name, e))?;
+        contents
+            .entry(tool_id.clone())
+            .or_default()
+            .entry(entry.clone())
+            .or_default()
+            .push((parts[2..].join("/"), content));
+    }
+
+    let mut results = Vec::new();
+    for tool in manifest.tools {
+        let mut result = SnapshotRestoreResult {
+            tool_id: tool.tool_id.clone(),
+            ..Default::default()
+        };
+        let skills_dir = match tools::get_tool_skills_dir(&tool.tool_id) {
+            Ok(dir) => dir,
+            Err(e) => {
+                result.errors.push(e);
+                results.push(result);
+                continue;
+            }
+        };
+        let entries = contents.remove(&tool.tool_id).unwrap_or_default();
+        let required_bytes: u64 = entries
+            .values()
+            .flatten()
+            .map(|(_, content)| content.len() as u64)
+            .sum();
+        if let Err(e) = crate::disk::ensure_free_space(&skills_dir, required_bytes) {
+            result.errors.push(e);
+            results.push(result);
+            continue;
+        }
+
+        for (entry, files) in entries {
+            let target = skills_dir.join(&entry);
+            if target.exists() || target.is_symlink() {
+                match policy {
+                    ConflictPolicy::Error => {
+                        result.errors.push(format!("{} already exists", entry));
+                        continue;
+                    }
+                    ConflictPolicy::Skip => {
+                        result.skipped.push(entry);
+                        continue;
+                    }
+                    ConflictPolicy::Overwrite => {
+                        let removed = if target.is_dir() && !target.is_symlink() {
+                            fs::remove_dir_all(&target).await
+                        } else {
+                            fs::remove_file(&target).await
+                        };
+                        if let Err(e) = removed {
+                            result
+                                .errors
+                                .push(format!("{}: failed to remove existing skill: {}", entry, e));
+                            continue;
+                        }
+                    }
+                    ConflictPolicy::Merge => {}
+                }
+            }
+
+            match library::write_skill_files(&skills_dir, &files).await {
+                Ok(()) => result.restored.push(target.to_string_lossy().to_string()),
+                Err(e) => result.errors.push(format!("{}: {}", entry, e)),
+            }
+        }
+
+        tracing::info!(
+            "Restored {} skills for {} from {}",
+            result.restored.len(),
+            tool.tool_id,
+            snapshot_path
+        );
+        results.push(result);
+    }
+
+    Ok(results)
+}
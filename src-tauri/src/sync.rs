@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
+use futures::stream::{self, StreamExt};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use sha2::{Digest, Sha256};
-use std::path::Path;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use tokio::fs;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,37 +22,202 @@ pub struct SyncMeta {
     pub version: u32,
     pub synced_at: String,
     pub platform_url: String,
+    #[serde(default)]
+    pub skill_md_only: bool, // installed without auxiliary files; verify doesn't report them missing
+    /// Files that came from the catalog, so an in-place update can tell them from user files
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub catalog_files: Vec<String>,
 }
 
+/// What a pull changed locally
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncReport {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub deleted: Vec<String>,
+    pub unchanged: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncConflict {
+    pub filepath: String,
+    pub action: String, // "backed_up", "kept_local" or "overwritten"
+}
+
+/// Hashes of the files as last pulled, used to detect local modifications
+const BASELINE_FILE: &str = ".skillhub-baseline.json";
+pub(crate) const BACKUP_SUFFIX: &str = ".local.bak";
+
 const SKIP_FILES: &[&str] = &[
     ".git",
     ".DS_Store",
     ".skillhub.json",
+    ".skillhub-baseline.json",
+    ".skillhub-backups",
     ".gitignore",
     "Thumbs.db",
 ];
 
-fn should_skip(name: &str) -> bool {
-    name.starts_with('.') && SKIP_FILES.contains(&name) || name == "Thumbs.db"
+/// Paths skipped because their file name isn't valid UTF-8 (reported via diagnostics)
+static NON_UTF8_ENTRIES: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/// Get the file name of a directory entry as UTF-8.
+/// Names that aren't valid UTF-8 would be corrupted by a lossy conversion and break later
+/// operations, so they are logged, recorded for diagnostics and skipped (None).
+pub(crate) fn checked_file_name(path: &Path) -> Option<String> {
+    let name = path.file_name()?;
+    match name.to_str() {
+        Some(name) => Some(name.to_string()),
+        None => {
+            tracing::warn!("Skipping entry with non-UTF-8 file name: {}", path.display());
+            if let Ok(mut entries) = NON_UTF8_ENTRIES.lock() {
+                entries.insert(path.display().to_string());
+            }
+            None
+        }
+    }
+}
+
+/// List the entries skipped so far because of non-UTF-8 file names.
+pub fn get_non_utf8_entries() -> Vec<String> {
+    NON_UTF8_ENTRIES
+        .lock()
+        .map(|entries| entries.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+pub(crate) fn should_skip(name: &str) -> bool {
+    name.starts_with('.') && SKIP_FILES.contains(&name)
+        || name == "Thumbs.db"
+        || name.ends_with(BACKUP_SUFFIX)
+}
+
+/// User-configured ignore patterns (`sync_ignore` in ~/.skillhub/config.json), applied on top
+/// of the built-in skip list. Patterns use gitignore-style matching relative to the skill root,
+/// e.g. ".venv", "__pycache__/", "dist/" or "*.log".
+pub(crate) struct SyncIgnore(Gitignore);
+
+impl SyncIgnore {
+    /// Compile the given patterns for a skill root
+    pub(crate) fn build(root: &Path, patterns: &[String]) -> Result<Self, String> {
+        let mut builder = GitignoreBuilder::new(root);
+        for pattern in patterns {
+            builder
+                .add_line(None, pattern)
+                .map_err(|e| format!("Invalid ignore pattern {}: {}", pattern, e))?;
+        }
+        let matcher = builder
+            .build()
+            .map_err(|e| format!("Failed to compile ignore patterns: {}", e))?;
+        Ok(Self(matcher))
+    }
+
+    /// Compile the configured patterns once for a sync run.
+    /// Invalid patterns are logged and dropped rather than failing the sync.
+    pub(crate) fn load(root: &Path) -> Self {
+        let patterns = crate::installer::get_sync_ignore();
+        Self::build(root, &patterns).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring sync_ignore config: {}", e);
+            Self(Gitignore::empty())
+        })
+    }
+
+    pub(crate) fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        self.0.matched(path, is_dir).is_ignore()
+    }
+}
+
+/// Convert a relative path to the portable form stored in sync data: forward slashes on every platform.
+pub(crate) fn to_portable_path(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Split a stored relative path on either separator, rejecting anything that could point
+/// outside the skill directory: `..`, absolute paths and drive prefixes
+fn portable_parts(filepath: &str) -> Result<Vec<&str>, String> {
+    let invalid = || format!("Invalid file path: {}", filepath);
+    if filepath.starts_with(['/', '\\']) {
+        return Err(invalid());
+    }
+
+    let parts: Vec<&str> = filepath
+        .split(['/', '\\'])
+        .filter(|part| !part.is_empty() && *part != ".")
+        .collect();
+    let has_drive = |part: &str| {
+        let bytes = part.as_bytes();
+        bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+    };
+    if parts.is_empty() || parts.contains(&"..") || parts.first().is_some_and(|p| has_drive(p)) {
+        return Err(invalid());
+    }
+    Ok(parts)
+}
+
+/// Normalize a stored relative path that may use either separator (older Windows clients
+/// pushed backslashes) into the portable forward-slash form.
+pub(crate) fn normalize_portable_path(filepath: &str) -> Result<String, String> {
+    Ok(portable_parts(filepath)?.join("/"))
 }
 
+/// Convert a portable relative path back into a native path for filesystem access.
+pub(crate) fn from_portable_path(filepath: &str) -> Result<PathBuf, String> {
+    Ok(portable_parts(filepath)?.into_iter().collect())
+}
+
+/// Compute the hex-encoded SHA-256 hash of some content.
+pub(crate) fn hash_content(content: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content);
+    hex::encode(hasher.finalize())
+}
+
+/// Max files read and hashed at once, bounded to avoid exhausting file descriptors
+const HASH_CONCURRENCY: usize = 16;
+
 /// Recursively collect all files from a skill directory, compute SHA-256 hashes.
+/// Files are read and hashed concurrently; the result is sorted by filepath so the
+/// order doesn't depend on which reads finish first.
 pub async fn collect_files(path: &str) -> Result<Vec<SyncFile>, String> {
+    collect_files_with_concurrency(path, HASH_CONCURRENCY).await
+}
+
+async fn collect_files_with_concurrency(path: &str, concurrency: usize) -> Result<Vec<SyncFile>, String> {
     let root = Path::new(path);
     if !root.exists() {
         return Err(format!("Path does not exist: {}", path));
     }
 
+    let ignore = SyncIgnore::load(root);
+    let mut paths = Vec::new();
+    collect_file_paths(root, &ignore, &mut paths).await?;
+
+    let results: Vec<Result<Option<SyncFile>, String>> = stream::iter(paths)
+        .map(|entry_path| read_sync_file(root, entry_path))
+        .buffer_unordered(concurrency)
+        .collect()
+        .await;
+
     let mut files = Vec::new();
-    collect_files_recursive(root, root, &mut files).await?;
+    for result in results {
+        if let Some(file) = result? {
+            files.push(file);
+        }
+    }
+    files.sort_by(|a, b| a.filepath.cmp(&b.filepath));
+
     Ok(files)
 }
 
 #[async_recursion::async_recursion]
-async fn collect_files_recursive(
-    root: &Path,
+async fn collect_file_paths(
     current: &Path,
-    files: &mut Vec<SyncFile>,
+    ignore: &SyncIgnore,
+    paths: &mut Vec<PathBuf>,
 ) -> Result<(), String> {
     let mut entries = fs::read_dir(current)
         .await
@@ -59,61 +228,69 @@ async fn collect_files_recursive(
         .await
         .map_err(|e| format!("Failed to read entry: {}", e))?
     {
-        let name = entry.file_name().to_string_lossy().to_string();
+        let entry_path = entry.path();
+        let Some(name) = checked_file_name(&entry_path) else {
+            continue;
+        };
         if should_skip(&name) {
             continue;
         }
 
-        let entry_path = entry.path();
         let file_type = entry
             .file_type()
             .await
             .map_err(|e| format!("Failed to get file type: {}", e))?;
 
+        if ignore.is_ignored(&entry_path, file_type.is_dir()) {
+            continue;
+        }
+
         if file_type.is_dir() {
-            collect_files_recursive(root, &entry_path, files).await?;
+            collect_file_paths(&entry_path, ignore, paths).await?;
         } else if file_type.is_file() {
-            let content = fs::read(&entry_path)
-                .await
-                .map_err(|e| format!("Failed to read file {}: {}", entry_path.display(), e))?;
-
-            let file_size = content.len() as u64;
-
-            // Compute SHA-256 hash
-            let mut hasher = Sha256::new();
-            hasher.update(&content);
-            let hash = hex::encode(hasher.finalize());
-
-            // Get relative path from root
-            let relative = entry_path
-                .strip_prefix(root)
-                .map_err(|e| format!("Failed to compute relative path: {}", e))?;
-            let filepath = relative.to_string_lossy().to_string();
-
-            // Convert content to string (skip binary files)
-            let content_str = match String::from_utf8(content) {
-                Ok(s) => s,
-                Err(_) => {
-                    // Skip binary files
-                    continue;
-                }
-            };
-
-            files.push(SyncFile {
-                filepath,
-                content: content_str,
-                content_hash: hash,
-                file_size,
-            });
+            paths.push(entry_path);
         }
     }
 
     Ok(())
 }
 
+/// Read and hash a single file. Binary files are skipped (None).
+async fn read_sync_file(root: &Path, entry_path: PathBuf) -> Result<Option<SyncFile>, String> {
+    let content = fs::read(&entry_path)
+        .await
+        .map_err(|e| format!("Failed to read file {}: {}", entry_path.display(), e))?;
+
+    let file_size = content.len() as u64;
+
+    // Compute SHA-256 hash
+    let hash = hash_content(&content);
+
+    // Get relative path from root
+    let relative = entry_path
+        .strip_prefix(root)
+        .map_err(|e| format!("Failed to compute relative path: {}", e))?;
+    let filepath = to_portable_path(relative);
+
+    // Convert content to string (skip binary files)
+    let content_str = match String::from_utf8(content) {
+        Ok(s) => s,
+        Err(_) => return Ok(None),
+    };
+
+    Ok(Some(SyncFile {
+        filepath,
+        content: content_str,
+        content_hash: hash,
+        file_size,
+    }))
+}
+
 /// Write pulled files to local directory, creating parent dirs as needed.
+/// Files that are already identical locally are left untouched.
 /// Removes files that exist locally but not in the incoming set.
-pub async fn write_files(path: &str, files: &[SyncFile]) -> Result<(), String> {
+/// Returns which files were created, updated and deleted.
+pub async fn write_files(path: &str, files: &[SyncFile]) -> Result<SyncReport, String> {
     let root = Path::new(path);
 
     // Create root directory if it doesn't exist
@@ -121,44 +298,214 @@ pub async fn write_files(path: &str, files: &[SyncFile]) -> Result<(), String> {
         .await
         .map_err(|e| format!("Failed to create directory {}: {}", path, e))?;
 
-    // Collect incoming filepaths for cleanup
-    let incoming_paths: std::collections::HashSet<String> =
-        files.iter().map(|f| f.filepath.clone()).collect();
+    // Compare incoming files with the local state before writing anything
+    let mut report = SyncReport::default();
+    let mut to_write = Vec::new();
 
-    // Write all incoming files
     for file in files {
-        let file_path = root.join(&file.filepath);
+        let filepath = normalize_portable_path(&file.filepath)?;
+        let file_path = root.join(from_portable_path(&filepath)?);
+
+        match fs::read(&file_path).await {
+            // Identical files are left untouched to keep mtimes stable
+            Ok(local) if hash_content(&local) == incoming_hash(file) => report.unchanged += 1,
+            Ok(_) => {
+                report.updated.push(filepath);
+                to_write.push((file_path, file));
+            }
+            Err(_) => {
+                report.created.push(filepath);
+                to_write.push((file_path, file));
+            }
+        }
+    }
 
-        // Create parent directories
-        if let Some(parent) = file_path.parent() {
-            fs::create_dir_all(parent)
-                .await
-                .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    // Files that exist locally but not in the incoming set get removed
+    let incoming_paths: HashSet<String> = files
+        .iter()
+        .map(|f| normalize_portable_path(&f.filepath))
+        .collect::<Result<_, _>>()?;
+    let mut existing_files = Vec::new();
+    collect_existing_files(root, root, &SyncIgnore::load(root), &mut existing_files).await?;
+    report.deleted = existing_files
+        .into_iter()
+        .filter(|existing| !incoming_paths.contains(existing))
+        .collect();
+
+    for (file_path, file) in to_write {
+        write_sync_file(&file_path, file).await?;
+    }
+
+    for deleted in &report.deleted {
+        let Ok(relative) = from_portable_path(deleted) else {
+            continue;
+        };
+        let full_path = root.join(relative);
+        let _ = fs::remove_file(&full_path).await;
+    }
+
+    write_baseline(root, files).await;
+
+    Ok(report)
+}
+
+/// Write pulled files like `write_files`, but protect local edits.
+/// A local file counts as modified when its hash differs from the baseline recorded at the
+/// last pull (or, without a baseline, from the incoming content). Modified files are handled
+/// per `on_conflict`: "backup" saves `<file>.local.bak` before overwriting, "keep_local" leaves
+/// them untouched and "overwrite" replaces them.
+pub async fn write_files_safe(
+    path: &str,
+    files: &[SyncFile],
+    on_conflict: &str,
+) -> Result<Vec<SyncConflict>, String> {
+    if !["backup", "keep_local", "overwrite"].contains(&on_conflict) {
+        return Err(format!("Invalid conflict policy: {}", on_conflict));
+    }
+
+    let root = Path::new(path);
+    fs::create_dir_all(root)
+        .await
+        .map_err(|e| format!("Failed to create directory {}: {}", path, e))?;
+
+    let baseline = read_baseline(root).await;
+    let mut conflicts = Vec::new();
+
+    let incoming_paths: HashSet<String> = files
+        .iter()
+        .map(|f| normalize_portable_path(&f.filepath))
+        .collect::<Result<_, _>>()?;
+
+    for file in files {
+        let filepath = normalize_portable_path(&file.filepath)?;
+        let file_path = root.join(from_portable_path(&filepath)?);
+
+        if let Ok(local) = fs::read(&file_path).await {
+            let local_hash = hash_content(&local);
+            if local_hash == incoming_hash(file) {
+                continue;
+            }
+
+            let modified = baseline
+                .get(&filepath)
+                .map(|recorded| recorded != &local_hash)
+                .unwrap_or(true);
+
+            if modified {
+                let action = resolve_conflict(&file_path, on_conflict).await?;
+                conflicts.push(SyncConflict {
+                    filepath: filepath.clone(),
+                    action: action.to_string(),
+                });
+                if action == "kept_local" {
+                    continue;
+                }
+            }
         }
 
-        fs::write(&file_path, &file.content)
-            .await
-            .map_err(|e| format!("Failed to write file {}: {}", file_path.display(), e))?;
+        write_sync_file(&file_path, file).await?;
     }
 
-    // Clean removed files: collect existing files and remove those not in incoming set
+    // Clean removed files, but never silently drop local edits
     let mut existing_files = Vec::new();
-    collect_existing_files(root, root, &mut existing_files).await?;
+    collect_existing_files(root, root, &SyncIgnore::load(root), &mut existing_files).await?;
 
     for existing in existing_files {
-        if !incoming_paths.contains(&existing) {
-            let full_path = root.join(&existing);
-            let _ = fs::remove_file(&full_path).await;
+        if incoming_paths.contains(&existing) {
+            continue;
+        }
+
+        let Ok(relative) = from_portable_path(&existing) else {
+            continue;
+        };
+        let full_path = root.join(relative);
+        let modified = match (fs::read(&full_path).await, baseline.get(&existing)) {
+            (Ok(local), Some(recorded)) => &hash_content(&local) != recorded,
+            _ => true,
+        };
+
+        if modified {
+            let action = resolve_conflict(&full_path, on_conflict).await?;
+            conflicts.push(SyncConflict {
+                filepath: existing.clone(),
+                action: action.to_string(),
+            });
+            if action == "kept_local" {
+                continue;
+            }
         }
+
+        let _ = fs::remove_file(&full_path).await;
     }
 
-    Ok(())
+    write_baseline(root, files).await;
+
+    Ok(conflicts)
+}
+
+/// Apply the conflict policy to a locally modified file before it gets replaced or removed
+pub(crate) async fn resolve_conflict(file_path: &Path, on_conflict: &str) -> Result<&'static str, String> {
+    match on_conflict {
+        "backup" => {
+            let mut backup_name = file_path.as_os_str().to_os_string();
+            backup_name.push(BACKUP_SUFFIX);
+            fs::copy(file_path, PathBuf::from(backup_name))
+                .await
+                .map_err(|e| format!("Failed to back up {}: {}", file_path.display(), e))?;
+            Ok("backed_up")
+        }
+        "keep_local" => Ok("kept_local"),
+        _ => Ok("overwritten"),
+    }
+}
+
+/// The hash of an incoming file, computed from its content if the sender didn't provide one
+fn incoming_hash(file: &SyncFile) -> String {
+    if file.content_hash.is_empty() {
+        hash_content(file.content.as_bytes())
+    } else {
+        file.content_hash.clone()
+    }
+}
+
+/// Write one incoming file, creating parent directories as needed
+async fn write_sync_file(file_path: &Path, file: &SyncFile) -> Result<(), String> {
+    if let Some(parent) = file_path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+
+    fs::write(file_path, &file.content)
+        .await
+        .map_err(|e| format!("Failed to write file {}: {}", file_path.display(), e))
+}
+
+/// Read the per-file hashes recorded at the last pull
+async fn read_baseline(root: &Path) -> HashMap<String, String> {
+    match fs::read_to_string(root.join(BASELINE_FILE)).await {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => HashMap::new(),
+    }
+}
+
+/// Record the pulled files' hashes as the new baseline (best effort)
+async fn write_baseline(root: &Path, files: &[SyncFile]) {
+    let baseline: HashMap<String, String> = files
+        .iter()
+        .filter_map(|f| Some((normalize_portable_path(&f.filepath).ok()?, incoming_hash(f))))
+        .collect();
+
+    if let Ok(content) = serde_json::to_string_pretty(&baseline) {
+        let _ = fs::write(root.join(BASELINE_FILE), content).await;
+    }
 }
 
 #[async_recursion::async_recursion]
 async fn collect_existing_files(
     root: &Path,
     current: &Path,
+    ignore: &SyncIgnore,
     files: &mut Vec<String>,
 ) -> Result<(), String> {
     let mut entries = match fs::read_dir(current).await {
@@ -167,19 +514,25 @@ async fn collect_existing_files(
     };
 
     while let Some(entry) = entries.next_entry().await.map_err(|e| e.to_string())? {
-        let name = entry.file_name().to_string_lossy().to_string();
+        let entry_path = entry.path();
+        let Some(name) = checked_file_name(&entry_path) else {
+            continue;
+        };
         if should_skip(&name) {
             continue;
         }
 
-        let entry_path = entry.path();
         let file_type = entry.file_type().await.map_err(|e| e.to_string())?;
 
+        if ignore.is_ignored(&entry_path, file_type.is_dir()) {
+            continue;
+        }
+
         if file_type.is_dir() {
-            collect_existing_files(root, &entry_path, files).await?;
+            collect_existing_files(root, &entry_path, ignore, files).await?;
         } else if file_type.is_file() {
             if let Ok(relative) = entry_path.strip_prefix(root) {
-                files.push(relative.to_string_lossy().to_string());
+                files.push(to_portable_path(relative));
             }
         }
     }
@@ -218,6 +571,54 @@ pub async fn write_meta(path: &str, meta: &SyncMeta) -> Result<(), String> {
     Ok(())
 }
 
+/// Record how a skill was installed in its .skillhub.json. An existing meta for the same
+/// skill keeps its sync fields; with a catalog `skill_id` and no meta yet, one is created.
+/// `catalog_files` (the installed catalog paths) is recorded only for catalog installs.
+/// Skills installed without an id and never synced are left without a meta.
+pub async fn record_install(
+    path: &str,
+    skill_id: Option<&str>,
+    skill_slug: &str,
+    skill_md_only: bool,
+    catalog_files: Vec<String>,
+) -> Result<(), String> {
+    let existing = read_meta(path).await?;
+    let mut meta = match (existing, skill_id) {
+        (Some(meta), None) => meta,
+        (Some(meta), Some(id)) if meta.skill_id == id => meta,
+        (_, Some(id)) => SyncMeta {
+            skill_id: id.to_string(),
+            skill_slug: skill_slug.to_string(),
+            version: 0,
+            synced_at: iso8601_now(),
+            platform_url: format!("{}/skills/{}", crate::get_api_base_url(), skill_slug),
+            skill_md_only: false,
+            catalog_files: Vec::new(),
+        },
+        (None, None) => return Ok(()),
+    };
+    meta.skill_md_only = skill_md_only;
+    if skill_id.is_some() {
+        meta.catalog_files = catalog_files;
+    }
+    write_meta(path, &meta).await
+}
+
+/// Write .skillhub.json from a caller that only knows the sync fields (pull, push, rollback).
+/// For the same skill, install details it leaves empty (skill_md_only, catalog_files) are
+/// kept from the existing meta instead of being wiped.
+pub async fn merge_meta(path: &str, mut meta: SyncMeta) -> Result<(), String> {
+    if let Some(existing) = read_meta(path).await? {
+        if existing.skill_id == meta.skill_id {
+            meta.skill_md_only |= existing.skill_md_only;
+            if meta.catalog_files.is_empty() {
+                meta.catalog_files = existing.catalog_files;
+            }
+        }
+    }
+    write_meta(path, &meta).await
+}
+
 /// Save binary data (e.g. Git ZIP export) to disk.
 pub async fn save_export(data: &[u8], save_path: &str) -> Result<(), String> {
     let path = Path::new(save_path);
@@ -235,3 +636,140 @@ pub async fn save_export(data: &[u8], save_path: &str) -> Result<(), String> {
 
     Ok(())
 }
+
+/// Current UTC time like JavaScript's toISOString(), e.g. "2024-05-01T12:30:00.000Z"
+pub(crate) fn iso8601_now() -> String {
+    let elapsed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs = elapsed.as_secs() as i64;
+    let (days, rem) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60,
+        elapsed.subsec_millis()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::test_dir;
+
+    fn sync_file(filepath: &str, content: &str) -> SyncFile {
+        SyncFile {
+            filepath: filepath.to_string(),
+            content: content.to_string(),
+            content_hash: hash_content(content.as_bytes()),
+            file_size: content.len() as u64,
+        }
+    }
+
+    #[tokio::test]
+    async fn pull_leaves_unchanged_files_untouched() {
+        let dir = test_dir("unchanged-mtime");
+        let root = dir.to_string_lossy().to_string();
+        let files = vec![sync_file("SKILL.md", "# Skill\n"), sync_file("notes.md", "v1")];
+        write_files(&root, &files).await.unwrap();
+
+        // Backdate the file so any rewrite would be visible in its mtime
+        let old_mtime = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+        std::fs::File::options()
+            .write(true)
+            .open(dir.join("SKILL.md"))
+            .unwrap()
+            .set_modified(old_mtime)
+            .unwrap();
+
+        let files = vec![sync_file("SKILL.md", "# Skill\n"), sync_file("notes.md", "v2")];
+        let report = write_files(&root, &files).await.unwrap();
+
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(report.updated, vec!["notes.md".to_string()]);
+        let mtime = std::fs::metadata(dir.join("SKILL.md")).unwrap().modified().unwrap();
+        assert_eq!(mtime, old_mtime);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn collected_files_are_ordered_regardless_of_concurrency() {
+        let dir = test_dir("collect-order");
+        for i in 0..40 {
+            let sub = dir.join(format!("dir{}", i % 4));
+            std::fs::create_dir_all(&sub).unwrap();
+            std::fs::write(sub.join(format!("file{:02}.md", i)), "x".repeat(i * 100)).unwrap();
+        }
+        let root = dir.to_string_lossy().to_string();
+
+        let expected: Vec<String> = collect_files_with_concurrency(&root, 1)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|f| f.filepath)
+            .collect();
+        let mut sorted = expected.clone();
+        sorted.sort();
+        assert_eq!(expected, sorted);
+        assert_eq!(expected.len(), 40);
+
+        for concurrency in [2, 8, HASH_CONCURRENCY, 64] {
+            let paths: Vec<String> = collect_files_with_concurrency(&root, concurrency)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|f| f.filepath)
+                .collect();
+            assert_eq!(paths, expected, "concurrency {}", concurrency);
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn portable_paths_round_trip_nested_separators() {
+        for stored in ["scripts/lib/run.sh", "scripts\\lib\\run.sh", "scripts/lib\\run.sh"] {
+            let portable = normalize_portable_path(stored).unwrap();
+            assert_eq!(portable, "scripts/lib/run.sh");
+
+            let native = from_portable_path(&portable).unwrap();
+            assert_eq!(native, Path::new("scripts").join("lib").join("run.sh"));
+            assert_eq!(to_portable_path(&native), portable);
+        }
+        assert_eq!(normalize_portable_path("./docs//a.md").unwrap(), "docs/a.md");
+    }
+
+    #[test]
+    fn portable_paths_reject_escapes() {
+        let escapes = [
+            "../evil.sh",
+            "a/../../b",
+            "a\\..\\b",
+            "/etc/passwd",
+            "\\\\server\\share",
+            "C:\\x.md",
+            "c:/x.md",
+            "",
+            "./",
+        ];
+        for stored in escapes {
+            assert!(normalize_portable_path(stored).is_err(), "{} should be rejected", stored);
+            assert!(from_portable_path(stored).is_err(), "{} should be rejected", stored);
+        }
+    }
+}
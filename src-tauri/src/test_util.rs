@@ -0,0 +1,11 @@
+// Helpers shared by the unit tests in each module
+
+use std::path::PathBuf;
+
+/// A fresh, empty directory under the system temp dir
+pub(crate) fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("skillhub-test-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
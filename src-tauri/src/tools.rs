@@ -1,5 +1,9 @@
-use crate::{DetectedTool, InstalledSkill};
+use crate::{sync, DetectedTool, InstalledSkill};
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::fs;
 
 // Tool configurations based on OFFICIAL documentation:
@@ -24,6 +28,9 @@ use tokio::fs;
 // - TRAE IDE: https://docs.trae.ai/ide/skills
 //   Global: ~/.trae/skills/, Project: .trae/skills/
 //   Also supports rules: .trae/project_rules.md (v1.3.0+)
+// - Qoder: https://docs.qoder.com/
+//   Global: ~/.qoder/skills/, Project: .qoder/skills/
+//   Directory names can be corrected in ~/.skillhub/config.json (custom_tools)
 
 struct ToolConfig {
     id: &'static str,
@@ -33,9 +40,19 @@ struct ToolConfig {
     primary_subpath: &'static str,
     // All subpaths to scan for counting skills
     all_subpaths: &'static [&'static str],
+    // Extensions of standalone skill files (folders with a SKILL.md are always skills)
+    skill_extensions: &'static [&'static str],
+    // CLI used to probe the installed version (`<cli> --version`), if the tool has one
+    cli: Option<&'static str>,
+    // Whether the tool reads skills from the home directory and/or from a project
+    supports_personal: bool,
+    supports_project: bool,
 }
 
-const SUPPORTED_TOOLS: &[ToolConfig] = &[
+// Standalone skill file extensions for tools that don't set their own
+const DEFAULT_SKILL_EXTENSIONS: &[&str] = &["md"];
+
+static SUPPORTED_TOOLS: &[ToolConfig] = &[
     // Claude Code: ~/.claude/skills/
     // Personal: ~/.claude/skills/, Project: .claude/skills/
     ToolConfig {
@@ -44,6 +61,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".claude"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: Some("claude"),
+        supports_personal: true,
+        supports_project: true,
     },
     // Codex: ~/.codex/skills/
     // USER: ~/.codex/skills/, REPO: .codex/skills/, ADMIN: /etc/codex/skills/
@@ -53,6 +74,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".codex"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: Some("codex"),
+        supports_personal: true,
+        supports_project: true,
     },
     // Cursor: ~/.cursor/skills/ (v2.3.35+)
     ToolConfig {
@@ -61,6 +86,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".cursor"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: &["md", "mdc"], // .mdc rules
+        cli: Some("cursor"),
+        supports_personal: true,
+        supports_project: true,
     },
     // Cline: ~/.cline/skills/
     // Global: ~/.cline/skills/, Project: .cline/skills/
@@ -70,6 +99,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".cline"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: None,
+        supports_personal: true,
+        supports_project: true,
     },
     // OpenCode: ~/.config/opencode/skills/
     // Also supports .claude/skills/ for compatibility
@@ -79,6 +112,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".config/opencode"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: Some("opencode"),
+        supports_personal: true,
+        supports_project: true,
     },
     // Gemini CLI: ~/.gemini/skills/
     // User: ~/.gemini/skills/, Workspace: .gemini/skills/
@@ -88,6 +125,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".gemini"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: Some("gemini"),
+        supports_personal: true,
+        supports_project: true,
     },
     // Kilo Code: ~/.kilocode/skills/
     // Also has mode-specific: skills-code/, skills-architect/
@@ -97,6 +138,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".kilocode", ".kilo"],
         primary_subpath: "skills",
         all_subpaths: &["skills", "skills-code", "skills-architect"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: None,
+        supports_personal: true,
+        supports_project: true,
     },
     // GitHub Copilot (VS Code): ~/.copilot/skills/ (recommended)
     // Also supports ~/.claude/skills/ for legacy compatibility
@@ -107,14 +152,22 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".copilot"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: None,
+        supports_personal: true,
+        supports_project: true,
     },
-    // Windsurf: ~/.windsurf/rules/ (uses rules, not skills)
+    // Windsurf: .windsurf/rules/ (uses rules, not skills; project only)
     ToolConfig {
         id: "windsurf",
         name: "Windsurf",
         config_paths: &[".windsurf", ".codeium/windsurf"],
         primary_subpath: "rules",
         all_subpaths: &["rules"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: Some("windsurf"),
+        supports_personal: false,
+        supports_project: true,
     },
     // RooCode: ~/.roo/skills/
     ToolConfig {
@@ -123,6 +176,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".roo", ".roocode"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: None,
+        supports_personal: true,
+        supports_project: true,
     },
     // Aider: No official skills support
     ToolConfig {
@@ -131,6 +188,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".aider"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: Some("aider"),
+        supports_personal: true,
+        supports_project: true,
     },
     // Augment: ~/.augment/skills/
     ToolConfig {
@@ -139,6 +200,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".augment"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: None,
+        supports_personal: true,
+        supports_project: true,
     },
     // Continue: uses rules (not skills)
     // ToolConfig {
@@ -147,6 +212,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
     //     config_paths: &[".continue"],
     //     primary_subpath: "rules",
     //     all_subpaths: &["rules"],
+    //     skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+    //     cli: None,
+    //     supports_personal: true,
+    //     supports_project: true,
     // },
     // AWS Kiro: ~/.kiro/skills/
     ToolConfig {
@@ -155,6 +224,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".kiro"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: None,
+        supports_personal: true,
+        supports_project: true,
     },
     // Zencoder: ~/.zencoder/skills/
     ToolConfig {
@@ -163,6 +236,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".zencoder"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: None,
+        supports_personal: true,
+        supports_project: true,
     },
     // Zed: uses rules (not skills)
     ToolConfig {
@@ -171,6 +248,10 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".zed"],
         primary_subpath: "rules",
         all_subpaths: &["rules"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: Some("zed"),
+        supports_personal: true,
+        supports_project: true,
     },
     // TRAE IDE: ~/.trae/skills/
     // Global: ~/.trae/skills/, Project: .trae/skills/
@@ -181,6 +262,23 @@ const SUPPORTED_TOOLS: &[ToolConfig] = &[
         config_paths: &[".trae"],
         primary_subpath: "skills",
         all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: None,
+        supports_personal: true,
+        supports_project: true,
+    },
+    // Qoder: ~/.qoder/skills/
+    // Global: ~/.qoder/skills/, Project: .qoder/skills/
+    ToolConfig {
+        id: "qoder",
+        name: "Qoder",
+        config_paths: &[".qoder"],
+        primary_subpath: "skills",
+        all_subpaths: &["skills"],
+        skill_extensions: DEFAULT_SKILL_EXTENSIONS,
+        cli: None,
+        supports_personal: true,
+        supports_project: true,
     },
     // Note: VS Code uses GitHub Copilot for skills, so no separate vscode entry needed
 ];
@@ -194,38 +292,558 @@ fn fnv1a_hash(input: &str) -> u64 {
     hash
 }
 
-fn build_folder_name(skill_name: &str) -> String {
+/// Slug a skill name into a folder name, None if the name has nothing usable in one.
+/// ASCII is handled as it always was: lowercased, each space becomes `-`, and anything but
+/// letters, digits, `-` and `_` is dropped (so "Foo - Bar" is "foo---bar"). Non-ASCII
+/// letters and digits are kept (so "数据分析" stays readable) and other whitespace becomes `-`.
+fn slugify_skill_name(skill_name: &str) -> Option<String> {
     let trimmed = skill_name.trim();
-    if trimmed.is_empty() {
-        return "skill".to_string();
-    }
 
-    let sanitized = trimmed
+    let slug: String = trimmed
         .to_lowercase()
-        .replace(' ', "-")
         .chars()
-        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
-        .collect::<String>();
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_ascii_alphanumeric() || c == '-' || c == '_' => Some(c),
+            c if !c.is_ascii() && c.is_alphanumeric() => Some(c),
+            c if !c.is_ascii() && c.is_whitespace() => Some('-'),
+            _ => None,
+        })
+        .collect();
+
+    // A name made only of separators (e.g. " - ") slugs to nothing meaningful
+    if slug.chars().any(|c| c.is_alphanumeric()) {
+        return Some(slug);
+    }
+
+    // Names made only of symbols (e.g. emoji) get a stable hashed name; pure
+    // punctuation/whitespace is unusable
+    if trimmed
+        .chars()
+        .any(|c| !c.is_whitespace() && !c.is_ascii_punctuation())
+    {
+        return Some(format!("skill-{:x}", fnv1a_hash(trimmed)));
+    }
+
+    None
+}
+
+/// Build the folder name for a skill.
+/// Falls back to the frontmatter `name` of the SKILL.md content when the given name is empty
+/// or only symbols, and rejects the install if neither yields a usable name.
+fn build_folder_name(skill_name: &str, skill_content: Option<&str>) -> Result<String, String> {
+    slugify_skill_name(skill_name)
+        .or_else(|| {
+            let (name, _, _) = parse_skill_md(skill_content?);
+            slugify_skill_name(&name?)
+        })
+        .ok_or_else(|| {
+            format!(
+                "Invalid skill name '{}': it has no characters usable in a folder name",
+                skill_name.trim()
+            )
+        })
+}
+
+/// Whether a skill file path is the SKILL.md at the skill root (any case, either separator)
+fn is_root_skill_md(path: &str) -> bool {
+    sync::normalize_portable_path(path).is_ok_and(|p| p.eq_ignore_ascii_case("SKILL.md"))
+}
+
+/// Find the SKILL.md content among a skill's files, if present
+fn find_skill_md(files: &[(String, String)]) -> Option<&str> {
+    files
+        .iter()
+        .find(|(path, _)| is_root_skill_md(path))
+        .map(|(_, content)| content.as_str())
+}
+
+/// A skill's text files as (relative_path, content) pairs
+pub type TextFiles = Vec<(String, String)>;
+
+/// Keep only a skill's root SKILL.md, for installs that leave out bundled scripts and assets.
+/// Returns the kept files and the paths that were dropped.
+pub fn skill_md_only_files(files: &[(String, String)]) -> Result<(TextFiles, Vec<String>), String> {
+    let (kept, skipped): (Vec<_>, Vec<_>) = files
+        .iter()
+        .cloned()
+        .partition(|(path, _)| is_root_skill_md(path));
+    if kept.is_empty() {
+        return Err("Skill has no SKILL.md to install".to_string());
+    }
+    Ok((kept, skipped.into_iter().map(|(path, _)| path).collect()))
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SkillFilesInstallResult {
+    pub installed_paths: Vec<String>,
+    pub skipped_files: Vec<String>, // left out by a SKILL.md-only install
+    pub note: Option<String>,
+    pub verified: bool, // every installed file matched the hash the API reported
+    pub unverified_files: Vec<String>,
+}
+
+impl SkillFilesInstallResult {
+    pub fn new(installed: VerifiedInstallResult, skipped_files: Vec<String>) -> Self {
+        let note = (!skipped_files.is_empty()).then(|| {
+            format!(
+                "Installed SKILL.md only; skipped {} auxiliary file(s)",
+                skipped_files.len()
+            )
+        });
+        Self {
+            installed_paths: installed.installed_paths,
+            skipped_files,
+            note,
+            verified: installed.verified,
+            unverified_files: installed.unverified_files,
+        }
+    }
+}
+
+/// Names Windows reserves for devices, with or without an extension (e.g. `nul.txt`)
+const WINDOWS_RESERVED_NAMES: &[&str] = &[
+    "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8",
+    "com9", "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+];
+
+/// Use a caller-provided folder name verbatim, or fall back to the slug of the skill name.
+/// Custom names must be a single safe path component that skill scans will see.
+fn resolve_folder_name(
+    skill_name: &str,
+    skill_content: Option<&str>,
+    folder_name: Option<&str>,
+) -> Result<String, String> {
+    let Some(folder_name) = folder_name else {
+        return build_folder_name(skill_name, skill_content);
+    };
+
+    let trimmed = folder_name.trim();
+    if trimmed.is_empty() {
+        return Err("Folder name cannot be empty".to_string());
+    }
+    if trimmed.starts_with('.') {
+        return Err(format!(
+            "Folder name cannot start with a dot, hidden folders aren't listed as skills: {}",
+            folder_name
+        ));
+    }
+    if trimmed.ends_with('.') {
+        return Err(format!("Folder name cannot end with a dot: {}", folder_name));
+    }
+    if trimmed.contains(['/', '\\']) || trimmed.chars().any(|c| c.is_control()) {
+        return Err(format!("Folder name cannot contain path separators: {}", folder_name));
+    }
+    // Characters Windows doesn't allow in file names
+    if trimmed.contains(['<', '>', ':', '"', '|', '?', '*']) {
+        return Err(format!("Folder name contains invalid characters: {}", folder_name));
+    }
+    let stem = trimmed.split('.').next().unwrap_or(trimmed).trim_end();
+    if WINDOWS_RESERVED_NAMES.contains(&stem.to_ascii_lowercase().as_str()) {
+        return Err(format!("Folder name is reserved on Windows: {}", folder_name));
+    }
+
+    Ok(trimmed.to_string())
+}
+
+/// Diagnostic for a config path that exists but is a regular file
+fn not_a_directory_error(config_path: &str) -> String {
+    format!("~/{} exists but is not a directory", config_path)
+}
+
+/// Fail early with a clear message if a tool's config path is a file instead of a directory
+fn check_config_dir(home: &std::path::Path, config_path: &str) -> Result<(), String> {
+    let config_dir = home.join(config_path);
+    if config_dir.exists() && !config_dir.is_dir() {
+        return Err(not_a_directory_error(config_path));
+    }
+    Ok(())
+}
+
+/// Resolve a user-supplied directory path: expand a leading `~`, canonicalize, and check
+/// the result is an existing directory. Relative paths are rejected rather than resolved
+/// against the app's working directory.
+pub fn resolve_path(input: &str) -> Result<String, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err("Path is empty".to_string());
+    }
+
+    let expanded = match trimmed.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\']) => {
+            let home = crate::home::home_dir()?;
+            home.join(rest.trim_start_matches(['/', '\\']))
+        }
+        _ => PathBuf::from(trimmed),
+    };
+
+    if !expanded.is_absolute() {
+        return Err(format!("Path must be absolute (or start with ~): {}", input));
+    }
+    if !expanded.exists() {
+        return Err(format!("Path does not exist: {}", input));
+    }
+
+    let canonical = dunce::canonicalize(&expanded)
+        .map_err(|e| format!("Failed to resolve path {}: {}", input, e))?;
+
+    if !canonical.is_dir() {
+        return Err(format!("Path is not a directory: {}", input));
+    }
+
+    Ok(canonical.to_string_lossy().to_string())
+}
+
+/// A tool registered by the user in config.json (`custom_tools`): an editor SkillHub doesn't
+/// know yet, or a built-in tool whose directories differ on this machine (same id)
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CustomTool {
+    pub id: String,
+    pub name: String,
+    pub config_path: String, // relative to the home or project directory, e.g. ".trae"
+    #[serde(default = "default_skills_subpath")]
+    pub skills_subpath: String, // relative to config_path, "." for the config directory itself
+    #[serde(default = "default_true")]
+    pub supports_personal: bool,
+    #[serde(default = "default_true")]
+    pub supports_project: bool,
+}
+
+fn default_skills_subpath() -> String {
+    "skills".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Table entries built for custom tools, keyed by the tool as configured (None if it is
+/// invalid). Entries are leaked because tool lookups hand out 'static references, so each
+/// distinct tool is built once and editing the config doesn't leak the others again.
+static CUSTOM_TOOL_CONFIGS: Mutex<Vec<(CustomTool, Option<&'static ToolConfig>)>> =
+    Mutex::new(Vec::new());
+
+/// Check a custom tool before it is registered: a plain id, a name, and directories that
+/// stay inside the home or project directory
+fn validate_custom_tool(tool: &CustomTool) -> Result<(), String> {
+    let valid_id = !tool.id.is_empty()
+        && tool
+            .id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    if !valid_id {
+        return Err(format!(
+            "Invalid tool id '{}': use lowercase letters, digits, '-' and '_'",
+            tool.id
+        ));
+    }
+    if tool.name.trim().is_empty() {
+        return Err("Tool name cannot be empty".to_string());
+    }
+    sync::normalize_portable_path(&tool.config_path)
+        .map_err(|e| format!("Invalid config path '{}': {}", tool.config_path, e))?;
+    if tool.skills_subpath != "." {
+        sync::normalize_portable_path(&tool.skills_subpath)
+            .map_err(|e| format!("Invalid skills path '{}': {}", tool.skills_subpath, e))?;
+    }
+    if !tool.supports_personal && !tool.supports_project {
+        return Err("A tool must support personal or project skills".to_string());
+    }
+    Ok(())
+}
+
+fn leak_str(s: &str) -> &'static str {
+    Box::leak(s.to_string().into_boxed_str())
+}
+
+/// Build the table entry for a custom tool; a correction of a built-in tool keeps its CLI
+/// and skill file extensions
+fn custom_tool_config(tool: &CustomTool) -> &'static ToolConfig {
+    let builtin = SUPPORTED_TOOLS.iter().find(|t| t.id == tool.id);
+    let subpath = leak_str(&tool.skills_subpath);
+    Box::leak(Box::new(ToolConfig {
+        id: leak_str(&tool.id),
+        name: leak_str(&tool.name),
+        config_paths: Box::leak(vec![leak_str(&tool.config_path)].into_boxed_slice()),
+        primary_subpath: subpath,
+        all_subpaths: Box::leak(vec![subpath].into_boxed_slice()),
+        skill_extensions: builtin.map_or(DEFAULT_SKILL_EXTENSIONS, |t| t.skill_extensions),
+        cli: builtin.and_then(|t| t.cli),
+        supports_personal: tool.supports_personal,
+        supports_project: tool.supports_project,
+    }))
+}
+
+/// The built-in tools, with user corrections applied, followed by user-registered tools.
+/// Invalid entries in config.json are ignored with a warning.
+fn all_tools() -> Vec<&'static ToolConfig> {
+    let custom = crate::installer::read_skillhub_config().custom_tools;
+    if custom.is_empty() {
+        return SUPPORTED_TOOLS.iter().collect();
+    }
+
+    let configs: Vec<&'static ToolConfig> = {
+        let mut cache = CUSTOM_TOOL_CONFIGS.lock().unwrap_or_else(|e| e.into_inner());
+        custom
+            .into_iter()
+            .filter_map(|tool| {
+                if let Some((_, config)) = cache.iter().find(|(cached, _)| *cached == tool) {
+                    return *config;
+                }
+                let config = match validate_custom_tool(&tool) {
+                    Ok(()) => Some(custom_tool_config(&tool)),
+                    Err(e) => {
+                        tracing::warn!("Ignoring custom tool {}: {}", tool.id, e);
+                        None
+                    }
+                };
+                cache.push((tool, config));
+                config
+            })
+            .collect()
+    };
+
+    let mut tools: Vec<&'static ToolConfig> = SUPPORTED_TOOLS
+        .iter()
+        .map(|builtin| {
+            configs
+                .iter()
+                .find(|c| c.id == builtin.id)
+                .copied()
+                .unwrap_or(builtin)
+        })
+        .collect();
+    tools.extend(
+        configs
+            .iter()
+            .filter(|c| !SUPPORTED_TOOLS.iter().any(|builtin| builtin.id == c.id)),
+    );
+    tools
+}
+
+/// Look up a tool by id in a list from `all_tools`
+fn find_tool_in(
+    tools: &[&'static ToolConfig],
+    tool_id: &str,
+) -> Result<&'static ToolConfig, String> {
+    tools
+        .iter()
+        .find(|t| t.id == tool_id)
+        .copied()
+        .ok_or_else(|| format!("Unknown tool: {}", tool_id))
+}
+
+/// Look up a built-in or custom tool by id. Commands that handle several tools should call
+/// `all_tools` once and use `find_tool_in`, as each call here reads the config.
+fn find_tool(tool_id: &str) -> Result<&'static ToolConfig, String> {
+    find_tool_in(&all_tools(), tool_id)
+}
+
+/// Register a custom tool, replacing any registered one with the same id. Using a built-in
+/// tool's id corrects that tool's directories.
+pub async fn register_custom_tool(tool: CustomTool) -> Result<ToolInfo, String> {
+    validate_custom_tool(&tool)?;
+    let id = tool.id.clone();
+    crate::installer::update_skillhub_config(|config| {
+        config.custom_tools.retain(|t| t.id != tool.id);
+        config.custom_tools.push(tool);
+    })
+    .await?;
+    tracing::info!("Registered custom tool {}", id);
+    list_supported_tools()
+        .into_iter()
+        .find(|t| t.id == id)
+        .ok_or_else(|| format!("Custom tool {} could not be loaded", id))
+}
+
+/// Remove a registered custom tool; a corrected built-in tool goes back to its defaults
+pub async fn remove_custom_tool(tool_id: &str) -> Result<(), String> {
+    crate::installer::update_skillhub_config(|config| {
+        config.custom_tools.retain(|t| t.id != tool_id);
+    })
+    .await
+}
+
+/// Whether a tool should be scanned given the configured enabled_tools (None enables all)
+fn is_tool_enabled(tool_id: &str, enabled_tools: &Option<Vec<String>>) -> bool {
+    enabled_tools
+        .as_ref()
+        .map(|ids| ids.iter().any(|id| id == tool_id))
+        .unwrap_or(true)
+}
+
+/// The supported tools that are enabled in the config
+fn enabled_tool_configs() -> Vec<&'static ToolConfig> {
+    let enabled_tools = crate::installer::get_enabled_tools();
+    all_tools()
+        .into_iter()
+        .filter(|t| is_tool_enabled(t.id, &enabled_tools))
+        .collect()
+}
+
+/// (id, name) of each enabled tool
+pub(crate) fn enabled_tool_names() -> Vec<(&'static str, &'static str)> {
+    enabled_tool_configs().iter().map(|t| (t.id, t.name)).collect()
+}
+
+/// What to do when a skill already exists at the install destination
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    Error,
+    Skip,
+    Overwrite,
+    Merge,
+}
+
+/// Static description of a supported tool, for settings/onboarding screens
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ToolInfo {
+    pub id: String,
+    pub name: String,
+    pub config_paths: Vec<String>, // relative to the home or project directory
+    pub primary_subpath: String,
+    pub all_subpaths: Vec<String>,
+    pub supports_personal: bool,
+    pub supports_project: bool,
+    pub custom: bool, // registered or corrected in config.json
+}
+
+/// List every supported tool: the built-in table plus the tools registered in config.json.
+/// Only the config is read; skills directories aren't scanned.
+pub fn list_supported_tools() -> Vec<ToolInfo> {
+    all_tools()
+        .into_iter()
+        .map(|tool| ToolInfo {
+            id: tool.id.to_string(),
+            name: tool.name.to_string(),
+            config_paths: tool.config_paths.iter().map(|p| p.to_string()).collect(),
+            primary_subpath: tool.primary_subpath.to_string(),
+            all_subpaths: tool.all_subpaths.iter().map(|p| p.to_string()).collect(),
+            supports_personal: tool.supports_personal,
+            supports_project: tool.supports_project,
+            custom: !SUPPORTED_TOOLS.iter().any(|builtin| std::ptr::eq(builtin, tool)),
+        })
+        .collect()
+}
+
+/// Reject installing into the home directory for tools that only read project skills
+fn check_personal_scope(tool: &ToolConfig) -> Result<(), String> {
+    if !tool.supports_personal {
+        return Err(format!(
+            "{} only supports project skills; install into a project instead",
+            tool.name
+        ));
+    }
+    Ok(())
+}
+
+/// Reject installing into a project for tools that only read personal skills
+fn check_project_scope(tool: &ToolConfig) -> Result<(), String> {
+    if !tool.supports_project {
+        return Err(format!(
+            "{} only supports personal skills; install to your home directory instead",
+            tool.name
+        ));
+    }
+    Ok(())
+}
+
+/// Parse a conflict policy string: "error", "skip", "overwrite" or "merge"
+pub fn parse_conflict_policy(value: &str) -> Result<ConflictPolicy, String> {
+    match value {
+        "error" => Ok(ConflictPolicy::Error),
+        "skip" => Ok(ConflictPolicy::Skip),
+        "overwrite" => Ok(ConflictPolicy::Overwrite),
+        "merge" => Ok(ConflictPolicy::Merge),
+        _ => Err(format!("Invalid conflict policy: {}", value)),
+    }
+}
+
+/// Get the personal skills directory a tool installs into (e.g. ~/.claude/skills)
+pub fn get_tool_skills_dir(tool_id: &str) -> Result<PathBuf, String> {
+    let home = crate::home::home_dir()?;
+
+    let tool = find_tool(tool_id)?;
+    check_personal_scope(tool)?;
+
+    Ok(skills_dir_in(tool, &home))
+}
 
-    if !sanitized.is_empty() {
-        return sanitized;
+/// A tool's skills directory (where skill folders are installed) under `base`,
+/// which is the home directory or a project directory
+fn skills_dir_in(tool: &ToolConfig, base: &std::path::Path) -> PathBuf {
+    let config_dir = base.join(tool.config_paths[0]);
+    if tool.primary_subpath == "." {
+        config_dir
+    } else {
+        config_dir.join(tool.primary_subpath)
     }
+}
+
+/// The folder a skill would be installed into for each tool, as (tool_id, absolute path),
+/// without creating anything. `scope` is "personal" (home directory) or "project"
+/// (requires `project_path`). The folder is named the way the installers name it, from
+/// `folder_name` or the skill name, falling back to the frontmatter name in `skill_content`.
+pub fn resolve_install_paths(
+    skill_name: &str,
+    skill_content: Option<&str>,
+    folder_name: Option<&str>,
+    tool_ids: &[String],
+    scope: &str,
+    project_path: Option<&str>,
+) -> Result<Vec<(String, String)>, String> {
+    let base = match scope {
+        "personal" => crate::home::home_dir()?,
+        "project" => {
+            let project_path = project_path.ok_or("A project path is required for project scope")?;
+            PathBuf::from(resolve_path(project_path)?)
+        }
+        _ => return Err(format!("Invalid install scope: {}", scope)),
+    };
+    let folder_name = resolve_folder_name(skill_name, skill_content, folder_name)?;
+
+    let tools = all_tools();
+    tool_ids
+        .iter()
+        .map(|tool_id| {
+            let tool = find_tool_in(&tools, tool_id)?;
+            if scope == "project" {
+                check_project_scope(tool)?;
+            } else {
+                check_personal_scope(tool)?;
+            }
 
-    format!("skill-{:x}", fnv1a_hash(trimmed))
+            let path = skills_dir_in(tool, &base).join(&folder_name);
+            Ok((tool_id.clone(), path.to_string_lossy().to_string()))
+        })
+        .collect()
 }
 
-fn get_home_dir() -> Option<PathBuf> {
-    dirs::home_dir()
+/// Check whether skills can be installed into a tool's personal skills directory
+pub fn check_tool_writable(tool_id: &str) -> Result<crate::disk::WritableStatus, String> {
+    let skills_dir = get_tool_skills_dir(tool_id)?;
+    Ok(crate::disk::check_writable(&skills_dir))
 }
 
-pub async fn detect_all_tools() -> Result<Vec<DetectedTool>, String> {
-    let home = get_home_dir().ok_or("Cannot find home directory")?;
+/// Detect installed tools under the home directory, or under `base_dir` when given
+/// (for setups where the home directory can't be found)
+pub async fn detect_all_tools(base_dir: Option<&str>) -> Result<Vec<DetectedTool>, String> {
+    let home = crate::home::base_dir_or_home(base_dir)?;
     let mut detected = Vec::new();
 
-    for tool in SUPPORTED_TOOLS {
+    let enabled_tools = crate::installer::get_enabled_tools();
+    let tools = all_tools();
+
+    for tool in &tools {
+        // Disabled tools are listed below without scanning
+        if !is_tool_enabled(tool.id, &enabled_tools) {
+            continue;
+        }
+
         for config_path in tool.config_paths {
             let config_dir = home.join(config_path);
-            let installed = config_dir.exists();
+            // A regular file at the config path (a misconfiguration) doesn't count as installed
+            let installed = config_dir.is_dir();
 
             if installed {
                 // Count skills from all supported subpaths
@@ -233,8 +851,8 @@ pub async fn detect_all_tools() -> Result<Vec<DetectedTool>, String> {
 
                 for subpath in tool.all_subpaths {
                     let skills_dir = config_dir.join(subpath);
-                    if skills_dir.exists() {
-                        total_skills += count_skills(&skills_dir).await.unwrap_or(0);
+                    if skills_dir.is_dir() {
+                        total_skills += count_skills(&skills_dir, tool.skill_extensions).await.unwrap_or(0);
                     }
                 }
 
@@ -252,6 +870,12 @@ pub async fn detect_all_tools() -> Result<Vec<DetectedTool>, String> {
                     skills_path: primary_dir.to_string_lossy().to_string(),
                     installed,
                     skills_count: total_skills,
+                    version: None,
+                    enabled: true,
+                    error: None,
+                    supports_personal: tool.supports_personal,
+                    supports_project: tool.supports_project,
+                    scope: "personal".to_string(),
                 });
                 break; // Found this tool, move to next
             }
@@ -259,7 +883,7 @@ pub async fn detect_all_tools() -> Result<Vec<DetectedTool>, String> {
     }
 
     // Also check for tools not installed yet but show them as available
-    for tool in SUPPORTED_TOOLS {
+    for tool in &tools {
         if !detected.iter().any(|d| d.id == tool.id) {
             let config_dir = home.join(tool.config_paths[0]);
             let primary_dir = if tool.primary_subpath == "." {
@@ -268,6 +892,13 @@ pub async fn detect_all_tools() -> Result<Vec<DetectedTool>, String> {
                 config_dir.join(tool.primary_subpath)
             };
 
+            let enabled = is_tool_enabled(tool.id, &enabled_tools);
+            let error = tool
+                .config_paths
+                .iter()
+                .find(|p| enabled && home.join(p).exists())
+                .map(|p| not_a_directory_error(p));
+
             detected.push(DetectedTool {
                 name: tool.name.to_string(),
                 id: tool.id.to_string(),
@@ -275,39 +906,217 @@ pub async fn detect_all_tools() -> Result<Vec<DetectedTool>, String> {
                 skills_path: primary_dir.to_string_lossy().to_string(),
                 installed: false,
                 skills_count: 0,
+                version: None,
+                enabled,
+                error,
+                supports_personal: tool.supports_personal,
+                supports_project: tool.supports_project,
+                scope: "personal".to_string(),
             });
         }
     }
 
+    // Probe versions of installed tools concurrently
+    let tools = &tools;
+    let versions = futures::future::join_all(detected.iter().map(|d| async move {
+        if d.installed {
+            probe_tool_version(find_tool_in(tools, &d.id).ok()?).await
+        } else {
+            None
+        }
+    }))
+    .await;
+    for (tool, version) in detected.iter_mut().zip(versions) {
+        tool.version = version;
+    }
+
+    Ok(detected)
+}
+
+/// Check which tools a project is already set up for, by looking for each project-capable
+/// tool's config directory (e.g. `.cursor/`) in it. Tools the project doesn't use are listed
+/// as not installed, so the UI can pre-select the others.
+pub async fn detect_project_tools(project_path: &str) -> Result<Vec<DetectedTool>, String> {
+    let project_dir = PathBuf::from(resolve_path(project_path)?);
+    let enabled_tools = crate::installer::get_enabled_tools();
+    let mut detected = Vec::new();
+
+    for tool in all_tools().into_iter().filter(|t| t.supports_project) {
+        let config_path = tool
+            .config_paths
+            .iter()
+            .find(|p| project_dir.join(p).is_dir())
+            .unwrap_or(&tool.config_paths[0]);
+        let config_dir = project_dir.join(config_path);
+        let installed = config_dir.is_dir();
+
+        let mut skills_count = 0;
+        if installed {
+            for subpath in tool.all_subpaths {
+                let skills_dir = config_dir.join(subpath);
+                if skills_dir.is_dir() {
+                    skills_count += count_skills_in_dir(&skills_dir, tool.skill_extensions).await;
+                }
+            }
+        }
+
+        let error = (!installed && config_dir.exists())
+            .then(|| format!("{} exists in the project but is not a directory", config_path));
+        let skills_path = if tool.primary_subpath == "." {
+            config_dir.clone()
+        } else {
+            config_dir.join(tool.primary_subpath)
+        };
+
+        detected.push(DetectedTool {
+            name: tool.name.to_string(),
+            id: tool.id.to_string(),
+            config_path: config_dir.to_string_lossy().to_string(),
+            skills_path: skills_path.to_string_lossy().to_string(),
+            installed,
+            skills_count,
+            version: None,
+            enabled: is_tool_enabled(tool.id, &enabled_tools),
+            error,
+            supports_personal: tool.supports_personal,
+            supports_project: tool.supports_project,
+            scope: "project".to_string(),
+        });
+    }
+
     Ok(detected)
 }
 
-async fn count_skills(skills_dir: &PathBuf) -> Result<usize, String> {
-    let count = count_skills_in_dir(skills_dir).await;
+/// Tool versions probed so far, spawning CLIs is slow so each is probed once per session
+static TOOL_VERSIONS: Mutex<BTreeMap<String, Option<String>>> = Mutex::new(BTreeMap::new());
+
+/// How long a probe like `<cli> --version` may run; a CLI stuck on a first-run, login or
+/// update prompt is killed so callers don't wait on it
+const CLI_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Program names to try for a CLI. On Windows many CLIs (npm packages, Cursor, Windsurf)
+/// are `.cmd` shims, which aren't found without the extension.
+#[cfg(windows)]
+fn cli_programs(cli: &str) -> Vec<String> {
+    vec![format!("{}.cmd", cli), cli.to_string()]
+}
+
+#[cfg(not(windows))]
+fn cli_programs(cli: &str) -> Vec<String> {
+    vec![cli.to_string()]
+}
+
+/// Run a CLI with no input and return its stdout, None if it can't be found, fails, or
+/// doesn't finish within `CLI_PROBE_TIMEOUT` (it's killed then)
+pub(crate) async fn run_cli(cli: &str, args: &[&str]) -> Option<String> {
+    for program in cli_programs(cli) {
+        // Not found under this name, try the next one
+        let Ok(child) = tokio::process::Command::new(&program)
+            .args(args)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+        else {
+            continue;
+        };
+
+        // On timeout the child is dropped, which kills it
+        return match tokio::time::timeout(CLI_PROBE_TIMEOUT, child.wait_with_output()).await {
+            Ok(Ok(output)) if output.status.success() => {
+                Some(String::from_utf8_lossy(&output.stdout).to_string())
+            }
+            Ok(_) => None,
+            Err(_) => {
+                tracing::warn!(
+                    "{} {} did not finish within {}s",
+                    program,
+                    args.join(" "),
+                    CLI_PROBE_TIMEOUT.as_secs()
+                );
+                None
+            }
+        };
+    }
+    None
+}
+
+/// Best-effort version of a tool from `<cli> --version`, None for tools without a CLI or
+/// whose CLI fails or times out
+async fn probe_tool_version(tool: &ToolConfig) -> Option<String> {
+    let cli = tool.cli?;
+
+    if let Some(cached) = TOOL_VERSIONS.lock().ok()?.get(tool.id) {
+        return cached.clone();
+    }
+
+    let version = run_cli(cli, &["--version"])
+        .await
+        .and_then(|output| output.lines().next().and_then(extract_version));
+
+    if let Ok(mut versions) = TOOL_VERSIONS.lock() {
+        versions.insert(tool.id.to_string(), version.clone());
+    }
+    version
+}
+
+/// Pick the version number out of CLI output like "1.0.3 (Claude Code)" or "codex-cli 0.1.0"
+fn extract_version(output: &str) -> Option<String> {
+    output
+        .split_whitespace()
+        .map(|word| word.trim_start_matches('v').trim_end_matches(','))
+        .find(|word| word.contains('.') && word.starts_with(|c: char| c.is_ascii_digit()))
+        .map(|word| word.to_string())
+}
+
+async fn count_skills(skills_dir: &PathBuf, extensions: &[&str]) -> Result<usize, String> {
+    let count = count_skills_in_dir(skills_dir, extensions).await;
     Ok(count)
 }
 
-async fn count_skills_in_dir(dir: &PathBuf) -> usize {
+// Entries at the root of a skills directory that are documentation or examples, not skills
+const NON_SKILL_DIRS: &[&str] = &["_template", ".template"];
+const NON_SKILL_FILES: &[&str] = &["readme.md", "license.md"];
+
+/// Whether a skills-root entry should be left out of counts and listings
+fn is_non_skill_entry(name: &str, is_dir: bool) -> bool {
+    if is_dir {
+        NON_SKILL_DIRS.contains(&name)
+    } else {
+        NON_SKILL_FILES.contains(&name.to_lowercase().as_str())
+    }
+}
+
+/// Whether a file is a standalone skill for a tool, by its extension (e.g. "md", "mdc")
+fn has_skill_extension(path: &std::path::Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| extensions.iter().any(|ext| e.eq_ignore_ascii_case(ext)))
+}
+
+async fn count_skills_in_dir(dir: &PathBuf, extensions: &[&str]) -> usize {
     let mut count = 0;
 
     if let Ok(mut entries) = fs::read_dir(dir).await {
         while let Ok(Some(entry)) = entries.next_entry().await {
             let path = entry.path();
-            // Skip hidden files/directories
-            if path.file_name()
-                .map(|n| n.to_string_lossy().starts_with('.'))
-                .unwrap_or(false)
-            {
+            // Skip hidden entries, READMEs/templates and names that aren't valid UTF-8
+            match sync::checked_file_name(&path) {
+                Some(name) if !name.starts_with('.') && !is_non_skill_entry(&name, path.is_dir()) => {}
+                _ => continue,
+            }
+            if is_broken_link(&path).await {
                 continue;
             }
-            
+
             if path.is_dir() {
                 // Check if it has SKILL.md
                 if path.join("SKILL.md").exists() {
                     count += 1;
                 }
-            } else if path.extension().map(|e| e == "md").unwrap_or(false) {
-                // Or is a .md file itself
+            } else if has_skill_extension(&path, extensions) {
+                // Or is a skill file itself
                 count += 1;
             }
         }
@@ -317,69 +1126,289 @@ async fn count_skills_in_dir(dir: &PathBuf) -> usize {
 }
 
 pub async fn get_skills_for_tool(tool_id: &str) -> Result<Vec<InstalledSkill>, String> {
-    let home = get_home_dir().ok_or("Cannot find home directory")?;
-    let mut skills = Vec::new();
+    skills_for_tool(find_tool(tool_id)?).await
+}
+
+/// Skills installed for a tool that was already looked up
+async fn skills_for_tool(tool: &ToolConfig) -> Result<Vec<InstalledSkill>, String> {
+    let home = crate::home::home_dir()?;
 
-    let tool = SUPPORTED_TOOLS
+    let dirs = tool_skill_dirs(tool, &home)
+        .into_iter()
+        .map(|dir| (tool, dir))
+        .collect();
+    Ok(collect_skills_from_dirs(dirs).await)
+}
+
+/// Skills directories a tool reads under `base` (the home or a project directory): every
+/// skills subpath of the first of its config directories that exists
+fn tool_skill_dirs(tool: &ToolConfig, base: &std::path::Path) -> Vec<PathBuf> {
+    let Some(config_dir) = tool
+        .config_paths
         .iter()
-        .find(|t| t.id == tool_id)
-        .ok_or_else(|| format!("Unknown tool: {}", tool_id))?;
+        .map(|config_path| base.join(config_path))
+        .find(|config_dir| config_dir.is_dir())
+    else {
+        return Vec::new();
+    };
 
-    'outer: for config_path in tool.config_paths {
-        let config_dir = home.join(config_path);
-        if !config_dir.exists() {
-            continue;
-        }
+    tool.all_subpaths
+        .iter()
+        .map(|subpath| config_dir.join(subpath))
+        .filter(|skills_dir| skills_dir.is_dir())
+        .collect()
+}
 
-        // Check all supported skills subpaths
-        for subpath in tool.all_subpaths {
-            let skills_dir = config_dir.join(subpath);
+/// Skills directories under every config directory a tool has in a project. Projects
+/// may use any of a tool's config paths, not only the first one that exists.
+fn project_skill_dirs(tool: &ToolConfig, project_dir: &std::path::Path) -> Vec<PathBuf> {
+    tool.config_paths
+        .iter()
+        .map(|config_path| project_dir.join(config_path))
+        .filter(|config_dir| config_dir.is_dir())
+        .flat_map(|config_dir| tool.all_subpaths.iter().map(move |subpath| config_dir.join(subpath)))
+        .filter(|skills_dir| skills_dir.is_dir())
+        .collect()
+}
 
-            if !skills_dir.exists() {
-                continue;
+/// Skills directories of several tools, as listed by `skill_dirs`, each paired with the first
+/// tool that reads it. Tools can share a skills directory, so collecting from these lists each
+/// skill once.
+fn unique_skill_dirs<'a>(
+    tools: impl IntoIterator<Item = &'a ToolConfig>,
+    skill_dirs: impl Fn(&ToolConfig) -> Vec<PathBuf>,
+) -> Vec<(&'a ToolConfig, PathBuf)> {
+    let mut dirs: Vec<(&ToolConfig, PathBuf)> = Vec::new();
+    for tool in tools {
+        for dir in skill_dirs(tool) {
+            if !dirs.iter().any(|(_, seen)| *seen == dir) {
+                dirs.push((tool, dir));
             }
-
-            collect_skills_from_dir(&skills_dir, tool_id, &mut skills).await;
         }
-        // Found config dir, stop looking at alternative config paths
-        break 'outer;
     }
+    dirs
+}
 
-    Ok(skills)
+/// Skills found in each (tool, skills directory) pair
+async fn collect_skills_from_dirs(dirs: Vec<(&ToolConfig, PathBuf)>) -> Vec<InstalledSkill> {
+    let mut skills = Vec::new();
+    for (tool, dir) in dirs {
+        collect_skills_from_dir(&dir, tool.id, tool.skill_extensions, &mut skills).await;
+    }
+    skills
 }
 
-async fn collect_skills_from_dir(skills_dir: &PathBuf, tool_id: &str, skills: &mut Vec<InstalledSkill>) {
-    if let Ok(mut entries) = fs::read_dir(skills_dir).await {
-        while let Ok(Some(entry)) = entries.next_entry().await {
-            let path = entry.path();
-            
-            // Skip hidden files/directories
-            if path.file_name()
-                .map(|n| n.to_string_lossy().starts_with('.'))
-                .unwrap_or(false)
-            {
-                continue;
-            }
+/// A folder in a skills directory that isn't recognized as a skill
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UnrecognizedEntry {
+    pub name: String,
+    pub path: String,
+    pub reason: String,
+}
 
-            if path.is_dir() {
-                let skill_md = path.join("SKILL.md");
-                if skill_md.exists() {
-                    if let Ok(content) = fs::read_to_string(&skill_md).await {
-                        let (name, description, author) = parse_skill_md(&content);
-                        skills.push(InstalledSkill {
-                            name: name.unwrap_or_else(|| {
-                                path.file_name()
-                                    .map(|n| n.to_string_lossy().to_string())
-                                    .unwrap_or_default()
-                            }),
+/// Get the personal skills directories of a tool that exist on disk
+/// (all subpaths under the first config path found)
+pub(crate) fn existing_skills_dirs(tool_id: &str) -> Result<Vec<PathBuf>, String> {
+    let home = crate::home::home_dir()?;
+
+    let tool = find_tool(tool_id)?;
+
+    let Some(config_dir) = tool
+        .config_paths
+        .iter()
+        .map(|config_path| home.join(config_path))
+        .find(|dir| dir.is_dir())
+    else {
+        return Ok(Vec::new());
+    };
+
+    Ok(tool
+        .all_subpaths
+        .iter()
+        .map(|subpath| config_dir.join(subpath))
+        .filter(|dir| dir.is_dir())
+        .collect())
+}
+
+/// Find folders in a tool's skills directories that aren't counted as skills, with the reason
+pub async fn scan_skill_health(tool_id: &str) -> Result<Vec<UnrecognizedEntry>, String> {
+    let home = crate::home::home_dir()?;
+    let mut unrecognized = Vec::new();
+
+    let tool = find_tool(tool_id)?;
+
+    for config_path in tool.config_paths {
+        let config_dir = home.join(config_path);
+        if !config_dir.is_dir() {
+            continue;
+        }
+
+        for subpath in tool.all_subpaths {
+            let skills_dir = config_dir.join(subpath);
+            let Ok(mut entries) = fs::read_dir(&skills_dir).await else {
+                continue;
+            };
+
+            while let Ok(Some(entry)) = entries.next_entry().await {
+                let path = entry.path();
+                let name = match sync::checked_file_name(&path) {
+                    Some(name) if !name.starts_with('.') => name,
+                    _ => continue,
+                };
+                if !path.is_dir() || path.join("SKILL.md").exists() {
+                    continue;
+                }
+
+                unrecognized.push(UnrecognizedEntry {
+                    name,
+                    reason: unrecognized_reason(&path).await,
+                    path: path.to_string_lossy().to_string(),
+                });
+            }
+        }
+        // Found config dir, stop looking at alternative config paths
+        break;
+    }
+
+    Ok(unrecognized)
+}
+
+/// Explain why a folder without SKILL.md isn't a skill
+async fn unrecognized_reason(dir: &std::path::Path) -> String {
+    let mut markdown = Vec::new();
+    let mut has_entries = false;
+
+    if let Ok(mut entries) = fs::read_dir(dir).await {
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            let Some(name) = sync::checked_file_name(&path) else {
+                continue;
+            };
+            if sync::should_skip(&name) {
+                continue;
+            }
+            has_entries = true;
+            if path.is_file() && path.extension().map(|e| e == "md").unwrap_or(false) {
+                markdown.push(name);
+            }
+        }
+    }
+
+    if !has_entries {
+        "Empty folder".to_string()
+    } else if markdown.is_empty() {
+        "No SKILL.md and no markdown files".to_string()
+    } else if let Some(name) = markdown.iter().find(|n| n.eq_ignore_ascii_case("SKILL.md")) {
+        // Tools look for the exact name, so a differently-cased file isn't picked up
+        format!("No SKILL.md (found {} with different casing)", name)
+    } else {
+        markdown.sort();
+        format!("No SKILL.md (only {})", markdown.join(", "))
+    }
+}
+
+/// Whether a path is itself a symlink (or a junction on Windows), without following it
+async fn is_link(path: &std::path::Path) -> bool {
+    fs::symlink_metadata(path)
+        .await
+        .map(|m| m.file_type().is_symlink())
+        .unwrap_or(false)
+}
+
+/// Whether a path is a symlink (or junction) whose target no longer exists
+async fn is_broken_link(path: &std::path::Path) -> bool {
+    is_link(path).await
+        && matches!(fs::metadata(path).await, Err(e) if e.kind() == std::io::ErrorKind::NotFound)
+}
+
+/// Find dangling symlinks anywhere in a tool's skills directories, e.g. linked installs
+/// whose shared folder was deleted. Links are not followed while walking.
+pub async fn find_broken_symlinks(tool_id: &str) -> Result<Vec<String>, String> {
+    let mut broken = Vec::new();
+    for skills_dir in existing_skills_dirs(tool_id)? {
+        collect_broken_links(skills_dir, &mut broken).await;
+    }
+    broken.sort();
+    broken.dedup();
+    Ok(broken)
+}
+
+#[async_recursion::async_recursion]
+async fn collect_broken_links(dir: PathBuf, broken: &mut Vec<String>) {
+    let Ok(mut entries) = fs::read_dir(&dir).await else {
+        return;
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if is_link(&path).await {
+            if is_broken_link(&path).await {
+                broken.push(path.to_string_lossy().to_string());
+            }
+        } else if path.is_dir() {
+            collect_broken_links(path, broken).await;
+        }
+    }
+}
+
+/// Remove dangling symlinks found by `find_broken_symlinks`, returning the removed paths.
+/// Paths that aren't (or are no longer) broken links are left alone.
+pub async fn remove_broken_symlinks(paths: &[String]) -> Result<Vec<String>, String> {
+    let mut removed = Vec::new();
+    for path in paths {
+        let link = std::path::Path::new(path);
+        if !is_broken_link(link).await {
+            tracing::warn!("Not removing {}: it is not a broken link", path);
+            continue;
+        }
+        // Directory links (junctions on Windows) and file links are removed differently
+        remove_dir_link(link)
+            .or_else(|_| std::fs::remove_file(link))
+            .map_err(|e| format!("Failed to remove link {}: {}", path, e))?;
+        removed.push(path.clone());
+    }
+    Ok(removed)
+}
+
+async fn collect_skills_from_dir(
+    skills_dir: &PathBuf,
+    tool_id: &str,
+    extensions: &[&str],
+    skills: &mut Vec<InstalledSkill>,
+) {
+    if let Ok(mut entries) = fs::read_dir(skills_dir).await {
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            // Skip hidden entries, READMEs/templates and names that aren't valid UTF-8
+            match sync::checked_file_name(&path) {
+                Some(name) if !name.starts_with('.') && !is_non_skill_entry(&name, path.is_dir()) => {}
+                _ => continue,
+            }
+            if is_broken_link(&path).await {
+                tracing::warn!("Skipping broken link {}", path.display());
+                continue;
+            }
+
+            if path.is_dir() {
+                let skill_md = path.join("SKILL.md");
+                if skill_md.exists() {
+                    if let Ok(content) = fs::read_to_string(&skill_md).await {
+                        let (name, description, author) = parse_skill_md(&content);
+                        skills.push(InstalledSkill {
+                            name: name.unwrap_or_else(|| {
+                                path.file_name()
+                                    .map(|n| n.to_string_lossy().to_string())
+                                    .unwrap_or_default()
+                            }),
                             path: path.to_string_lossy().to_string(),
                             description,
                             author,
                             tool_id: tool_id.to_string(),
+                            modified_at: get_modified_at(&skill_md).await,
+                            linked: is_link(&path).await,
                         });
                     }
                 }
-            } else if path.extension().map(|e| e == "md").unwrap_or(false) {
+            } else if has_skill_extension(&path, extensions) {
                 if let Ok(content) = fs::read_to_string(&path).await {
                     let (name, description, author) = parse_skill_md(&content);
                     skills.push(InstalledSkill {
@@ -392,6 +1421,8 @@ async fn collect_skills_from_dir(skills_dir: &PathBuf, tool_id: &str, skills: &m
                         description,
                         author,
                         tool_id: tool_id.to_string(),
+                        modified_at: get_modified_at(&path).await,
+                        linked: is_link(&path).await,
                     });
                 }
             }
@@ -399,6 +1430,102 @@ async fn collect_skills_from_dir(skills_dir: &PathBuf, tool_id: &str, skills: &m
     }
 }
 
+/// Get the skills installed in a project directory across all supported tools
+pub async fn get_project_skills(project_path: &str) -> Vec<InstalledSkill> {
+    let project_dir = PathBuf::from(project_path);
+    let dirs = unique_skill_dirs(all_tools(), |tool| project_skill_dirs(tool, &project_dir));
+    collect_skills_from_dirs(dirs).await
+}
+
+/// Last modification time of a file as a Unix timestamp (seconds)
+async fn get_modified_at(path: &PathBuf) -> Option<u64> {
+    let modified = fs::metadata(path).await.ok()?.modified().ok()?;
+    modified
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs())
+}
+
+/// Get installed skills across all supported tools, most recently modified first
+pub async fn get_all_installed_skills() -> Result<Vec<InstalledSkill>, String> {
+    let home = crate::home::home_dir()?;
+    let dirs = unique_skill_dirs(enabled_tool_configs(), |tool| tool_skill_dirs(tool, &home));
+    let mut skills = collect_skills_from_dirs(dirs).await;
+
+    skills.sort_by_key(|s| std::cmp::Reverse(s.modified_at));
+    Ok(skills)
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InstalledSkillsPage {
+    pub skills: Vec<InstalledSkill>,
+    pub total: usize, // skills across all pages
+}
+
+/// One page of the installed skills of the given tools (all enabled tools when empty).
+/// `sort_by` is "name", "modified_at" (newest first) or "size" (largest first);
+/// `page` starts at 1. Sizes aren't part of InstalledSkill, so they're only measured
+/// when sorting by size.
+pub async fn list_installed_skills_paged(
+    tool_ids: &[String],
+    sort_by: &str,
+    page: usize,
+    limit: usize,
+) -> Result<InstalledSkillsPage, String> {
+    if page == 0 || limit == 0 {
+        return Err("Page and limit must be at least 1".to_string());
+    }
+
+    let mut skills = if tool_ids.is_empty() {
+        get_all_installed_skills().await?
+    } else {
+        let home = crate::home::home_dir()?;
+        let tools = all_tools();
+        let selected = tool_ids
+            .iter()
+            .map(|tool_id| find_tool_in(&tools, tool_id))
+            .collect::<Result<Vec<_>, String>>()?;
+        let dirs = unique_skill_dirs(selected, |tool| tool_skill_dirs(tool, &home));
+        collect_skills_from_dirs(dirs).await
+    };
+
+    match sort_by {
+        "name" => skills.sort_by(|a, b| {
+            a.name
+                .to_lowercase()
+                .cmp(&b.name.to_lowercase())
+                .then_with(|| a.path.cmp(&b.path))
+        }),
+        "modified_at" => skills.sort_by(|a, b| {
+            b.modified_at
+                .cmp(&a.modified_at)
+                .then_with(|| a.path.cmp(&b.path))
+        }),
+        "size" => {
+            let sizes = futures::future::join_all(
+                skills
+                    .iter()
+                    .map(|s| crate::skill_backups::disk_usage(PathBuf::from(&s.path))),
+            )
+            .await;
+            let mut sized: Vec<(u64, InstalledSkill)> = sizes.into_iter().zip(skills).collect();
+            sized.sort_by(|(a_size, a), (b_size, b)| {
+                b_size.cmp(a_size).then_with(|| a.path.cmp(&b.path))
+            });
+            skills = sized.into_iter().map(|(_, skill)| skill).collect();
+        }
+        other => return Err(format!("Unknown sort order: {}", other)),
+    }
+
+    let total = skills.len();
+    let skills = skills
+        .into_iter()
+        .skip((page - 1).saturating_mul(limit))
+        .take(limit)
+        .collect();
+    Ok(InstalledSkillsPage { skills, total })
+}
+
 fn parse_skill_md(content: &str) -> (Option<String>, Option<String>, Option<String>) {
     let mut name = None;
     let mut description = None;
@@ -435,81 +1562,754 @@ fn parse_skill_md(content: &str) -> (Option<String>, Option<String>, Option<Stri
     (name, description, author)
 }
 
+/// Outcome of installing a skill into one tool
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InstallResult {
+    pub tool_id: String,
+    pub status: String, // "installed" or "failed"
+    pub path: Option<String>,
+    pub error: Option<String>,
+    pub verified: bool, // content matched the hash the API reported
+}
+
+impl InstallResult {
+    fn from_result(tool_id: &str, result: Result<String, String>) -> Self {
+        match result {
+            Ok(path) => InstallResult {
+                tool_id: tool_id.to_string(),
+                status: "installed".to_string(),
+                path: Some(path),
+                error: None,
+                verified: false,
+            },
+            Err(e) => {
+                tracing::warn!("Install into {} failed: {}", tool_id, e);
+                InstallResult {
+                    tool_id: tool_id.to_string(),
+                    status: "failed".to_string(),
+                    path: None,
+                    error: Some(e),
+                    verified: false,
+                }
+            }
+        }
+    }
+}
+
+/// Install a single SKILL.md into each tool. A failure in one tool doesn't stop the others;
+/// every tool gets its own result. With `expected_sha256` the content is checked first and
+/// nothing is installed on a mismatch; without it the results are marked unverified.
 pub async fn install_skill_to_tools(
     skill_content: &str,
     skill_name: &str,
     tool_ids: &[String],
-) -> Result<Vec<String>, String> {
-    let home = get_home_dir().ok_or("Cannot find home directory")?;
-    let mut installed_paths = Vec::new();
+    folder_name: Option<&str>,
+    expected_sha256: Option<&str>,
+    base_dir: Option<&str>,
+) -> Result<Vec<InstallResult>, String> {
+    let tools = all_tools();
+    install_skill_with_tools(
+        &tools,
+        skill_content,
+        skill_name,
+        tool_ids,
+        folder_name,
+        expected_sha256,
+        base_dir,
+    )
+    .await
+}
+
+/// `install_skill_to_tools` with the tool list already loaded
+async fn install_skill_with_tools(
+    tools: &[&'static ToolConfig],
+    skill_content: &str,
+    skill_name: &str,
+    tool_ids: &[String],
+    folder_name: Option<&str>,
+    expected_sha256: Option<&str>,
+    base_dir: Option<&str>,
+) -> Result<Vec<InstallResult>, String> {
+    let home = crate::home::base_dir_or_home(base_dir)?;
+    let expected: HashMap<String, String> = expected_sha256
+        .map(|hash| ("SKILL.md".to_string(), hash.to_string()))
+        .into_iter()
+        .collect();
+    let verified =
+        verify_file_hashes(&[("SKILL.md".to_string(), skill_content.to_string())], &expected)?.is_empty();
 
     // Create a safe folder name from skill name
-    let folder_name = build_folder_name(skill_name);
+    let folder_name = resolve_folder_name(skill_name, Some(skill_content), folder_name)?;
 
+    let mut results = Vec::new();
     for tool_id in tool_ids {
-        let tool = SUPPORTED_TOOLS
-            .iter()
-            .find(|t| t.id == tool_id)
-            .ok_or_else(|| format!("Unknown tool: {}", tool_id))?;
+        let result =
+            install_skill_md_to_tool(&home, tools, tool_id, &folder_name, skill_content).await;
+        results.push(InstallResult {
+            verified,
+            ..InstallResult::from_result(tool_id, result)
+        });
+    }
 
-        // Use the primary subpath for installation
-        let skills_dir = if tool.primary_subpath == "." {
-            home.join(tool.config_paths[0])
-        } else {
-            home.join(tool.config_paths[0]).join(tool.primary_subpath)
-        };
+    Ok(results)
+}
 
-        // Create skills directory if it doesn't exist
-        if !skills_dir.exists() {
-            fs::create_dir_all(&skills_dir)
-                .await
-                .map_err(|e| format!("Failed to create skills directory: {}", e))?;
+/// Write SKILL.md into `<tool skills dir>/<folder_name>/`, returning the file path
+async fn install_skill_md_to_tool(
+    home: &std::path::Path,
+    tools: &[&'static ToolConfig],
+    tool_id: &str,
+    folder_name: &str,
+    skill_content: &str,
+) -> Result<String, String> {
+    let tool = find_tool_in(tools, tool_id)?;
+    check_personal_scope(tool)?;
+    check_config_dir(home, tool.config_paths[0])?;
+
+    // Use the primary subpath for installation
+    let skills_dir = skills_dir_in(tool, home);
+    crate::disk::ensure_writable(&skills_dir)?;
+
+    // Create skills directory if it doesn't exist
+    if !skills_dir.exists() {
+        fs::create_dir_all(&skills_dir)
+            .await
+            .map_err(|e| format!("Failed to create skills directory: {}", e))?;
+    }
+
+    let skill_dir = skills_dir.join(folder_name);
+    fs::create_dir_all(&skill_dir)
+        .await
+        .map_err(|e| format!("Failed to create skill directory: {}", e))?;
+
+    let skill_file = skill_dir.join("SKILL.md");
+    fs::write(&skill_file, skill_content)
+        .await
+        .map_err(|e| format!("Failed to write skill file: {}", e))?;
+
+    Ok(skill_file.to_string_lossy().to_string())
+}
+
+/// Result of linking a shared skill folder into one tool
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LinkedInstallResult {
+    pub tool_id: String,
+    pub path: String,
+    pub linked: bool, // false when the folder had to be copied instead
+    pub created: bool, // false when the tool already had this link
+    pub warning: Option<String>,
+}
+
+#[cfg(unix)]
+fn create_dir_link(target: &std::path::Path, link: &std::path::Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(windows)]
+fn create_dir_link(target: &std::path::Path, link: &std::path::Path) -> std::io::Result<()> {
+    // Symlinks need Developer Mode or admin rights; junctions work for any user
+    std::os::windows::fs::symlink_dir(target, link).or_else(|_| junction::create(target, link))
+}
+
+#[cfg(unix)]
+fn remove_dir_link(link: &std::path::Path) -> std::io::Result<()> {
+    std::fs::remove_file(link)
+}
+
+#[cfg(windows)]
+fn remove_dir_link(link: &std::path::Path) -> std::io::Result<()> {
+    // Directory symlinks and junctions are removed as directories, which leaves the target alone
+    std::fs::remove_dir(link)
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ToolResetReport {
+    pub tool_id: String,
+    pub removed: Vec<String>,
+    pub backup_path: Option<String>, // snapshot of the skills taken before they were removed
+}
+
+/// Undo what SkillHub set up for one tool. With `remove_skills` the personal skills
+/// directory is snapshotted in full (hidden files and backups too) and then removed
+/// (a relocated directory only loses its link); for Claude Code the API configuration
+/// the app wrote is removed too.
+/// The tool's own settings are never touched.
+pub async fn reset_tool(tool_id: &str, remove_skills: bool) -> Result<ToolResetReport, String> {
+    let tool = find_tool(tool_id)?;
+    let mut report = ToolResetReport {
+        tool_id: tool_id.to_string(),
+        ..Default::default()
+    };
+
+    if remove_skills {
+        // Here the skills directory would be the tool's whole config directory
+        if tool.primary_subpath == "." {
+            return Err(format!(
+                "{} keeps its skills in its config directory, remove skills individually instead",
+                tool.name
+            ));
+        }
+        let skills_dir = get_tool_skills_dir(tool_id)?;
+        if fs::symlink_metadata(&skills_dir).await.is_ok() {
+            let snapshot =
+                crate::snapshot::snapshot_skills_dir_full(tool_id, &format!("reset-{}", tool_id)).await?;
+            report.backup_path = Some(snapshot.path);
+
+            if is_link(&skills_dir).await {
+                remove_dir_link(&skills_dir)
+                    .map_err(|e| format!("Failed to remove {}: {}", skills_dir.display(), e))?;
+                report
+                    .removed
+                    .push(format!("{} (link only, its target was kept)", skills_dir.display()));
+            } else {
+                fs::remove_dir_all(&skills_dir)
+                    .await
+                    .map_err(|e| format!("Failed to remove {}: {}", skills_dir.display(), e))?;
+                report.removed.push(skills_dir.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    if tool_id == "claude" {
+        report
+            .removed
+            .extend(crate::installer::reset_claude_code_config().await?);
+    }
+
+    tracing::info!("Reset {}: removed {:?}", tool_id, report.removed);
+    Ok(report)
+}
+
+/// Install a skill into each tool as a link to one canonical folder
+/// (e.g. ~/.skillhub/skills/<slug>), so edits show up in every tool at once.
+/// Falls back to copying when the link can't be created.
+pub async fn install_skill_symlinked(
+    canonical_path: &str,
+    tool_ids: &[String],
+) -> Result<Vec<LinkedInstallResult>, String> {
+    let home = crate::home::home_dir()?;
+    let canonical = PathBuf::from(resolve_path(canonical_path)?);
+
+    if !canonical.join("SKILL.md").is_file() {
+        return Err(format!("No SKILL.md found in {}", canonical_path));
+    }
+    let slug = sync::checked_file_name(&canonical)
+        .ok_or_else(|| format!("Invalid skill folder name: {}", canonical_path))?;
+
+    let tools = all_tools();
+    let mut results = Vec::new();
+    for tool_id in tool_ids {
+        match link_skill_into_tool(&home, &canonical, &slug, &tools, tool_id).await {
+            Ok(result) => results.push(result),
+            Err(e) => {
+                // Undo the links and copies made by this call; links that were already there stay
+                for result in results.iter().rev().filter(|r| r.created) {
+                    let created = std::path::Path::new(&result.path);
+                    let removed = if result.linked {
+                        remove_dir_link(created)
+                    } else {
+                        std::fs::remove_dir_all(created)
+                    };
+                    if let Err(err) = removed {
+                        tracing::warn!("Failed to roll back {}: {}", created.display(), err);
+                    }
+                }
+                return Err(e);
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// Link the canonical skill folder into one tool's personal skills directory
+async fn link_skill_into_tool(
+    home: &std::path::Path,
+    canonical: &PathBuf,
+    slug: &str,
+    tools: &[&'static ToolConfig],
+    tool_id: &str,
+) -> Result<LinkedInstallResult, String> {
+    let tool = find_tool_in(tools, tool_id)?;
+    check_personal_scope(tool)?;
+    check_config_dir(home, tool.config_paths[0])?;
+
+    let skills_dir = skills_dir_in(tool, home);
+    crate::disk::ensure_writable(&skills_dir)?;
+    fs::create_dir_all(&skills_dir)
+        .await
+        .map_err(|e| format!("Failed to create skills directory: {}", e))?;
+
+    let link_path = skills_dir.join(slug);
+    if fs::symlink_metadata(&link_path).await.is_ok() {
+        // Re-linking to the same folder is a no-op; anything else would clobber a skill
+        let same_target = dunce::canonicalize(&link_path)
+            .map(|p| &p == canonical)
+            .unwrap_or(false);
+        if is_link(&link_path).await && same_target {
+            return Ok(LinkedInstallResult {
+                tool_id: tool_id.to_string(),
+                path: link_path.to_string_lossy().to_string(),
+                linked: true,
+                created: false,
+                warning: None,
+            });
         }
+        return Err(format!(
+            "Skill already exists for {}: {}",
+            tool.name,
+            link_path.display()
+        ));
+    }
+
+    match create_dir_link(canonical, &link_path) {
+        Ok(()) => Ok(LinkedInstallResult {
+            tool_id: tool_id.to_string(),
+            path: link_path.to_string_lossy().to_string(),
+            linked: true,
+            created: true,
+            warning: None,
+        }),
+        Err(e) => {
+            tracing::warn!("Failed to link {} into {}: {}", slug, tool.name, e);
+            if let Err(copy_err) = copy_dir_recursive(canonical, &link_path).await {
+                // Don't leave a partial copy behind
+                let _ = fs::remove_dir_all(&link_path).await;
+                return Err(copy_err);
+            }
+            Ok(LinkedInstallResult {
+                tool_id: tool_id.to_string(),
+                path: link_path.to_string_lossy().to_string(),
+                linked: false,
+                created: true,
+                warning: Some(format!(
+                    "Could not create a link ({}), installed a copy instead",
+                    e
+                )),
+            })
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ConsolidationReport {
+    pub canonical_path: String,
+    pub linked: Vec<String>,    // copies replaced by a link to the canonical folder
+    pub copied: Vec<String>,    // links weren't possible, so re-copied from the canonical folder
+    pub differing: Vec<String>, // copies with other content, left alone
+    pub failed: Vec<String>,    // "<path>: <error>" for copies that couldn't be checked or replaced
+    pub bytes_saved: u64,
+}
+
+/// Replace identical copies of a skill in other tools with links to the copy in
+/// `keep_tool_id`, so it is stored once. Copies are compared file for file, hidden files
+/// included, so one that differs in anything is left alone. A failure with one tool's copy
+/// is reported and the others are still processed.
+pub async fn consolidate_duplicate_skill(
+    slug: &str,
+    keep_tool_id: &str,
+) -> Result<ConsolidationReport, String> {
+    if slug.is_empty() || slug.starts_with('.') || slug.contains(['/', '\\']) {
+        return Err(format!("Invalid skill folder name: {}", slug));
+    }
+    let home = crate::home::home_dir()?;
+    let keep_dir = get_tool_skills_dir(keep_tool_id)?.join(slug);
+    if !keep_dir.is_dir() {
+        return Err(format!("Skill {} is not installed as a folder in {}", slug, keep_tool_id));
+    }
+    // The kept copy may itself be a link; point the others at the real folder
+    let canonical = dunce::canonicalize(&keep_dir)
+        .map_err(|e| format!("Failed to resolve {}: {}", keep_dir.display(), e))?;
+    let canonical_files = crate::library::read_all_files(&canonical).await?;
+
+    let mut report = ConsolidationReport {
+        canonical_path: canonical.to_string_lossy().to_string(),
+        ..Default::default()
+    };
+    let mut seen = vec![keep_dir.clone()];
+    for tool in enabled_tool_configs() {
+        if tool.id == keep_tool_id || check_personal_scope(tool).is_err() {
+            continue;
+        }
+        // Tools sharing a skills directory share the copy too
+        let path = skills_dir_in(tool, &home).join(slug);
+        if seen.contains(&path) || !path.exists() {
+            continue;
+        }
+        seen.push(path.clone());
+        if dunce::canonicalize(&path).is_ok_and(|p| p == canonical) {
+            continue;
+        }
+
+        if !path.is_dir() {
+            report.differing.push(path.to_string_lossy().to_string());
+            continue;
+        }
+        match crate::library::read_all_files(&path).await {
+            Ok(files) if files == canonical_files => {}
+            Ok(_) => {
+                report.differing.push(path.to_string_lossy().to_string());
+                continue;
+            }
+            Err(e) => {
+                report.failed.push(format!("{}: {}", path.display(), e));
+                continue;
+            }
+        }
+
+        let size = crate::skill_backups::disk_usage(path.clone()).await;
+        match replace_with_link(&canonical, &path, slug).await {
+            Ok(true) => {
+                report.bytes_saved += size;
+                report.linked.push(path.to_string_lossy().to_string());
+            }
+            Ok(false) => report.copied.push(path.to_string_lossy().to_string()),
+            Err(e) => report.failed.push(format!("{}: {}", path.display(), e)),
+        }
+    }
 
-        let skill_dir = skills_dir.join(&folder_name);
-        fs::create_dir_all(&skill_dir)
+    tracing::info!(
+        "Consolidated {}: {} linked, {} copied, {} differing, {} failed, {} bytes saved",
+        slug,
+        report.linked.len(),
+        report.copied.len(),
+        report.differing.len(),
+        report.failed.len(),
+        report.bytes_saved
+    );
+    Ok(report)
+}
+
+/// Swap a skill folder for a link to `canonical`, or a fresh copy of it where links aren't
+/// possible. Returns whether a link was created. The old folder is kept aside until the
+/// replacement exists, so a failure leaves the skill in place.
+async fn replace_with_link(canonical: &PathBuf, path: &PathBuf, slug: &str) -> Result<bool, String> {
+    let aside = path.with_file_name(format!(".skillhub-previous-{}", slug));
+    if aside.exists() {
+        fs::remove_dir_all(&aside)
+            .await
+            .map_err(|e| format!("Failed to remove {}: {}", aside.display(), e))?;
+    }
+    if is_link(path).await {
+        remove_dir_link(path).map_err(|e| format!("Failed to remove link {}: {}", path.display(), e))?;
+    } else {
+        fs::rename(path, &aside)
             .await
-            .map_err(|e| format!("Failed to create skill directory: {}", e))?;
+            .map_err(|e| format!("Failed to move {}: {}", path.display(), e))?;
+    }
 
-        let skill_file = skill_dir.join("SKILL.md");
-        fs::write(&skill_file, skill_content)
+    let linked = match create_dir_link(canonical, path) {
+        Ok(()) => true,
+        Err(e) => {
+            tracing::warn!("Failed to link {} to {}: {}", path.display(), canonical.display(), e);
+            if let Err(e) = copy_dir_recursive(canonical, path).await {
+                let _ = fs::remove_dir_all(path).await;
+                if aside.exists() {
+                    let _ = fs::rename(&aside, path).await;
+                }
+                return Err(e);
+            }
+            false
+        }
+    };
+    if aside.exists() {
+        fs::remove_dir_all(&aside)
             .await
-            .map_err(|e| format!("Failed to write skill file: {}", e))?;
+            .map_err(|e| format!("Failed to remove {}: {}", aside.display(), e))?;
+    }
+    Ok(linked)
+}
 
-        installed_paths.push(skill_file.to_string_lossy().to_string());
+/// Move a tool's personal skills directory to `new_dir`, leaving a link (a junction on
+/// Windows) at the standard path so the tool keeps reading its skills from there.
+/// With `move_existing` the installed skills are moved along; otherwise the current
+/// directory must be empty. Returns the path the skills directory now resolves to.
+pub async fn relocate_tool_skills(
+    tool_id: &str,
+    new_dir: &str,
+    move_existing: bool,
+) -> Result<String, String> {
+    let tool = find_tool(tool_id)?;
+    let skills_dir = get_tool_skills_dir(tool_id)?;
+
+    let new_dir = PathBuf::from(new_dir.trim());
+    if !new_dir.is_absolute() {
+        return Err(format!("New skills directory must be an absolute path: {}", new_dir.display()));
     }
+    fs::create_dir_all(&new_dir)
+        .await
+        .map_err(|e| format!("Failed to create {}: {}", new_dir.display(), e))?;
+    crate::disk::ensure_writable(&new_dir)?;
+    let target = dunce::canonicalize(&new_dir)
+        .map_err(|e| format!("Failed to resolve {}: {}", new_dir.display(), e))?;
+
+    // Where the skills currently live (an earlier relocation is followed through its link)
+    let current = if skills_dir.exists() {
+        Some(
+            dunce::canonicalize(&skills_dir)
+                .map_err(|e| format!("Failed to resolve {}: {}", skills_dir.display(), e))?,
+        )
+    } else {
+        None
+    };
+
+    if let Some(current) = &current {
+        if *current == target {
+            return Ok(target.to_string_lossy().to_string());
+        }
+        if target.starts_with(current) {
+            return Err(format!(
+                "New skills directory can't be inside the current one: {}",
+                current.display()
+            ));
+        }
+        // Moving the skills into a folder that contains them would move them into themselves
+        if current.starts_with(&target) || skills_dir.starts_with(&target) {
+            return Err(format!(
+                "New skills directory can't contain the current one: {}",
+                current.display()
+            ));
+        }
 
-    Ok(installed_paths)
+        let mut names = Vec::new();
+        let mut entries = fs::read_dir(current)
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", current.display(), e))?;
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            let name = sync::checked_file_name(&path)
+                .ok_or_else(|| format!("Invalid file name in {}", current.display()))?;
+            names.push(name);
+        }
+
+        if !names.is_empty() && !move_existing {
+            return Err(format!(
+                "{} is not empty ({} entries); move the existing skills along or empty it first",
+                current.display(),
+                names.len()
+            ));
+        }
+        // Check for clashes first so a conflict doesn't leave the skills half moved
+        if let Some(name) = names.iter().find(|name| target.join(name).exists()) {
+            return Err(format!(
+                "Cannot move {}: {} already exists",
+                name,
+                target.join(name).display()
+            ));
+        }
+        for name in &names {
+            move_entry(&current.join(name), &target.join(name)).await?;
+        }
+    }
+
+    // Replace the old directory (or link) at the standard path with a link to the new one
+    if is_link(&skills_dir).await {
+        remove_dir_link(&skills_dir)
+            .map_err(|e| format!("Failed to remove link {}: {}", skills_dir.display(), e))?;
+    } else if skills_dir.exists() {
+        fs::remove_dir(&skills_dir)
+            .await
+            .map_err(|e| format!("Failed to remove {}: {}", skills_dir.display(), e))?;
+    } else if let Some(parent) = skills_dir.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create directory: {}", e))?;
+    }
+    create_dir_link(&target, &skills_dir)
+        .map_err(|e| format!("Failed to link {} to {}: {}", skills_dir.display(), target.display(), e))?;
+
+    // Make sure the tool sees the same skills through the link as in the new directory
+    let resolved = dunce::canonicalize(&skills_dir)
+        .map_err(|e| format!("Failed to resolve {}: {}", skills_dir.display(), e))?;
+    if resolved != target {
+        return Err(format!(
+            "{} resolves to {} instead of {}",
+            skills_dir.display(),
+            resolved.display(),
+            target.display()
+        ));
+    }
+    let through_link = count_skills_in_dir(&skills_dir, tool.skill_extensions).await;
+    let in_target = count_skills_in_dir(&target, tool.skill_extensions).await;
+    if through_link != in_target {
+        return Err(format!(
+            "{} shows {} skills through the link, expected {}",
+            tool.name, through_link, in_target
+        ));
+    }
+
+    tracing::info!(
+        "Relocated {} skills directory to {} ({} skills)",
+        tool.name,
+        target.display(),
+        in_target
+    );
+    Ok(resolved.to_string_lossy().to_string())
+}
+
+/// Older personal skills locations a tool still reads, relative to the home directory
+fn legacy_skills_subpaths(tool_id: &str) -> &'static [&'static str] {
+    match tool_id {
+        "copilot" | "opencode" => &[".claude/skills"],
+        _ => &[],
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MigrationItem {
+    pub name: String,
+    pub from: String,
+    pub to: String,
+    pub conflict: bool, // a skill with the same name already exists at the destination
 }
 
-/// Install a skill to a specific project directory
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct MigrationPlan {
+    pub items: Vec<MigrationItem>,
+    pub keeps_originals: bool, // another tool also reads the legacy folder, so skills are copied
+    pub migrated: Vec<String>, // destination paths, filled in when applied
+}
+
+/// Find skills in a tool's legacy locations (e.g. ~/.claude/skills for Copilot) and plan
+/// moving them to its recommended skills directory; with `apply` the non-conflicting ones
+/// are moved. When any other tool also reads the legacy folder (Claude Code reads
+/// ~/.claude/skills, as does OpenCode's legacy lookup), skills are copied so that tool keeps
+/// them, whether or not it is enabled in SkillHub.
+pub async fn migrate_legacy_skills(tool_id: &str, apply: bool) -> Result<MigrationPlan, String> {
+    let home = crate::home::home_dir()?;
+    migrate_legacy_skills_in(&all_tools(), &home, tool_id, apply).await
+}
+
+/// `migrate_legacy_skills` for the given tools, with `home` as the home directory
+async fn migrate_legacy_skills_in(
+    tools: &[&'static ToolConfig],
+    home: &std::path::Path,
+    tool_id: &str,
+    apply: bool,
+) -> Result<MigrationPlan, String> {
+    let tool = find_tool_in(tools, tool_id)?;
+    check_personal_scope(tool)?;
+    let target_dir = skills_dir_in(tool, home);
+    let mut plan = MigrationPlan::default();
+
+    for subpath in legacy_skills_subpaths(tool_id) {
+        let legacy_dir = home.join(subpath);
+        if !legacy_dir.is_dir() {
+            continue;
+        }
+        if tools.iter().any(|t| {
+            t.id != tool_id
+                && (skills_dir_in(t, home) == legacy_dir
+                    || legacy_skills_subpaths(t.id).contains(subpath))
+        }) {
+            plan.keeps_originals = true;
+        }
+
+        let mut skills = Vec::new();
+        collect_skills_from_dir(&legacy_dir, tool_id, DEFAULT_SKILL_EXTENSIONS, &mut skills).await;
+        for skill in skills {
+            let from = PathBuf::from(&skill.path);
+            let Some(name) = sync::checked_file_name(&from) else {
+                continue;
+            };
+            let to = target_dir.join(&name);
+            plan.items.push(MigrationItem {
+                conflict: to.exists() || plan.items.iter().any(|i| i.name == name),
+                name,
+                from: skill.path,
+                to: to.to_string_lossy().to_string(),
+            });
+        }
+    }
+
+    if !apply {
+        return Ok(plan);
+    }
+
+    if plan.items.iter().any(|i| !i.conflict) {
+        fs::create_dir_all(&target_dir)
+            .await
+            .map_err(|e| format!("Failed to create directory: {}", e))?;
+        crate::disk::ensure_writable(&target_dir)?;
+    }
+    for item in plan.items.iter().filter(|i| !i.conflict) {
+        let (from, to) = (PathBuf::from(&item.from), PathBuf::from(&item.to));
+        if !plan.keeps_originals {
+            move_entry(&from, &to).await?;
+        } else if from.is_dir() {
+            copy_dir_recursive(&from, &to).await?;
+        } else {
+            fs::copy(&from, &to)
+                .await
+                .map_err(|e| format!("Failed to copy {}: {}", item.name, e))?;
+        }
+        plan.migrated.push(item.to.clone());
+    }
+
+    tracing::info!(
+        "Migrated {} legacy skills for {} ({} conflicts)",
+        plan.migrated.len(),
+        tool_id,
+        plan.items.iter().filter(|i| i.conflict).count()
+    );
+    Ok(plan)
+}
+
+/// Move a file or folder, copying it when a rename isn't possible (e.g. across drives)
+async fn move_entry(src: &PathBuf, dest: &PathBuf) -> Result<(), String> {
+    if fs::rename(src, dest).await.is_ok() {
+        return Ok(());
+    }
+
+    let is_dir = fs::symlink_metadata(src)
+        .await
+        .map(|m| m.is_dir())
+        .map_err(|e| format!("Failed to read {}: {}", src.display(), e))?;
+    if is_dir {
+        copy_dir_recursive(src, dest).await?;
+        fs::remove_dir_all(src).await
+    } else {
+        match fs::copy(src, dest).await {
+            Ok(_) => fs::remove_file(src).await,
+            Err(e) => Err(e),
+        }
+    }
+    .map_err(|e| format!("Failed to move {}: {}", src.display(), e))
+}
+
+/// Install a skill to a specific project directory, returning the SKILL.md path and whether
+/// it was checked against `expected_sha256` (nothing is written on a mismatch)
 pub async fn install_skill_to_project(
     skill_content: &str,
     skill_name: &str,
     project_path: &str,
     tool_id: &str,
-) -> Result<String, String> {
+    folder_name: Option<&str>,
+    expected_sha256: Option<&str>,
+) -> Result<(String, bool), String> {
     let project_dir = PathBuf::from(project_path);
     
     if !project_dir.exists() {
         return Err(format!("Project directory does not exist: {}", project_path));
     }
 
-    let tool = SUPPORTED_TOOLS
-        .iter()
-        .find(|t| t.id == tool_id)
-        .ok_or_else(|| format!("Unknown tool: {}", tool_id))?;
+    let expected: HashMap<String, String> = expected_sha256
+        .map(|hash| ("SKILL.md".to_string(), hash.to_string()))
+        .into_iter()
+        .collect();
+    let verified =
+        verify_file_hashes(&[("SKILL.md".to_string(), skill_content.to_string())], &expected)?.is_empty();
+
+    let tool = find_tool(tool_id)?;
+    check_project_scope(tool)?;
 
     // Create a safe folder name from skill name
-    let folder_name = build_folder_name(skill_name);
+    let folder_name = resolve_folder_name(skill_name, Some(skill_content), folder_name)?;
 
     // Build the project skills directory path
     // e.g., /path/to/project/.claude/skills/skill-name/SKILL.md
-    let skills_dir = if tool.primary_subpath == "." {
-        project_dir.join(tool.config_paths[0])
-    } else {
-        project_dir.join(tool.config_paths[0]).join(tool.primary_subpath)
-    };
+    let skills_dir = skills_dir_in(tool, &project_dir);
+    crate::disk::ensure_writable(&skills_dir)?;
 
     // Create skills directory if it doesn't exist
     if !skills_dir.exists() {
@@ -528,34 +2328,35 @@ pub async fn install_skill_to_project(
         .await
         .map_err(|e| format!("Failed to write skill file: {}", e))?;
 
-    Ok(skill_file.to_string_lossy().to_string())
+    Ok((skill_file.to_string_lossy().to_string(), verified))
 }
 
+/// Install a skill's files to a specific project directory, returning the skill folder and
+/// the files that had no hash in `expected_hashes` (nothing is written on a mismatch)
 pub async fn install_skill_files_to_project(
     files: &[(String, String)],
     skill_name: &str,
     project_path: &str,
     tool_id: &str,
-) -> Result<String, String> {
+    folder_name: Option<&str>,
+    expected_hashes: &HashMap<String, String>,
+) -> Result<(String, Vec<String>), String> {
     let project_dir = PathBuf::from(project_path);
 
     if !project_dir.exists() {
         return Err(format!("Project directory does not exist: {}", project_path));
     }
 
-    let tool = SUPPORTED_TOOLS
-        .iter()
-        .find(|t| t.id == tool_id)
-        .ok_or_else(|| format!("Unknown tool: {}", tool_id))?;
+    let unverified_files = verify_file_hashes(files, expected_hashes)?;
 
-    let folder_name = build_folder_name(skill_name);
+    let tool = find_tool(tool_id)?;
+    check_project_scope(tool)?;
+
+    let folder_name = resolve_folder_name(skill_name, find_skill_md(files), folder_name)?;
 
     // Build the project skills directory path
-    let skills_dir = if tool.primary_subpath == "." {
-        project_dir.join(tool.config_paths[0])
-    } else {
-        project_dir.join(tool.config_paths[0]).join(tool.primary_subpath)
-    };
+    let skills_dir = skills_dir_in(tool, &project_dir);
+    crate::disk::ensure_writable(&skills_dir)?;
 
     // Create skills directory if it doesn't exist
     if !skills_dir.exists() {
@@ -570,24 +2371,23 @@ pub async fn install_skill_files_to_project(
         .map_err(|e| format!("Failed to create skill directory: {}", e))?;
 
     // Install each file
-    for (relative_path, content) in files {
-        let file_path = skill_dir.join(relative_path);
+    write_skill_files(&skill_dir, files).await?;
 
-        // Create parent directories if needed
-        if let Some(parent) = file_path.parent() {
-            if !parent.exists() {
-                fs::create_dir_all(parent)
-                    .await
-                    .map_err(|e| format!("Failed to create directory: {}", e))?;
-            }
-        }
-
-        fs::write(&file_path, content)
-            .await
-            .map_err(|e| format!("Failed to write file {}: {}", relative_path, e))?;
-    }
+    Ok((skill_dir.to_string_lossy().to_string(), unverified_files))
+}
 
-    Ok(skill_dir.to_string_lossy().to_string())
+/// Whether a folder is a tool's skills directory, personal or inside any project. It holds
+/// every skill of the tool, so it is never removed along with one of them.
+fn is_skills_root(dir: &std::path::Path) -> bool {
+    let home = crate::home::home_dir().ok();
+    all_tools().into_iter().any(|tool| {
+        home.as_ref().is_some_and(|home| skills_dir_in(tool, home) == dir)
+            || tool.config_paths.iter().any(|config| {
+                tool.all_subpaths
+                    .iter()
+                    .any(|subpath| dir.ends_with(std::path::Path::new(config).join(subpath)))
+            })
+    })
 }
 
 pub async fn uninstall_skill(skill_path: &str) -> Result<(), String> {
@@ -598,99 +2398,604 @@ pub async fn uninstall_skill(skill_path: &str) -> Result<(), String> {
             .await
             .map_err(|e| format!("Failed to remove skill directory: {}", e))?;
     } else if path.is_file() {
-        // If it's a file, remove the parent directory if it only contains this file
         let parent = path.parent().ok_or("Invalid path")?;
         fs::remove_file(&path)
             .await
             .map_err(|e| format!("Failed to remove skill file: {}", e))?;
 
-        // Try to remove parent if empty
-        let _ = fs::remove_dir(parent).await;
+        // A SKILL.md path stands for its skill folder, which goes too if nothing else is in it.
+        // Any other file is a loose skill (e.g. skills/my-skill.md) whose parent is the shared
+        // skills directory, which must stay even when it ends up empty.
+        let is_skill_md = sync::checked_file_name(&path)
+            .is_some_and(|name| name.eq_ignore_ascii_case("SKILL.md"));
+        if is_skill_md && !is_skills_root(parent) {
+            let _ = fs::remove_dir(parent).await;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write a skill's files into a hidden staging folder next to the destination, then rename it
+/// into place so a failed write never leaves a half-installed skill. On reinstall the staging
+/// folder starts as a copy of the existing skill, so files the user added there are kept and
+/// only the incoming files are overwritten; the old folder is restored if the final rename fails.
+async fn install_files_atomically(
+    skills_dir: &std::path::Path,
+    folder_name: &str,
+    files: &[(String, String)],
+) -> Result<PathBuf, String> {
+    let skill_dir = skills_dir.join(folder_name);
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let staging_dir = skills_dir.join(format!(".skillhub-staging-{}-{}", folder_name, stamp));
+    let previous_dir = skills_dir.join(format!(".skillhub-previous-{}-{}", folder_name, stamp));
+
+    if skill_dir.is_dir() {
+        if let Err(e) = copy_dir_recursive(&skill_dir, &staging_dir).await {
+            let _ = fs::remove_dir_all(&staging_dir).await;
+            return Err(format!("Failed to stage existing skill: {}", e));
+        }
+    }
+    if let Err(e) = write_skill_files(&staging_dir, files).await {
+        let _ = fs::remove_dir_all(&staging_dir).await;
+        return Err(e);
+    }
+
+    // Move any prior version aside so it can be restored if the swap fails
+    let had_previous = skill_dir.exists();
+    if had_previous {
+        if let Err(e) = fs::rename(&skill_dir, &previous_dir).await {
+            let _ = fs::remove_dir_all(&staging_dir).await;
+            return Err(format!("Failed to replace existing skill: {}", e));
+        }
+    }
+
+    if let Err(e) = fs::rename(&staging_dir, &skill_dir).await {
+        let _ = fs::remove_dir_all(&staging_dir).await;
+        if had_previous {
+            let _ = fs::rename(&previous_dir, &skill_dir).await;
+        }
+        return Err(format!("Failed to move skill into place: {}", e));
+    }
+
+    if had_previous {
+        let _ = fs::remove_dir_all(&previous_dir).await;
+    }
+
+    Ok(skill_dir)
+}
+
+// Classic Windows MAX_PATH; longer paths fail unless long paths are enabled system-wide
+#[cfg(windows)]
+const WINDOWS_MAX_PATH: usize = 260;
+
+/// On Windows, give paths over MAX_PATH the `\\?\` extended-length prefix so deep skill
+/// trees under a long home directory can still be written. Paths that can't be prefixed
+/// (relative ones) get an actionable error instead of the OS's cryptic one.
+/// Other platforms return the path unchanged.
+pub(crate) fn long_path_safe(path: PathBuf) -> Result<PathBuf, String> {
+    #[cfg(windows)]
+    {
+        let display = path.to_string_lossy().replace('/', "\\");
+        if display.chars().count() < WINDOWS_MAX_PATH || display.starts_with(r"\\?\") {
+            return Ok(path);
+        }
+
+        // Extended-length paths skip normalization, so they must be absolute with backslashes
+        if let Some(unc) = display.strip_prefix(r"\\") {
+            return Ok(PathBuf::from(format!(r"\\?\UNC\{}", unc)));
+        }
+        if path.is_absolute() {
+            return Ok(PathBuf::from(format!(r"\\?\{}", display)));
+        }
+        Err(format!(
+            "Path is too long for Windows ({} characters, limit {}): {}. \
+             Enable long paths in Windows or install to a shorter location.",
+            display.chars().count(),
+            WINDOWS_MAX_PATH,
+            display
+        ))
+    }
+
+    #[cfg(not(windows))]
+    Ok(path)
+}
+
+/// Write files (relative_path, content) under dir, creating parent directories as needed
+async fn write_skill_files(dir: &std::path::Path, files: &[(String, String)]) -> Result<(), String> {
+    fs::create_dir_all(dir)
+        .await
+        .map_err(|e| format!("Failed to create skill directory: {}", e))?;
+
+    for (relative_path, content) in files {
+        let file_path = long_path_safe(dir.join(sync::from_portable_path(relative_path)?))?;
+
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+
+        fs::write(&file_path, content)
+            .await
+            .map_err(|e| format!("Failed to write file {}: {}", relative_path, e))?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UninstallResult {
+    pub name: String,
+    pub path: String,
+    pub tool_id: String,
+    pub error: Option<String>,
+}
+
+/// Uninstall every skill whose frontmatter author matches (case-insensitive) from the given tools
+pub async fn uninstall_skills_by_author(
+    author: &str,
+    tool_ids: &[String],
+) -> Result<Vec<UninstallResult>, String> {
+    let author = author.trim().to_lowercase();
+    if author.is_empty() {
+        return Err("Author cannot be empty".to_string());
+    }
+
+    let home = crate::home::home_dir()?;
+    let tools = all_tools();
+    let selected = tool_ids
+        .iter()
+        .map(|tool_id| find_tool_in(&tools, tool_id))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let dirs = unique_skill_dirs(selected, |tool| tool_skill_dirs(tool, &home));
+    let skills = collect_skills_from_dirs(dirs).await;
+    let mut results: Vec<UninstallResult> = Vec::new();
+
+    for skill in skills {
+        let matches = skill
+            .author
+            .as_deref()
+            .map(|a| a.trim().to_lowercase() == author)
+            .unwrap_or(false);
+        if !matches {
+            continue;
+        }
+
+        let error = uninstall_skill(&skill.path).await.err();
+        results.push(UninstallResult {
+            name: skill.name,
+            path: skill.path,
+            tool_id: skill.tool_id,
+            error,
+        });
+    }
+
+    Ok(results)
+}
+
+/// Install multiple files for a skill (supports multi-file skills)
+/// files: Vec<(relative_path, content)>
+/// Files are checked against `expected_hashes` (path -> SHA-256) before anything is written;
+/// files without a hash are installed and listed as unverified.
+pub async fn install_skill_files_to_tools(
+    files: &[(String, String)],
+    skill_name: &str,
+    tool_ids: &[String],
+    folder_name: Option<&str>,
+    expected_hashes: &HashMap<String, String>,
+) -> Result<VerifiedInstallResult, String> {
+    let tools = all_tools();
+    install_skill_files_with_tools(
+        &tools,
+        files,
+        skill_name,
+        tool_ids,
+        folder_name,
+        expected_hashes,
+    )
+    .await
+}
+
+/// `install_skill_files_to_tools` with the tool list already loaded
+async fn install_skill_files_with_tools(
+    tools: &[&'static ToolConfig],
+    files: &[(String, String)],
+    skill_name: &str,
+    tool_ids: &[String],
+    folder_name: Option<&str>,
+    expected_hashes: &HashMap<String, String>,
+) -> Result<VerifiedInstallResult, String> {
+    let home = crate::home::home_dir()?;
+    let mut installed_paths = Vec::new();
+    let unverified_files = verify_file_hashes(files, expected_hashes)?;
+
+    // Create a safe folder name from skill name
+    let folder_name = resolve_folder_name(skill_name, find_skill_md(files), folder_name)?;
+
+    // Check every destination before writing to any, so a failure can't leave some tools
+    // installed and others not
+    let mut skills_dirs = Vec::new();
+    for tool_id in tool_ids {
+        let tool = find_tool_in(tools, tool_id)?;
+        check_personal_scope(tool)?;
+        check_config_dir(&home, tool.config_paths[0])?;
+
+        // Use the primary subpath for installation
+        let skills_dir = skills_dir_in(tool, &home);
+        crate::disk::ensure_writable(&skills_dir)?;
+        skills_dirs.push(skills_dir);
+    }
+    let required_bytes: u64 = files.iter().map(|(_, content)| content.len() as u64).sum();
+    crate::disk::ensure_free_space_all(&skills_dirs, required_bytes)?;
+
+    for skills_dir in skills_dirs {
+        // Create skills directory if it doesn't exist
+        if !skills_dir.exists() {
+            fs::create_dir_all(&skills_dir)
+                .await
+                .map_err(|e| format!("Failed to create skills directory: {}", e))?;
+        }
+
+        let skill_dir = install_files_atomically(&skills_dir, &folder_name, files).await?;
+        installed_paths.push(skill_dir.to_string_lossy().to_string());
+    }
+
+    Ok(VerifiedInstallResult {
+        installed_paths,
+        verified: unverified_files.is_empty(),
+        unverified_files,
+    })
+}
+
+/// One skill in a batch install: either its SKILL.md content or its full file set
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BatchSkill {
+    pub name: String,
+    pub content: Option<String>,
+    pub files: Option<Vec<(String, String)>>,
+    #[serde(default)]
+    pub hashes: HashMap<String, String>, // path -> SHA-256 reported by the API
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BatchInstallResult {
+    pub name: String,
+    pub installed_paths: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BatchInstallProgress {
+    pub completed: usize,
+    pub total: usize,
+    pub name: String,
+    pub success: bool,
+}
+
+/// Install a multi-file skill into any existing directory, without tool path resolution.
+/// `on_conflict` decides what happens if the skill folder already exists there:
+/// error, skip (leave it), overwrite (replace it) or merge (write over matching files only).
+pub async fn install_skill_to_dir(
+    files: &[(String, String)],
+    skill_name: &str,
+    dest_dir: &str,
+    folder_name: Option<&str>,
+    on_conflict: ConflictPolicy,
+) -> Result<String, String> {
+    let dest = PathBuf::from(resolve_path(dest_dir)?);
+    let folder_name = resolve_folder_name(skill_name, find_skill_md(files), folder_name)?;
+    let skill_dir = dest.join(&folder_name);
+
+    crate::disk::ensure_writable(&dest)?;
+    let required_bytes: u64 = files.iter().map(|(_, content)| content.len() as u64).sum();
+    crate::disk::ensure_free_space(&dest, required_bytes)?;
+
+    if skill_dir.exists() {
+        match on_conflict {
+            ConflictPolicy::Error => {
+                return Err(format!("Skill '{}' already exists in {}", folder_name, dest_dir));
+            }
+            ConflictPolicy::Skip => return Ok(skill_dir.to_string_lossy().to_string()),
+            ConflictPolicy::Merge => {
+                write_skill_files(&skill_dir, files).await?;
+                return Ok(skill_dir.to_string_lossy().to_string());
+            }
+            ConflictPolicy::Overwrite => {}
+        }
+    }
+
+    let skill_dir = install_files_atomically(&dest, &folder_name, files).await?;
+    Ok(skill_dir.to_string_lossy().to_string())
+}
+
+/// Collapse per-tool results into the installed paths, or the combined errors if any tool failed
+fn successful_paths(results: Vec<InstallResult>) -> Result<Vec<String>, String> {
+    let errors: Vec<String> = results
+        .iter()
+        .filter_map(|r| Some(format!("{}: {}", r.tool_id, r.error.as_ref()?)))
+        .collect();
+    if !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+    Ok(results.into_iter().filter_map(|r| r.path).collect())
+}
+
+/// Install several skills to the given tools, continuing past individual failures.
+/// on_progress is called after each skill.
+pub async fn install_skills_batch(
+    skills: &[BatchSkill],
+    tool_ids: &[String],
+    on_progress: impl Fn(BatchInstallProgress),
+) -> Vec<BatchInstallResult> {
+    let mut results = Vec::new();
+    let tools = all_tools();
+
+    for (index, skill) in skills.iter().enumerate() {
+        let installed = match (&skill.files, &skill.content) {
+            (Some(files), _) => install_skill_files_with_tools(
+                &tools,
+                files,
+                &skill.name,
+                tool_ids,
+                None,
+                &skill.hashes,
+            )
+            .await
+            .map(|result| result.installed_paths),
+            (None, Some(content)) => {
+                let expected = skill
+                    .hashes
+                    .iter()
+                    .find(|(path, _)| is_root_skill_md(path))
+                    .map(|(_, hash)| hash.as_str());
+                install_skill_with_tools(&tools, content, &skill.name, tool_ids, None, expected, None)
+                    .await
+                    .and_then(successful_paths)
+            }
+            (None, None) => Err("Skill has neither content nor files".to_string()),
+        };
+
+        on_progress(BatchInstallProgress {
+            completed: index + 1,
+            total: skills.len(),
+            name: skill.name.clone(),
+            success: installed.is_ok(),
+        });
+
+        results.push(match installed {
+            Ok(installed_paths) => BatchInstallResult {
+                name: skill.name.clone(),
+                installed_paths,
+                error: None,
+            },
+            Err(e) => {
+                tracing::warn!("Batch install of {} failed: {}", skill.name, e);
+                BatchInstallResult {
+                    name: skill.name.clone(),
+                    installed_paths: Vec::new(),
+                    error: Some(e),
+                }
+            }
+        });
     }
 
-    Ok(())
+    results
 }
 
-/// Install multiple files for a skill (supports multi-file skills)
-/// files: Vec<(relative_path, content)>
-pub async fn install_skill_files_to_tools(
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct VerifiedInstallResult {
+    pub installed_paths: Vec<String>,
+    pub verified: bool, // false if any file came without an expected hash
+    pub unverified_files: Vec<String>,
+}
+
+/// Key a skill file path for hash lookups: portable separators, SKILL.md in any case
+fn hash_key(path: &str) -> String {
+    // An unsafe path can't match an installed file, so it's compared as given
+    let path = sync::normalize_portable_path(path).unwrap_or_else(|_| path.to_string());
+    if path.eq_ignore_ascii_case("SKILL.md") {
+        "SKILL.md".to_string()
+    } else {
+        path
+    }
+}
+
+/// Check skill files against the SHA-256 hashes the API reported for them (keyed by path,
+/// SKILL.md matched in any case). Fails on the first mismatch, before anything is written;
+/// returns the files that had no hash to check against.
+pub(crate) fn verify_file_hashes(
     files: &[(String, String)],
-    skill_name: &str,
-    tool_ids: &[String],
+    expected_hashes: &HashMap<String, String>,
 ) -> Result<Vec<String>, String> {
-    let home = get_home_dir().ok_or("Cannot find home directory")?;
-    let mut installed_paths = Vec::new();
+    let expected: HashMap<String, &str> = expected_hashes
+        .iter()
+        .filter(|(_, hash)| !hash.is_empty())
+        .map(|(path, hash)| (hash_key(path), hash.as_str()))
+        .collect();
+    let mut unverified = Vec::new();
+
+    for (path, content) in files {
+        match expected.get(&hash_key(path)) {
+            Some(expected) => {
+                let actual = sync::hash_content(content.as_bytes());
+                if !actual.eq_ignore_ascii_case(expected) {
+                    return Err(format!(
+                        "Integrity check failed for {}: expected {}, got {}",
+                        path, expected, actual
+                    ));
+                }
+            }
+            None => unverified.push(path.clone()),
+        }
+    }
 
-    // Create a safe folder name from skill name
-    let folder_name = build_folder_name(skill_name);
+    Ok(unverified)
+}
 
-    for tool_id in tool_ids {
-        let tool = SUPPORTED_TOOLS
-            .iter()
-            .find(|t| t.id == tool_id)
-            .ok_or_else(|| format!("Unknown tool: {}", tool_id))?;
+/// How many folder levels below a skill path to look for SKILL.md
+const SKILL_MD_SEARCH_DEPTH: usize = 2;
 
-        // Use the primary subpath for installation
-        let skills_dir = if tool.primary_subpath == "." {
-            home.join(tool.config_paths[0])
-        } else {
-            home.join(tool.config_paths[0]).join(tool.primary_subpath)
-        };
+/// Find the SKILL.md closest to dir, searching breadth-first up to max_depth levels down.
+/// Folders are visited in name order so the pick is deterministic.
+async fn find_nearest_skill_md(dir: &std::path::Path, max_depth: usize) -> Option<PathBuf> {
+    let mut level = vec![dir.to_path_buf()];
 
-        // Create skills directory if it doesn't exist
-        if !skills_dir.exists() {
-            fs::create_dir_all(&skills_dir)
-                .await
-                .map_err(|e| format!("Failed to create skills directory: {}", e))?;
-        }
+    for depth in 0..=max_depth {
+        let mut next_level = Vec::new();
 
-        let skill_dir = skills_dir.join(&folder_name);
-        fs::create_dir_all(&skill_dir)
-            .await
-            .map_err(|e| format!("Failed to create skill directory: {}", e))?;
-
-        // Install each file
-        for (relative_path, content) in files {
-            let file_path = skill_dir.join(relative_path);
-            
-            // Create parent directories if needed
-            if let Some(parent) = file_path.parent() {
-                if !parent.exists() {
-                    fs::create_dir_all(parent)
-                        .await
-                        .map_err(|e| format!("Failed to create directory: {}", e))?;
+        for current in &level {
+            let skill_file = current.join("SKILL.md");
+            if skill_file.is_file() {
+                return Some(skill_file);
+            }
+
+            if depth == max_depth {
+                continue;
+            }
+
+            let Ok(mut entries) = fs::read_dir(current).await else {
+                continue;
+            };
+            let mut subdirs = Vec::new();
+            while let Ok(Some(entry)) = entries.next_entry().await {
+                let entry_path = entry.path();
+                match sync::checked_file_name(&entry_path) {
+                    Some(name) if !name.starts_with('.') && name != "node_modules" => {}
+                    _ => continue,
+                }
+                if entry_path.is_dir() {
+                    subdirs.push(entry_path);
                 }
             }
-            
-            fs::write(&file_path, content)
-                .await
-                .map_err(|e| format!("Failed to write file {}: {}", relative_path, e))?;
+            subdirs.sort();
+            next_level.extend(subdirs);
         }
 
-        installed_paths.push(skill_dir.to_string_lossy().to_string());
+        level = next_level;
     }
 
-    Ok(installed_paths)
+    None
 }
 
 /// Read skill content from a path (for syncing between tools)
 pub async fn read_skill_content(skill_path: &str) -> Result<String, String> {
+    let skill_file = resolve_skill_file(skill_path).await?;
+    fs::read_to_string(&skill_file)
+        .await
+        .map_err(|e| format!("Failed to read skill file: {}", e))
+}
+
+/// Read just the frontmatter of a skill's SKILL.md (or the text up to its first heading
+/// when there is no frontmatter, or it has no name) and parse it, without loading the whole body
+pub async fn read_skill_metadata(skill_path: &str) -> Result<SkillMetadata, String> {
+    use tokio::io::AsyncBufReadExt;
+
+    let skill_file = resolve_skill_file(skill_path).await?;
+    let file = fs::File::open(&skill_file)
+        .await
+        .map_err(|e| format!("Failed to read skill file: {}", e))?;
+    let mut lines = tokio::io::BufReader::new(file).lines();
+
+    let mut head = String::new();
+    let mut in_frontmatter = false;
+    let mut first_line = true;
+    while let Some(line) = lines
+        .next_line()
+        .await
+        .map_err(|e| format!("Failed to read skill file: {}", e))?
+    {
+        let trimmed = line.trim();
+        head.push_str(&line);
+        head.push('\n');
+
+        if first_line && trimmed == "---" {
+            in_frontmatter = true;
+        } else if in_frontmatter {
+            if trimmed == "---" {
+                // Without a name in the frontmatter, keep reading for the first heading
+                if extract_metadata(&head).name.is_some() {
+                    break;
+                }
+                in_frontmatter = false;
+            }
+        } else if trimmed.starts_with("# ") {
+            break;
+        }
+        first_line = false;
+    }
+
+    Ok(extract_metadata(&head))
+}
+
+// Rough characters-per-token ratio for English text and code
+const CHARS_PER_TOKEN: usize = 4;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FileSizeEstimate {
+    pub path: String,
+    pub chars: usize,
+    pub tokens: usize,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SkillSizeEstimate {
+    pub total_chars: usize,
+    pub total_tokens: usize,
+    pub files: Vec<FileSizeEstimate>, // text files only, largest first
+}
+
+/// Estimate how much context a skill takes up: characters and approximate tokens
+/// (chars / 4) of SKILL.md and every other text file in the skill
+pub async fn estimate_skill_size(skill_path: &str) -> Result<SkillSizeEstimate, String> {
+    let path = PathBuf::from(skill_path);
+    if !path.exists() {
+        return Err("Skill path does not exist".to_string());
+    }
+
+    let mut files: Vec<FileSizeEstimate> = crate::library::read_skill_files(&path)
+        .await?
+        .into_iter()
+        .filter_map(|(path, content)| {
+            // Binary files (images, archives) aren't loaded into context as text
+            let text = String::from_utf8(content).ok()?;
+            let chars = text.chars().count();
+            Some(FileSizeEstimate {
+                path,
+                chars,
+                tokens: chars.div_ceil(CHARS_PER_TOKEN),
+            })
+        })
+        .collect();
+    files.sort_by(|a, b| b.chars.cmp(&a.chars).then_with(|| a.path.cmp(&b.path)));
+
+    Ok(SkillSizeEstimate {
+        total_chars: files.iter().map(|f| f.chars).sum(),
+        total_tokens: files.iter().map(|f| f.tokens).sum(),
+        files,
+    })
+}
+
+/// Locate the SKILL.md for a skill path, which may be the file itself or its folder
+async fn resolve_skill_file(skill_path: &str) -> Result<PathBuf, String> {
     let path = PathBuf::from(skill_path);
 
     if path.is_dir() {
-        // Read SKILL.md from directory
-        let skill_file = path.join("SKILL.md");
-        if skill_file.exists() {
-            fs::read_to_string(&skill_file)
-                .await
-                .map_err(|e| format!("Failed to read skill file: {}", e))
-        } else {
-            Err("SKILL.md not found in directory".to_string())
-        }
-    } else if path.is_file() {
-        fs::read_to_string(&path)
+        // Read SKILL.md from directory, or the nearest one in a subfolder
+        find_nearest_skill_md(&path, SKILL_MD_SEARCH_DEPTH)
             .await
-            .map_err(|e| format!("Failed to read skill file: {}", e))
+            .ok_or_else(|| {
+                format!(
+                    "SKILL.md not found in directory or {} levels below",
+                    SKILL_MD_SEARCH_DEPTH
+                )
+            })
+    } else if path.is_file() {
+        Ok(path)
     } else {
         Err("Skill path does not exist".to_string())
     }
@@ -733,6 +3038,44 @@ pub fn open_folder_in_explorer(path: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Open an http(s) URL in the default browser.
+/// Other schemes (file://, custom protocol handlers, ...) are rejected so a link can't
+/// be used to launch local files or apps.
+pub fn open_external_url(url: &str) -> Result<(), String> {
+    let parsed = reqwest::Url::parse(url).map_err(|e| format!("Invalid URL: {}", e))?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return Err(format!("Only http and https URLs can be opened: {}", url));
+    }
+    // Pass the re-serialized URL so the OS handler gets a normalized, percent-encoded string
+    let url = parsed.as_str();
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .arg(url)
+            .spawn()
+            .map_err(|e| format!("Failed to open URL: {}", e))?;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        std::process::Command::new("explorer")
+            .arg(url)
+            .spawn()
+            .map_err(|e| format!("Failed to open URL: {}", e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        std::process::Command::new("xdg-open")
+            .arg(url)
+            .spawn()
+            .map_err(|e| format!("Failed to open URL: {}", e))?;
+    }
+
+    Ok(())
+}
+
 /// File tree node for visualization
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FileNode {
@@ -742,6 +3085,10 @@ pub struct FileNode {
     pub children: Option<Vec<FileNode>>,
     pub content: Option<String>,
     pub metadata: Option<SkillMetadata>,
+    #[serde(default)]
+    pub partial: bool, // Set on the root when the walk stopped early (time budget or cancel)
+    #[serde(default)]
+    pub is_binary: bool, // Content looked binary, so it wasn't read
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -750,10 +3097,46 @@ pub struct SkillMetadata {
     pub description: Option<String>,
     pub author: Option<String>,
     pub category: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Largest file without a known text extension that is sniffed and previewed
+const SNIFF_MAX_FILE_SIZE: u64 = 256 * 1024;
+/// How much of a file is checked when guessing whether it's text
+const SNIFF_PREFIX_LEN: usize = 1024;
+
+/// How long a folder tree walk may run before returning what it has so far
+const FOLDER_TREE_BUDGET: Duration = Duration::from_secs(5);
+
+// Cancellation flags for in-flight folder tree walks, keyed by the caller's request id
+static TREE_CANCELLATIONS: Mutex<BTreeMap<String, Arc<AtomicBool>>> = Mutex::new(BTreeMap::new());
+
+/// Stop condition shared by every level of a folder tree walk
+struct TreeBudget {
+    deadline: Instant,
+    cancelled: Arc<AtomicBool>,
+    exhausted: AtomicBool,
+}
+
+impl TreeBudget {
+    /// Check whether the walk should stop, remembering that it did
+    fn is_exhausted(&self) -> bool {
+        if Instant::now() >= self.deadline || self.cancelled.load(Ordering::Relaxed) {
+            self.exhausted.store(true, Ordering::Relaxed);
+        }
+        self.exhausted.load(Ordering::Relaxed)
+    }
 }
 
-/// Get the file tree structure for a skills folder
-pub async fn get_folder_tree(path: &str, max_depth: usize) -> Result<FileNode, String> {
+/// Get the file tree structure for a skills folder.
+/// Gives up after FOLDER_TREE_BUDGET, or when `cancel_folder_tree` is called with the same
+/// `request_id`, and returns the partial tree with `partial` set on the root.
+pub async fn get_folder_tree(
+    path: &str,
+    max_depth: usize,
+    request_id: Option<&str>,
+) -> Result<FileNode, String> {
     let path_buf = PathBuf::from(path);
 
     if !path_buf.exists() {
@@ -762,11 +3145,46 @@ pub async fn get_folder_tree(path: &str, max_depth: usize) -> Result<FileNode, S
             .map_err(|e| format!("Failed to create directory: {}", e))?;
     }
 
-    build_tree(&path_buf, 0, max_depth).await
+    let cancelled = Arc::new(AtomicBool::new(false));
+    if let (Some(id), Ok(mut pending)) = (request_id, TREE_CANCELLATIONS.lock()) {
+        pending.insert(id.to_string(), cancelled.clone());
+    }
+
+    let budget = TreeBudget {
+        deadline: Instant::now() + FOLDER_TREE_BUDGET,
+        cancelled,
+        exhausted: AtomicBool::new(false),
+    };
+    let result = build_tree(&path_buf, 0, max_depth, &budget).await;
+
+    if let (Some(id), Ok(mut pending)) = (request_id, TREE_CANCELLATIONS.lock()) {
+        pending.remove(id);
+    }
+
+    let mut root = result?;
+    root.partial = budget.exhausted.load(Ordering::Relaxed);
+    if root.partial {
+        tracing::warn!("Folder tree for {} is incomplete", path);
+    }
+    Ok(root)
+}
+
+/// Abort an in-flight folder tree walk started with the given request id
+pub fn cancel_folder_tree(request_id: &str) {
+    if let Ok(pending) = TREE_CANCELLATIONS.lock() {
+        if let Some(flag) = pending.get(request_id) {
+            flag.store(true, Ordering::Relaxed);
+        }
+    }
 }
 
 #[async_recursion::async_recursion]
-async fn build_tree(path: &PathBuf, current_depth: usize, max_depth: usize) -> Result<FileNode, String> {
+async fn build_tree(
+    path: &PathBuf,
+    current_depth: usize,
+    max_depth: usize,
+    budget: &TreeBudget,
+) -> Result<FileNode, String> {
     let name = path
         .file_name()
         .map(|n| n.to_string_lossy().to_string())
@@ -794,10 +3212,25 @@ async fn build_tree(path: &PathBuf, current_depth: usize, max_depth: usize) -> R
                 })
                 .unwrap_or(false);
         
-        let content = if should_read {
-            fs::read_to_string(path).await.ok()
+        // Files with a text extension are still checked for null bytes; others (LICENSE, ...)
+        // are read if they're small and their first KB looks like text
+        let (content, is_binary) = if should_read {
+            match fs::read(path).await {
+                Ok(bytes) if bytes.contains(&0) => (None, true),
+                Ok(bytes) => (String::from_utf8(bytes).ok(), false),
+                Err(_) => (None, false),
+            }
+        } else if fs::metadata(path)
+            .await
+            .is_ok_and(|m| m.len() <= SNIFF_MAX_FILE_SIZE)
+        {
+            match fs::read(path).await {
+                Ok(bytes) if looks_like_text(&bytes) => (String::from_utf8(bytes).ok(), false),
+                Ok(_) => (None, true),
+                Err(_) => (None, false),
+            }
         } else {
-            None
+            (None, false)
         };
 
         let metadata = if path.extension().map(|e| e == "md").unwrap_or(false) {
@@ -813,6 +3246,8 @@ async fn build_tree(path: &PathBuf, current_depth: usize, max_depth: usize) -> R
             children: None,
             content,
             metadata,
+            partial: false,
+            is_binary,
         });
     }
 
@@ -822,16 +3257,17 @@ async fn build_tree(path: &PathBuf, current_depth: usize, max_depth: usize) -> R
     if current_depth < max_depth {
         if let Ok(mut entries) = fs::read_dir(path).await {
             while let Ok(Some(entry)) = entries.next_entry().await {
+                if budget.is_exhausted() {
+                    break;
+                }
                 let entry_path = entry.path();
-                // Skip hidden files/folders
-                if entry_path.file_name()
-                    .map(|n| n.to_string_lossy().starts_with('.'))
-                    .unwrap_or(false)
-                {
-                    continue;
+                // Skip hidden files/folders and names that aren't valid UTF-8
+                match sync::checked_file_name(&entry_path) {
+                    Some(name) if !name.starts_with('.') => {}
+                    _ => continue,
                 }
 
-                if let Ok(child) = build_tree(&entry_path, current_depth + 1, max_depth).await {
+                if let Ok(child) = build_tree(&entry_path, current_depth + 1, max_depth, budget).await {
                     children.push(child);
                 }
             }
@@ -854,22 +3290,50 @@ async fn build_tree(path: &PathBuf, current_depth: usize, max_depth: usize) -> R
         children: Some(children),
         content: None,
         metadata: None,
+        partial: false,
+        is_binary: false,
     })
 }
 
-fn extract_metadata(content: &str) -> SkillMetadata {
+/// Whether the start of a file looks like text: no null bytes and valid UTF-8
+/// (a multi-byte character cut off at the end of the sample is allowed)
+fn looks_like_text(bytes: &[u8]) -> bool {
+    let sample = &bytes[..bytes.len().min(SNIFF_PREFIX_LEN)];
+    if sample.contains(&0) {
+        return false;
+    }
+    match std::str::from_utf8(sample) {
+        Ok(_) => true,
+        Err(e) => e.error_len().is_none(),
+    }
+}
+
+pub(crate) fn extract_metadata(content: &str) -> SkillMetadata {
     let mut name = None;
     let mut description = None;
     let mut author = None;
     let mut category = None;
+    let mut tags = Vec::new();
 
     // Try to parse YAML frontmatter
     if content.starts_with("---") {
         if let Some(end) = content[3..].find("---") {
             let frontmatter = &content[3..end + 3];
+            // Set while reading a block list ("tags:" followed by "- item" lines)
+            let mut in_tags = false;
             for line in frontmatter.lines() {
                 let line = line.trim();
-                if line.starts_with("name:") {
+                if in_tags {
+                    if let Some(item) = line.strip_prefix("- ") {
+                        tags.push(item.trim().trim_matches('"').to_string());
+                        continue;
+                    }
+                    in_tags = false;
+                }
+                if let Some(value) = line.strip_prefix("tags:") {
+                    tags = parse_tag_list(value);
+                    in_tags = tags.is_empty();
+                } else if line.starts_with("name:") {
                     name = Some(line[5..].trim().trim_matches('"').to_string());
                 } else if line.starts_with("description:") {
                     description = Some(line[12..].trim().trim_matches('"').to_string());
@@ -898,7 +3362,86 @@ fn extract_metadata(content: &str) -> SkillMetadata {
         description,
         author,
         category,
+        tags,
+    }
+}
+
+/// Parse an inline tag list: "[a, b]" or "a, b"
+fn parse_tag_list(value: &str) -> Vec<String> {
+    value
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|tag| tag.trim().trim_matches('"').trim_matches('\'').to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CategoryCount {
+    pub kind: String, // "category" or "tag"
+    pub name: String,
+    pub count: usize,
+}
+
+const UNCATEGORIZED: &str = "uncategorized";
+
+/// Count installed skills per frontmatter category and per tag.
+/// Skills without a category are counted under "uncategorized".
+pub async fn get_installed_categories() -> Result<Vec<CategoryCount>, String> {
+    let home = crate::home::home_dir()?;
+    let dirs = unique_skill_dirs(enabled_tool_configs(), |tool| tool_skill_dirs(tool, &home));
+
+    // Scan the skills directories in parallel; tools sharing one are only scanned once
+    let per_dir = futures::future::join_all(dirs.into_iter().map(|(tool, dir)| async move {
+        let mut skills = Vec::new();
+        collect_skills_from_dir(&dir, tool.id, tool.skill_extensions, &mut skills).await;
+
+        let mut metadata = Vec::new();
+        for skill in skills {
+            let path = PathBuf::from(skill.path);
+            let skill_md = if path.is_dir() { path.join("SKILL.md") } else { path };
+            if let Ok(content) = fs::read_to_string(&skill_md).await {
+                metadata.push(extract_metadata(&content));
+            }
+        }
+        metadata
+    }))
+    .await;
+
+    let mut categories: std::collections::BTreeMap<String, usize> = Default::default();
+    let mut tags: std::collections::BTreeMap<String, usize> = Default::default();
+
+    for metadata in per_dir.into_iter().flatten() {
+        let category = metadata
+            .category
+            .filter(|c| !c.is_empty())
+            .unwrap_or_else(|| UNCATEGORIZED.to_string());
+        *categories.entry(category).or_default() += 1;
+
+        for tag in metadata.tags {
+            *tags.entry(tag).or_default() += 1;
+        }
     }
+
+    let mut counts: Vec<CategoryCount> = categories
+        .into_iter()
+        .map(|(name, count)| CategoryCount {
+            kind: "category".to_string(),
+            name,
+            count,
+        })
+        .chain(tags.into_iter().map(|(name, count)| CategoryCount {
+            kind: "tag".to_string(),
+            name,
+            count,
+        }))
+        .collect();
+
+    // Most used first within each kind (the sort is stable, so names stay alphabetical)
+    counts.sort_by(|a, b| a.kind.cmp(&b.kind).then(b.count.cmp(&a.count)));
+    Ok(counts)
 }
 
 /// Tool directory info for a specific tool
@@ -925,12 +3468,9 @@ pub struct ToolDirectories {
 
 /// Get directory structure for a specific tool
 pub async fn get_tool_directories(tool_id: &str) -> Result<ToolDirectories, String> {
-    let home = get_home_dir().ok_or("Cannot find home directory")?;
+    let home = crate::home::home_dir()?;
     
-    let tool = SUPPORTED_TOOLS
-        .iter()
-        .find(|t| t.id == tool_id)
-        .ok_or_else(|| format!("Unknown tool: {}", tool_id))?;
+    let tool = find_tool(tool_id)?;
     
     let config_path = home.join(tool.config_paths[0]);
     let installed = config_path.exists();
@@ -943,11 +3483,12 @@ pub async fn get_tool_directories(tool_id: &str) -> Result<ToolDirectories, Stri
         icon: &str,
         is_file: bool,
         dir_type: &str,
+        extensions: &[&str],
     ) -> ToolDirectoryInfo {
         let skill_count = if is_file {
             if path.exists() { 1 } else { 0 }
         } else {
-            count_skills_in_dir(&path).await
+            count_skills_in_dir(&path, extensions).await
         };
         
         ToolDirectoryInfo {
@@ -981,6 +3522,7 @@ pub async fn get_tool_directories(tool_id: &str) -> Result<ToolDirectories, Stri
                 "package",
                 false,
                 "skills",
+                tool.skill_extensions,
             ).await,
             make_dir_info(
                 "Skills (Code Mode)",
@@ -989,6 +3531,7 @@ pub async fn get_tool_directories(tool_id: &str) -> Result<ToolDirectories, Stri
                 "package",
                 false,
                 "skills-code",
+                tool.skill_extensions,
             ).await,
             make_dir_info(
                 "Skills (Architect Mode)",
@@ -997,6 +3540,7 @@ pub async fn get_tool_directories(tool_id: &str) -> Result<ToolDirectories, Stri
                 "package",
                 false,
                 "skills-architect",
+                tool.skill_extensions,
             ).await,
         ],
         // All other tools: just show the primary skills directory
@@ -1008,6 +3552,7 @@ pub async fn get_tool_directories(tool_id: &str) -> Result<ToolDirectories, Stri
                 "package",
                 false,
                 dir_name,
+                tool.skill_extensions,
             ).await,
         ],
     };
@@ -1022,6 +3567,32 @@ pub async fn get_tool_directories(tool_id: &str) -> Result<ToolDirectories, Stri
     })
 }
 
+/// Create a tool's config directory and primary skills directory if missing.
+/// Returns only the directories that were actually created.
+pub async fn ensure_tool_directories(tool_id: &str) -> Result<Vec<String>, String> {
+    let home = crate::home::home_dir()?;
+
+    let tool = find_tool(tool_id)?;
+    check_config_dir(&home, tool.config_paths[0])?;
+
+    let config_dir = home.join(tool.config_paths[0]);
+    let skills_dir = get_tool_skills_dir(tool_id)?;
+
+    let mut created = Vec::new();
+    for dir in [config_dir, skills_dir] {
+        if dir.is_dir() {
+            continue;
+        }
+
+        fs::create_dir_all(&dir)
+            .await
+            .map_err(|e| format!("Failed to create directory {}: {}", dir.display(), e))?;
+        created.push(dir.to_string_lossy().to_string());
+    }
+
+    Ok(created)
+}
+
 /// Read a single file's content
 pub async fn read_file_content(path: &str) -> Result<String, String> {
     fs::read_to_string(path)
@@ -1029,9 +3600,21 @@ pub async fn read_file_content(path: &str) -> Result<String, String> {
         .map_err(|e| format!("Failed to read file: {}", e))
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CopySkillResult {
+    pub path: String,
+    pub files_written: usize,
+}
+
 /// Copy a skill from source to destination
 /// Handles both folder-based skills and single .md file skills
-pub async fn copy_skill(source_path: &str, dest_dir: &str) -> Result<String, String> {
+/// An existing destination is handled per on_conflict: "merge" copies file by file,
+/// overwriting matching files and keeping the others
+pub async fn copy_skill(
+    source_path: &str,
+    dest_dir: &str,
+    on_conflict: ConflictPolicy,
+) -> Result<CopySkillResult, String> {
     let source = PathBuf::from(source_path);
     let dest_base = PathBuf::from(dest_dir);
 
@@ -1051,25 +3634,176 @@ pub async fn copy_skill(source_path: &str, dest_dir: &str) -> Result<String, Str
 
     // Check if destination already exists
     if dest_path.exists() {
-        return Err(format!("Skill '{}' already exists in destination", skill_name));
+        match on_conflict {
+            ConflictPolicy::Error => {
+                return Err(format!("Skill '{}' already exists in destination", skill_name));
+            }
+            ConflictPolicy::Skip => {
+                return Ok(CopySkillResult {
+                    path: dest_path.to_string_lossy().to_string(),
+                    files_written: 0,
+                });
+            }
+            ConflictPolicy::Overwrite => {
+                let removed = if dest_path.is_dir() {
+                    fs::remove_dir_all(&dest_path).await
+                } else {
+                    fs::remove_file(&dest_path).await
+                };
+                removed.map_err(|e| format!("Failed to remove existing skill: {}", e))?;
+            }
+            ConflictPolicy::Merge => {}
+        }
     }
 
-    if source.is_dir() {
+    let files_written = if source.is_dir() {
         // Copy entire directory recursively
-        copy_dir_recursive(&source, &dest_path).await?;
+        copy_dir_recursive(&source, &dest_path).await?
     } else {
         // Copy single file
         fs::copy(&source, &dest_path)
             .await
             .map_err(|e| format!("Failed to copy file: {}", e))?;
+        1
+    };
+
+    Ok(CopySkillResult {
+        path: dest_path.to_string_lossy().to_string(),
+        files_written,
+    })
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ProjectSkillCopy {
+    pub slug: String,
+    pub status: String, // "copied", "skipped" (already in the project), "not_found", "ambiguous" or "failed"
+    pub path: Option<String>,
+    pub files_written: usize,
+    pub error: Option<String>,
+}
+
+/// Copy personal skills of a tool into a project's skills directory for the same tool.
+/// An empty `slugs` copies every personal skill. Each skill gets its own result, so one
+/// failure doesn't stop the rest.
+pub async fn copy_personal_skills_to_project(
+    tool_id: &str,
+    project_path: &str,
+    slugs: &[String],
+    on_conflict: ConflictPolicy,
+) -> Result<Vec<ProjectSkillCopy>, String> {
+    let project_dir = PathBuf::from(resolve_path(project_path)?);
+    if !project_dir.is_dir() {
+        return Err(format!("Project directory does not exist: {}", project_path));
+    }
+    let tool = find_tool(tool_id)?;
+    check_project_scope(tool)?;
+    let personal_dir = get_tool_skills_dir(tool_id)?;
+    let project_skills_dir = skills_dir_in(tool, &project_dir);
+    crate::disk::ensure_writable(&project_skills_dir)?;
+
+    // Personal skills by slug: folders, and loose skill files (any of the tool's extensions)
+    // under their name without the extension. A folder and a loose file can share a slug,
+    // so each slug keeps every match.
+    let mut personal: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    if let Ok(mut entries) = fs::read_dir(&personal_dir).await {
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            let Some(name) = sync::checked_file_name(&path) else {
+                continue;
+            };
+            if name.starts_with('.') || sync::should_skip(&name) || is_broken_link(&path).await {
+                continue;
+            }
+            if path.is_dir() {
+                personal.entry(name).or_default().push(path);
+            } else if has_skill_extension(&path, tool.skill_extensions) {
+                if let Some(slug) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    personal.entry(slug.to_string()).or_default().push(path.clone());
+                }
+            }
+        }
+    }
+
+    let wanted: Vec<String> = if slugs.is_empty() {
+        personal.keys().cloned().collect()
+    } else {
+        slugs.to_vec()
+    };
+
+    let mut results = Vec::new();
+    for slug in wanted {
+        let source = match personal.get(&slug).map(Vec::as_slice) {
+            Some([source]) => source,
+            Some(sources) if !sources.is_empty() => {
+                // Copying either one would silently drop the other
+                let names: Vec<String> = sources
+                    .iter()
+                    .filter_map(|p| p.file_name())
+                    .map(|n| n.to_string_lossy().to_string())
+                    .collect();
+                results.push(ProjectSkillCopy {
+                    slug,
+                    status: "ambiguous".to_string(),
+                    path: None,
+                    files_written: 0,
+                    error: Some(format!(
+                        "Several personal skills share this name ({}), rename one to copy it",
+                        names.join(", ")
+                    )),
+                });
+                continue;
+            }
+            _ => {
+                results.push(ProjectSkillCopy {
+                    slug,
+                    status: "not_found".to_string(),
+                    path: None,
+                    files_written: 0,
+                    error: Some(format!("No personal skill in {}", personal_dir.display())),
+                });
+                continue;
+            }
+        };
+
+        let dest = project_skills_dir.join(source.file_name().unwrap_or_default());
+        let skipped = on_conflict == ConflictPolicy::Skip && fs::symlink_metadata(&dest).await.is_ok();
+        let result = copy_skill(
+            &source.to_string_lossy(),
+            &project_skills_dir.to_string_lossy(),
+            on_conflict,
+        )
+        .await;
+        results.push(match result {
+            Ok(copied) => ProjectSkillCopy {
+                slug,
+                status: if skipped { "skipped" } else { "copied" }.to_string(),
+                path: Some(copied.path),
+                files_written: copied.files_written,
+                error: None,
+            },
+            Err(e) => ProjectSkillCopy {
+                slug,
+                status: "failed".to_string(),
+                path: None,
+                files_written: 0,
+                error: Some(e),
+            },
+        });
     }
 
-    Ok(dest_path.to_string_lossy().to_string())
+    tracing::info!(
+        "Copied {} of {} personal {} skills into {}",
+        results.iter().filter(|r| r.status == "copied").count(),
+        results.len(),
+        tool_id,
+        project_skills_dir.display()
+    );
+    Ok(results)
 }
 
-/// Recursively copy a directory
+/// Recursively copy a directory, returning the number of files copied
 #[async_recursion::async_recursion]
-async fn copy_dir_recursive(src: &PathBuf, dst: &PathBuf) -> Result<(), String> {
+async fn copy_dir_recursive(src: &PathBuf, dst: &PathBuf) -> Result<usize, String> {
     fs::create_dir_all(dst)
         .await
         .map_err(|e| format!("Failed to create directory: {}", e))?;
@@ -1078,20 +3812,22 @@ async fn copy_dir_recursive(src: &PathBuf, dst: &PathBuf) -> Result<(), String>
         .await
         .map_err(|e| format!("Failed to read directory: {}", e))?;
 
+    let mut copied = 0;
     while let Ok(Some(entry)) = entries.next_entry().await {
         let entry_path = entry.path();
         let dest_path = dst.join(entry.file_name());
 
         if entry_path.is_dir() {
-            copy_dir_recursive(&entry_path, &dest_path).await?;
+            copied += copy_dir_recursive(&entry_path, &dest_path).await?;
         } else {
             fs::copy(&entry_path, &dest_path)
                 .await
                 .map_err(|e| format!("Failed to copy file: {}", e))?;
+            copied += 1;
         }
     }
 
-    Ok(())
+    Ok(copied)
 }
 
 /// List skills in a directory (for import picker)
@@ -1103,7 +3839,157 @@ pub async fn list_skills_in_dir(dir_path: &str) -> Result<Vec<InstalledSkill>, S
     }
 
     let mut skills = Vec::new();
-    collect_skills_from_dir(&path, "temp", &mut skills).await;
+    collect_skills_from_dir(&path, "temp", DEFAULT_SKILL_EXTENSIONS, &mut skills).await;
 
     Ok(skills)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::test_dir;
+
+    #[test]
+    fn unusable_skill_names_are_rejected() {
+        for name in ["!!!", "   ", "", " - ", "-", "_-_"] {
+            let result = build_folder_name(name, None);
+            assert!(result.is_err(), "{:?} should be rejected, got {:?}", name, result);
+        }
+    }
+
+    #[test]
+    fn unusable_skill_names_fall_back_to_frontmatter_name() {
+        let content = "---\nname: Data Tools\n---\n# Data Tools\n";
+        for name in ["!!!", "   ", ""] {
+            assert_eq!(build_folder_name(name, Some(content)).unwrap(), "data-tools");
+        }
+    }
+
+    #[tokio::test]
+    async fn skill_metadata_without_a_name_uses_the_first_heading() {
+        let dir = test_dir("metadata-heading");
+        std::fs::write(
+            dir.join("SKILL.md"),
+            "---\ndescription: Formats tables\n---\n\nIntro\n\n# Table Tools\n\nBody\n",
+        )
+        .unwrap();
+
+        let metadata = read_skill_metadata(&dir.to_string_lossy()).await.unwrap();
+
+        assert_eq!(metadata.name.as_deref(), Some("Table Tools"));
+        assert_eq!(metadata.description.as_deref(), Some("Formats tables"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn skills_readme_does_not_count_as_a_skill() {
+        let skills = test_dir("readme-count");
+        std::fs::create_dir_all(skills.join("real-skill")).unwrap();
+        std::fs::write(skills.join("real-skill/SKILL.md"), "# Real\n").unwrap();
+        std::fs::write(skills.join("real-skill/README.md"), "# Docs\n").unwrap();
+
+        let before = count_skills_in_dir(&skills, DEFAULT_SKILL_EXTENSIONS).await;
+        std::fs::write(skills.join("README.md"), "# My skills\n").unwrap();
+        let after = count_skills_in_dir(&skills, DEFAULT_SKILL_EXTENSIONS).await;
+
+        assert_eq!(before, 1);
+        assert_eq!(after, before);
+        let _ = std::fs::remove_dir_all(&skills);
+    }
+
+    #[tokio::test]
+    async fn uninstalling_a_loose_skill_keeps_the_skills_directory() {
+        let root = test_dir("uninstall-loose");
+        let skills = root.join(".claude").join("skills");
+        std::fs::create_dir_all(&skills).unwrap();
+        let skill = skills.join("my-skill.md");
+        std::fs::write(&skill, "# My skill\n").unwrap();
+
+        uninstall_skill(&skill.to_string_lossy()).await.unwrap();
+
+        assert!(!skill.exists());
+        assert!(skills.is_dir());
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn uninstalling_skill_md_keeps_a_project_skills_directory() {
+        let project = test_dir("uninstall-project");
+        let skills = project.join(".claude").join("skills");
+        std::fs::create_dir_all(&skills).unwrap();
+        let skill_md = skills.join("SKILL.md");
+        std::fs::write(&skill_md, "# Stray\n").unwrap();
+
+        uninstall_skill(&skill_md.to_string_lossy()).await.unwrap();
+
+        assert!(skills.is_dir());
+        let _ = std::fs::remove_dir_all(&project);
+    }
+
+    #[tokio::test]
+    async fn migrating_legacy_skills_keeps_claude_skills_when_claude_is_disabled() {
+        // Which tools are enabled in SkillHub only filters what is shown; Claude Code still
+        // reads ~/.claude/skills, so the decision is made from every known tool
+        let home = test_dir("migrate-legacy");
+        let legacy = home.join(".claude").join("skills");
+        std::fs::create_dir_all(legacy.join("my-skill")).unwrap();
+        std::fs::write(legacy.join("my-skill/SKILL.md"), "# My skill\n").unwrap();
+        let tools: Vec<&'static ToolConfig> = SUPPORTED_TOOLS.iter().collect();
+
+        let plan = migrate_legacy_skills_in(&tools, &home, "copilot", true).await.unwrap();
+
+        assert!(plan.keeps_originals);
+        assert_eq!(plan.migrated.len(), 1);
+        assert!(legacy.join("my-skill/SKILL.md").is_file());
+        assert!(PathBuf::from(&plan.migrated[0]).join("SKILL.md").is_file());
+        let _ = std::fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    fn unusable_custom_folder_names_are_rejected() {
+        let names = [".hidden", ".", "..", "skill.", "CON", "nul", "Com1.md", "lpt9 .txt", "a/b", ""];
+        for name in names {
+            let result = resolve_folder_name("Skill", None, Some(name));
+            assert!(result.is_err(), "{:?} should be rejected, got {:?}", name, result);
+        }
+        assert_eq!(resolve_folder_name("Skill", None, Some(" my.skill ")).unwrap(), "my.skill");
+        assert_eq!(resolve_folder_name("Skill", None, Some("console")).unwrap(), "console");
+    }
+
+    #[test]
+    fn relative_paths_are_rejected() {
+        for input in ["projects/app", "./app", "..", "~user/app"] {
+            let error = resolve_path(input).unwrap_err();
+            assert!(error.starts_with("Path must be absolute"), "{}: {}", input, error);
+        }
+        assert!(resolve_path(&std::env::temp_dir().to_string_lossy()).is_ok());
+    }
+
+    #[test]
+    fn ascii_skill_names_slug_as_before() {
+        assert_eq!(slugify_skill_name("Foo - Bar").as_deref(), Some("foo---bar"));
+        assert_eq!(slugify_skill_name("  My Skill_v2!  ").as_deref(), Some("my-skill_v2"));
+        assert_eq!(slugify_skill_name("a/b\\c.d").as_deref(), Some("abcd"));
+    }
+
+    #[test]
+    fn cjk_skill_names_keep_their_characters() {
+        assert_eq!(slugify_skill_name("数据分析").as_deref(), Some("数据分析"));
+        assert_eq!(slugify_skill_name("数据 分析").as_deref(), Some("数据-分析"));
+        assert_eq!(slugify_skill_name("データ\u{3000}分析").as_deref(), Some("データ-分析"));
+    }
+
+    #[test]
+    fn accented_skill_names_keep_their_letters() {
+        assert_eq!(slugify_skill_name("Café Crème").as_deref(), Some("café-crème"));
+        assert_eq!(slugify_skill_name("Ünïcödé").as_deref(), Some("ünïcödé"));
+    }
+
+    #[test]
+    fn emoji_skill_names_get_a_stable_name() {
+        let slug = slugify_skill_name("🚀✨").unwrap();
+        assert!(slug.starts_with("skill-"), "{}", slug);
+        assert_eq!(slugify_skill_name("🚀✨").unwrap(), slug);
+        assert_eq!(slugify_skill_name("Rocket 🚀").as_deref(), Some("rocket-"));
+    }
+}
@@ -0,0 +1,150 @@
+// System tray icon and menu
+
+use tauri::{
+    menu::{Menu, MenuItem, Submenu},
+    tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
+    App, AppHandle, Listener, Manager, Runtime,
+};
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::{tools, InstalledSkill};
+
+const TRAY_ID: &str = "main";
+const RECENT_SKILLS_LIMIT: usize = 3;
+const RECENT_ITEM_PREFIX: &str = "recent:";
+/// Periodic refresh, for changes made outside the app (e.g. skills copied in by hand)
+const REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+/// Quiet time after a skills-changed event before rescanning, so a batch rescans once
+const REFRESH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Bumped on every refresh request; a pending refresh only runs if no newer one came in
+static REFRESH_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Create the tray icon with its menu
+pub fn create_tray(app: &App) -> tauri::Result<()> {
+    let menu = build_menu(app.handle(), "— skills", &[])?;
+
+    let _tray = TrayIconBuilder::with_id(TRAY_ID)
+        .icon(app.default_window_icon().unwrap().clone())
+        .menu(&menu)
+        .show_menu_on_left_click(false)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "show" => show_main_window(app),
+            "quit" => {
+                app.exit(0);
+            }
+            id => {
+                if let Some(path) = id.strip_prefix(RECENT_ITEM_PREFIX) {
+                    open_skill_folder(path);
+                }
+            }
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                show_main_window(tray.app_handle());
+            }
+        })
+        .build(app)?;
+
+    // Keep the menu current when installed skills change (emitted by install/uninstall)
+    let handle = app.handle().clone();
+    app.listen("skills-changed", move |_| {
+        schedule_tray_refresh(handle.clone());
+    });
+
+    // Tray menus can't be rebuilt on open on every platform, so also refresh on a timer
+    let handle = app.handle().clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            refresh_tray_menu(&handle).await;
+            tokio::time::sleep(REFRESH_INTERVAL).await;
+        }
+    });
+
+    Ok(())
+}
+
+fn build_menu<R: Runtime>(
+    app: &AppHandle<R>,
+    count_label: &str,
+    recent_skills: &[InstalledSkill],
+) -> tauri::Result<Menu<R>> {
+    let count_item = MenuItem::with_id(app, "skills_count", count_label, false, None::<&str>)?;
+
+    let recent_menu = Submenu::with_id(app, "recent", "Recent Skills", !recent_skills.is_empty())?;
+    for skill in recent_skills {
+        let item = MenuItem::with_id(
+            app,
+            format!("{}{}", RECENT_ITEM_PREFIX, skill.path),
+            format!("{} ({})", skill.name, skill.tool_id),
+            true,
+            None::<&str>,
+        )?;
+        recent_menu.append(&item)?;
+    }
+
+    let show_item = MenuItem::with_id(app, "show", "Show SkillHub", true, None::<&str>)?;
+    let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+
+    Menu::with_items(app, &[&count_item, &recent_menu, &show_item, &quit_item])
+}
+
+fn show_main_window<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// Open the folder of a skill (the parent folder for single-file skills)
+fn open_skill_folder(skill_path: &str) {
+    let path = std::path::Path::new(skill_path);
+    let folder = if path.is_file() {
+        path.parent().unwrap_or(path)
+    } else {
+        path
+    };
+    let _ = tools::open_folder_in_explorer(&folder.to_string_lossy());
+}
+
+/// Refresh the menu once REFRESH_DEBOUNCE has passed without another request
+fn schedule_tray_refresh<R: Runtime>(app: AppHandle<R>) {
+    let generation = REFRESH_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(REFRESH_DEBOUNCE).await;
+        if REFRESH_GENERATION.load(Ordering::SeqCst) == generation {
+            refresh_tray_menu(&app).await;
+        }
+    });
+}
+
+/// Recount installed skills, reload the most recent ones and rebuild the tray menu.
+/// One scan serves both; it lists each skill once even when tools share a skills directory.
+async fn refresh_tray_menu<R: Runtime>(app: &AppHandle<R>) {
+    let (count_label, recent_skills) = match tools::get_all_installed_skills().await {
+        Ok(skills) => {
+            let label = if skills.len() == 1 {
+                "1 skill installed".to_string()
+            } else {
+                format!("{} skills installed", skills.len())
+            };
+            let recent: Vec<InstalledSkill> = skills.into_iter().take(RECENT_SKILLS_LIMIT).collect();
+            (label, recent)
+        }
+        Err(_) => ("— skills".to_string(), Vec::new()),
+    };
+
+    if let (Ok(menu), Some(tray)) = (
+        build_menu(app, &count_label, &recent_skills),
+        app.tray_by_id(TRAY_ID),
+    ) {
+        let _ = tray.set_menu(Some(menu));
+    }
+}
@@ -0,0 +1,105 @@
+// App update status and installation via the updater plugin
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Runtime};
+use tauri_plugin_updater::{Error as UpdaterError, UpdaterExt};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateInfo {
+    pub status: String, // "available", "up_to_date" or "unreachable"
+    pub available: bool,
+    pub current_version: String,
+    pub latest_version: Option<String>,
+    pub notes: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadProgress {
+    pub downloaded: u64,
+    pub total: Option<u64>,
+}
+
+/// Errors that mean the update server couldn't be reached, as opposed to a broken setup
+fn is_unreachable(error: &UpdaterError) -> bool {
+    matches!(
+        error,
+        UpdaterError::Reqwest(_) | UpdaterError::Network(_) | UpdaterError::ReleaseNotFound
+    )
+}
+
+/// Check whether a newer version is available.
+/// An unreachable update server is reported as a status rather than an error.
+pub async fn check_for_update<R: Runtime>(app: &AppHandle<R>) -> Result<UpdateInfo, String> {
+    crate::installer::ensure_online()?;
+    let current_version = app.package_info().version.to_string();
+    let updater = app
+        .updater()
+        .map_err(|e| format!("Failed to initialize updater: {}", e))?;
+
+    match updater.check().await {
+        Ok(Some(update)) => Ok(UpdateInfo {
+            status: "available".to_string(),
+            available: true,
+            current_version,
+            latest_version: Some(update.version),
+            notes: update.body,
+            error: None,
+        }),
+        Ok(None) => Ok(UpdateInfo {
+            status: "up_to_date".to_string(),
+            available: false,
+            current_version: current_version.clone(),
+            latest_version: Some(current_version),
+            notes: None,
+            error: None,
+        }),
+        Err(e) if is_unreachable(&e) => {
+            tracing::warn!("Update server unreachable: {}", e);
+            Ok(UpdateInfo {
+                status: "unreachable".to_string(),
+                available: false,
+                current_version,
+                latest_version: None,
+                notes: None,
+                error: Some(e.to_string()),
+            })
+        }
+        Err(e) => Err(format!("Failed to check for updates: {}", e)),
+    }
+}
+
+/// Download and install the latest version, emitting `update-download-progress` events.
+/// The frontend relaunches the app afterwards.
+pub async fn install_update<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    crate::installer::ensure_online()?;
+    let updater = app
+        .updater()
+        .map_err(|e| format!("Failed to initialize updater: {}", e))?;
+
+    let update = updater
+        .check()
+        .await
+        .map_err(|e| format!("Failed to check for updates: {}", e))?
+        .ok_or("No update available")?;
+
+    tracing::info!("Installing update {}", update.version);
+
+    let mut downloaded: u64 = 0;
+    update
+        .download_and_install(
+            |chunk_length, content_length| {
+                downloaded += chunk_length as u64;
+                let _ = app.emit(
+                    "update-download-progress",
+                    DownloadProgress {
+                        downloaded,
+                        total: content_length,
+                    },
+                );
+            },
+            || {},
+        )
+        .await
+        .map_err(|e| format!("Failed to install update: {}", e))
+}
@@ -0,0 +1,100 @@
+// Provenance check: compare an installed skill with its canonical version in the SkillHub catalog
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::{bundle, library, sync};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyResult {
+    pub skill_id: String,
+    pub clean: bool,
+    pub matching: Vec<String>,
+    pub differing: Vec<String>,
+    pub missing: Vec<String>, // in the catalog but not installed
+    pub extra: Vec<String>,   // installed but not in the catalog
+    pub errors: Vec<String>,  // catalog files that couldn't be fetched
+}
+
+/// Installs normalize the SKILL.md name, so compare it case-insensitively
+pub(crate) fn normalize_catalog_path(path: &str) -> String {
+    // An unsafe path can't match an installed file, so it's compared as given
+    let path = sync::normalize_portable_path(path).unwrap_or_else(|_| path.to_string());
+    if path.eq_ignore_ascii_case("SKILL.md") {
+        "SKILL.md".to_string()
+    } else {
+        path
+    }
+}
+
+/// Compare an installed skill file by file with the catalog version it was installed from
+pub async fn verify_against_catalog(skill_path: &str) -> Result<VerifyResult, String> {
+    let meta = sync::read_meta(skill_path)
+        .await?
+        .ok_or("Skill has no .skillhub.json, its catalog source is unknown")?;
+
+    // Fetch and hash every catalog file
+    let fetched: Vec<(String, Result<String, String>)> =
+        bundle::fetch_catalog_files(&meta.skill_id)
+            .await?
+            .into_iter()
+            .map(|(path, content)| (path, content.map(|c| sync::hash_content(c.as_bytes()))))
+            .collect();
+
+    let local: BTreeMap<String, String> = library::read_skill_files(Path::new(skill_path))
+        .await?
+        .into_iter()
+        .map(|(path, content)| (path, sync::hash_content(&content)))
+        .collect();
+
+    let mut result = VerifyResult {
+        skill_id: meta.skill_id.clone(),
+        clean: false,
+        matching: Vec::new(),
+        differing: Vec::new(),
+        missing: Vec::new(),
+        extra: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    let mut catalog_paths = Vec::new();
+    for (file_path, hash) in fetched {
+        let path = normalize_catalog_path(&file_path);
+        // A SKILL.md-only install deliberately left the other files out
+        if meta.skill_md_only && !path.eq_ignore_ascii_case("SKILL.md") {
+            continue;
+        }
+        match hash {
+            Ok(hash) => match local.get(&path) {
+                Some(local_hash) if *local_hash == hash => result.matching.push(path.clone()),
+                Some(_) => result.differing.push(path.clone()),
+                None => result.missing.push(path.clone()),
+            },
+            Err(e) => result.errors.push(format!("{}: {}", path, e)),
+        }
+        catalog_paths.push(path);
+    }
+
+    result.extra = local
+        .keys()
+        .filter(|path| !catalog_paths.contains(path))
+        .cloned()
+        .collect();
+
+    for list in [
+        &mut result.matching,
+        &mut result.differing,
+        &mut result.missing,
+        &mut result.errors,
+    ] {
+        list.sort();
+    }
+
+    result.clean = result.differing.is_empty()
+        && result.missing.is_empty()
+        && result.extra.is_empty()
+        && result.errors.is_empty();
+
+    Ok(result)
+}
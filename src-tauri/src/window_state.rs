@@ -0,0 +1,148 @@
+// Main window geometry persistence
+// Saves size/position to ~/.skillhub/window.json and restores it on launch
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tauri::{PhysicalPosition, PhysicalSize, Runtime, WebviewWindow};
+
+/// Quiet time after the last move/resize before the geometry is written
+const SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Bumped on every move/resize; a pending save only runs if no newer event came in
+static SAVE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WindowState {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    #[serde(default)]
+    pub maximized: bool,
+}
+
+/// Get the window state file path
+fn get_window_state_path() -> Result<std::path::PathBuf, String> {
+    Ok(crate::installer::get_skillhub_config_dir()?.join("window.json"))
+}
+
+/// Read the saved window state, if any
+fn read_window_state() -> Option<WindowState> {
+    let path = get_window_state_path().ok()?;
+    let content = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Write the window state to disk
+fn write_window_state(state: &WindowState) -> Result<(), String> {
+    let path = get_window_state_path()?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+
+    let content = serde_json::to_string_pretty(state)
+        .map_err(|e| format!("Failed to serialize window state: {}", e))?;
+
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write window state: {}", e))
+}
+
+/// Save the current geometry of a window
+/// Minimized windows are skipped since their reported position is meaningless
+pub fn save_window_state<R: Runtime>(window: &WebviewWindow<R>) {
+    if window.is_minimized().unwrap_or(false) {
+        return;
+    }
+
+    let maximized = window.is_maximized().unwrap_or(false);
+
+    // Keep the last normal geometry while maximized so un-maximizing after restart works
+    let state = if maximized {
+        match read_window_state() {
+            Some(previous) => WindowState { maximized, ..previous },
+            None => return,
+        }
+    } else {
+        let (Ok(position), Ok(size)) = (window.outer_position(), window.outer_size()) else {
+            return;
+        };
+        WindowState {
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+            maximized,
+        }
+    };
+
+    let _ = write_window_state(&state);
+}
+
+/// Save the geometry once the window has stopped moving/resizing for SAVE_DEBOUNCE,
+/// so a drag writes the file once instead of on every event
+pub fn schedule_save_window_state<R: Runtime>(window: WebviewWindow<R>) {
+    let generation = SAVE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(SAVE_DEBOUNCE).await;
+        if SAVE_GENERATION.load(Ordering::SeqCst) == generation {
+            save_window_state(&window);
+        }
+    });
+}
+
+/// Restore saved geometry, clamped to the monitors currently attached
+pub fn restore_window_state<R: Runtime>(window: &WebviewWindow<R>) {
+    let Some(state) = read_window_state() else {
+        return;
+    };
+
+    let monitors = window.available_monitors().unwrap_or_default();
+
+    // Prefer the monitor that contains the saved window's top-left corner,
+    // otherwise fall back to the primary monitor (e.g. the saved one was disconnected)
+    let monitor = monitors
+        .iter()
+        .find(|m| {
+            let pos = m.position();
+            let size = m.size();
+            state.x >= pos.x
+                && state.y >= pos.y
+                && state.x < pos.x + size.width as i32
+                && state.y < pos.y + size.height as i32
+        })
+        .cloned()
+        .or_else(|| window.primary_monitor().ok().flatten());
+
+    let Some(monitor) = monitor else {
+        return;
+    };
+
+    let (x, y, width, height) = clamp_to_monitor(&state, *monitor.position(), *monitor.size());
+
+    let _ = window.set_size(PhysicalSize::new(width, height));
+    let _ = window.set_position(PhysicalPosition::new(x, y));
+
+    if state.maximized {
+        let _ = window.maximize();
+    }
+}
+
+/// Clamp the window rectangle so it fits entirely within the monitor bounds
+fn clamp_to_monitor(
+    state: &WindowState,
+    monitor_pos: PhysicalPosition<i32>,
+    monitor_size: PhysicalSize<u32>,
+) -> (i32, i32, u32, u32) {
+    let width = state.width.min(monitor_size.width);
+    let height = state.height.min(monitor_size.height);
+
+    let max_x = monitor_pos.x + (monitor_size.width - width) as i32;
+    let max_y = monitor_pos.y + (monitor_size.height - height) as i32;
+
+    let x = state.x.clamp(monitor_pos.x, max_x);
+    let y = state.y.clamp(monitor_pos.y, max_y);
+
+    (x, y, width, height)
+}
@@ -1,4 +1,5 @@
 import { useAppStore } from '../store'
+import { ensureOnline, guardedFetch } from './network'
 
 // Get the API base URL from environment or default to production
 const SKILLHUB_URL = import.meta.env.VITE_SKILLHUB_API_URL || 'https://www.skillhub.club'
@@ -39,8 +40,15 @@ export async function refreshAccessToken(): Promise<boolean> {
     return false
   }
 
+  // Offline, the refresh can't happen; keep the session for when the network is back
   try {
-    const response = await fetch(`${SKILLHUB_URL}/api/v1/oauth/token`, {
+    await ensureOnline()
+  } catch {
+    return false
+  }
+
+  try {
+    const response = await guardedFetch(`${SKILLHUB_URL}/api/v1/oauth/token`, {
       method: 'POST',
       headers: { 'Content-Type': 'application/json' },
       body: JSON.stringify({
@@ -99,6 +107,7 @@ export async function authenticatedFetch(
   url: string,
   options: RequestInit = {}
 ): Promise<Response> {
+  await ensureOnline()
   const token = await ensureValidToken()
 
   if (!token) {
@@ -109,7 +118,7 @@ export async function authenticatedFetch(
   // If body is a string, we can reuse it; otherwise clone the options
   const bodyForRetry = options.body
 
-  const response = await fetch(url, {
+  const response = await guardedFetch(url, {
     ...options,
     headers: {
       ...options.headers,
@@ -124,7 +133,7 @@ export async function authenticatedFetch(
 
     if (refreshed) {
       const newToken = useAppStore.getState().accessToken
-      return fetch(url, {
+      return guardedFetch(url, {
         ...options,
         body: bodyForRetry, // Use the saved body for retry
         headers: {
@@ -177,7 +186,7 @@ export async function fetchUserInfo() {
 
 // Exchange authorization code for tokens (initial login)
 export async function exchangeCodeForTokens(code: string) {
-  const response = await fetch(`${SKILLHUB_URL}/api/v1/oauth/token`, {
+  const response = await guardedFetch(`${SKILLHUB_URL}/api/v1/oauth/token`, {
     method: 'POST',
     headers: { 'Content-Type': 'application/json' },
     body: JSON.stringify({
@@ -0,0 +1,37 @@
+import { invoke } from '@tauri-apps/api/core'
+
+// Same error the Rust network commands return in offline mode, so callers can treat both alike
+export const OFFLINE_ERROR = 'offline: Offline mode is enabled, network requests are disabled'
+
+// Whether an error came from offline mode rather than a failed request
+export function isOfflineError(error: unknown): boolean {
+  const message = error instanceof Error ? error.message : String(error)
+  return message.startsWith('offline:')
+}
+
+// Whether offline mode (no network requests at all) is on
+export async function getOfflineMode(): Promise<boolean> {
+  try {
+    return await invoke<boolean>('get_offline_mode')
+  } catch {
+    return false // outside the app (e.g. vite preview) there is no offline mode
+  }
+}
+
+// Turn offline mode on or off
+export async function setOfflineMode(enabled: boolean): Promise<void> {
+  return invoke('set_offline_mode', { enabled })
+}
+
+// Throw the offline error when offline mode is on
+export async function ensureOnline(): Promise<void> {
+  if (await getOfflineMode()) {
+    throw new Error(OFFLINE_ERROR)
+  }
+}
+
+// fetch() that fails without touching the network while offline mode is on
+export async function guardedFetch(input: RequestInfo | URL, init?: RequestInit): Promise<Response> {
+  await ensureOnline()
+  return fetch(input, init)
+}
@@ -1,4 +1,5 @@
 import { ensureValidToken, SKILLHUB_URL } from './auth'
+import { guardedFetch } from './network'
 
 export type PlaygroundStreamEvent =
   | { type: 'content'; content: string }
@@ -40,7 +41,7 @@ export async function streamPlaygroundRun(
     throw new Error('AUTH_REQUIRED')
   }
 
-  const response = await fetch(`${SKILLHUB_URL}/api/playground/run`, {
+  const response = await guardedFetch(`${SKILLHUB_URL}/api/playground/run`, {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
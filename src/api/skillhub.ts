@@ -1,10 +1,16 @@
 import { invoke } from '@tauri-apps/api/core'
 import type {
   DetectedTool,
+  ToolInfo,
+  CustomTool,
+  DashboardStats,
+  UpdateStats,
   InstalledSkill,
+  InstalledSkillsPage,
   SkillHubSkill,
   CatalogResponse,
   SkillFilesResponse,
+  SkillFileMetadata,
   UserSkill,
   UserSkillFile,
   CreateUserSkillRequest,
@@ -19,6 +25,7 @@ import type {
   MarketplaceResponse,
   MarketplaceSkill
 } from '../types'
+import { getOfflineMode, guardedFetch } from './network'
 
 // Get the API base URL
 function getApiBaseUrl(): string {
@@ -87,8 +94,78 @@ if (typeof window !== 'undefined') {
 }
 
 // Detect all supported AI coding tools on the system
-export async function detectTools(): Promise<DetectedTool[]> {
-  return invoke('detect_tools')
+// baseDir stands in for the home directory when it can't be found
+export async function detectTools(baseDir?: string): Promise<DetectedTool[]> {
+  return invoke('detect_tools', { baseDir })
+}
+
+// Detect which tools a project directory is set up for (scope: 'project')
+export async function detectProjectTools(projectPath: string): Promise<DetectedTool[]> {
+  return invoke('detect_project_tools', { projectPath })
+}
+
+// Remove what SkillHub set up for a tool; removeSkills also deletes its skills after a snapshot
+export interface ToolResetReport {
+  tool_id: string
+  removed: string[]
+  backup_path: string | null
+}
+
+export async function resetTool(toolId: string, removeSkills: boolean): Promise<ToolResetReport> {
+  return invoke('reset_tool', { toolId, removeSkills })
+}
+
+// Move a tool's skills directory to newDir, linking the standard path to it
+// Returns the path the skills directory now resolves to
+export async function relocateToolSkills(
+  toolId: string,
+  newDir: string,
+  moveExisting: boolean
+): Promise<string> {
+  return invoke('relocate_tool_skills', { toolId, newDir, moveExisting })
+}
+
+// Skills found in a tool's legacy location and where they'd move
+export interface MigrationItem {
+  name: string
+  from: string
+  to: string
+  conflict: boolean
+}
+
+export interface MigrationPlan {
+  items: MigrationItem[]
+  keeps_originals: boolean // legacy folder is shared with another tool, so skills are copied
+  migrated: string[]
+}
+
+// Plan (or, with apply, perform) moving a tool's skills out of its legacy location
+export async function migrateLegacySkills(toolId: string, apply: boolean): Promise<MigrationPlan> {
+  return invoke('migrate_legacy_skills', { toolId, apply })
+}
+
+// List all supported tools, built-in and user-registered (no disk scanning)
+export async function listSupportedTools(): Promise<ToolInfo[]> {
+  return invoke('list_supported_tools')
+}
+
+// Register a custom tool (or correct a built-in one's directories); returns it as listed
+export async function registerCustomTool(tool: CustomTool): Promise<ToolInfo> {
+  return invoke('register_custom_tool', { tool })
+}
+
+export async function removeCustomTool(toolId: string): Promise<void> {
+  return invoke('remove_custom_tool', { toolId })
+}
+
+// Skill counts and disk usage across enabled tools, for the dashboard
+export async function getDashboardStats(): Promise<DashboardStats> {
+  return invoke('get_dashboard_stats')
+}
+
+// How many catalog-installed skills have updates (network-bound, call after getDashboardStats)
+export async function getDashboardUpdateStats(): Promise<UpdateStats> {
+  return invoke('get_dashboard_update_stats')
 }
 
 // Get installed skills for a specific tool
@@ -96,13 +173,47 @@ export async function getInstalledSkills(toolId: string): Promise<InstalledSkill
   return invoke('get_installed_skills', { toolId })
 }
 
+// One sorted page of installed skills (page starts at 1; empty toolIds = all enabled tools)
+export async function listInstalledSkillsPaged(
+  toolIds: string[],
+  sortBy: 'name' | 'modified_at' | 'size',
+  page: number,
+  limit: number
+): Promise<InstalledSkillsPage> {
+  return invoke('list_installed_skills_paged', { toolIds, sortBy, page, limit })
+}
+
 // Install a skill to specified tools
+export interface InstallResult {
+  tool_id: string
+  status: 'installed' | 'failed'
+  path?: string | null
+  error?: string | null
+  verified: boolean // content matched the hash the API reported
+}
+
+// Every tool is attempted; each tool's outcome is in the results (see installFailures)
 export async function installSkill(
   skillContent: string,
   skillName: string,
-  toolIds: string[]
-): Promise<string[]> {
-  return invoke('install_skill', { skillContent, skillName, toolIds })
+  toolIds: string[],
+  expectedSha256?: string, // SHA-256 the catalog reports for SKILL.md; nothing installs on a mismatch
+  baseDir?: string // stands in for the home directory when it can't be found
+): Promise<InstallResult[]> {
+  return invoke<InstallResult[]>('install_skill', {
+    skillContent,
+    skillName,
+    toolIds,
+    expectedSha256,
+    baseDir
+  })
+}
+
+// "<tool>: <error>" for each tool an install failed for
+export function installFailures(results: InstallResult[]): string[] {
+  return results
+    .filter(r => r.status === 'failed')
+    .map(r => `${r.tool_id}: ${r.error ?? 'unknown error'}`)
 }
 
 // Uninstall a skill
@@ -115,11 +226,22 @@ export async function readSkillContent(skillPath: string): Promise<string> {
   return invoke('read_skill_content', { skillPath })
 }
 
+// Read only the frontmatter metadata of a skill (for cards)
+export async function readSkillMetadata(skillPath: string): Promise<SkillFileMetadata> {
+  return invoke('read_skill_metadata', { skillPath })
+}
+
 // Search skills using SkillHub API
 export async function searchSkills(
   query: string,
   limit?: number
 ): Promise<SkillHubSkill[]> {
+  // Offline mode searches the prefetched catalog instead
+  if (await getOfflineMode()) {
+    const cached = await searchCachedCatalog(query)
+    return limit === undefined ? cached : cached.slice(0, limit)
+  }
+
   const results: SkillHubSkill[] = await invoke('search_skills', { query, limit })
 
   // Deduplicate by slug, keeping the one with highest github_stars
@@ -134,6 +256,14 @@ export async function searchSkills(
   return Array.from(uniqueBySlug.values())
 }
 
+// Search in pages; the backend emits 'search-result-page' ({ query, page, skills }) as each page arrives
+export async function searchSkillsStreaming(
+  query: string,
+  total?: number
+): Promise<SkillHubSkill[]> {
+  return invoke('search_skills_streaming', { query, total })
+}
+
 // Get skill catalog from SkillHub API
 export async function getCatalog(
   page?: number,
@@ -142,30 +272,77 @@ export async function getCatalog(
   sortBy?: string,
   type?: string // "collections" for aggregator repos
 ): Promise<CatalogResponse> {
+  if (await getOfflineMode()) {
+    return getCachedCatalogPage(page ?? 1, limit ?? 20, category, type)
+  }
   return invoke('get_catalog', { page, limit, category, sortBy, type })
 }
 
+// A catalog page of the prefetched catalog, for offline mode (best rated first)
+async function getCachedCatalogPage(
+  page: number,
+  limit: number,
+  category?: string,
+  type?: string
+): Promise<CatalogResponse> {
+  // Collections aren't part of the prefetched catalog
+  if (type === 'collections') {
+    return { skills: [], pagination: { page, limit, total: 0, totalPages: 1 } }
+  }
+  return invoke('get_cached_catalog_page', { page, limit, category })
+}
+
+// Catalog categories with skill counts, most skills first
+export interface CategoryInfo {
+  slug: string // value for getCatalog's category filter
+  name: string
+  count: number
+}
+
+export async function getCatalogCategories(): Promise<CategoryInfo[]> {
+  return invoke('get_catalog_categories')
+}
+
+// Save catalog skill summaries locally for offline search
+export interface PrefetchReport {
+  skills_cached: number
+  pages_fetched: number
+  path: string
+}
+
+export async function prefetchCatalog(
+  categories: string[] | null,
+  maxSkills: number
+): Promise<PrefetchReport> {
+  return invoke('prefetch_catalog', { categories, maxSkills })
+}
+
+// Search the prefetched catalog (works offline; empty until prefetchCatalog has run)
+export async function searchCachedCatalog(query: string): Promise<SkillHubSkill[]> {
+  return invoke('search_cached_catalog', { query })
+}
+
 // KOL API response type
 export interface KolUser {
   id: string
-  githubUsername: string
-  displayName: string
-  avatarUrl?: string
+  username: string
+  display_name: string
+  avatar_url?: string | null
   bio?: string | null
-  githubFollowers: number
-  skillCount: number
-  twitterHandle?: string | null
-  kolVerifiedAt?: string
+  followers: number
+  skills_count: number
+}
+
+export interface KolPagination {
+  total: number
+  limit: number
+  offset: number
+  has_more: boolean
 }
 
 export interface KolResponse {
   kols: KolUser[]
-  pagination: {
-    total: number
-    limit: number
-    offset: number
-    hasMore: boolean
-  }
+  pagination: KolPagination
 }
 
 // Get KOL list with caching
@@ -195,25 +372,10 @@ export async function getKolList(
 
 // KOL detail response type
 export interface KolDetailResponse {
-  user: {
-    id: string
-    githubUsername: string
-    displayName: string
-    avatarUrl?: string
-    bio?: string | null
-    githubFollowers: number
-    isKol: boolean
-    stats: {
-      totalSkills: number
-      totalStars: number
-    }
-  }
+  kol: KolUser
+  total_stars?: number | null
   skills: SkillHubSkill[]
-  pagination: {
-    total: number
-    limit: number
-    hasMore: boolean
-  }
+  pagination: KolPagination
 }
 
 // Get KOL detail with skills
@@ -247,6 +409,18 @@ export async function getSkillDetail(slug: string): Promise<SkillHubSkill> {
   return data.skill
 }
 
+// Popularity of a catalog skill; fields the API doesn't provide are null
+export interface SkillStats {
+  install_count: number | null
+  weekly_installs: number | null
+  stars: number | null
+  rating: number | null
+}
+
+export async function getSkillStats(skillId: string): Promise<SkillStats> {
+  return invoke('get_skill_stats', { skillId })
+}
+
 // GitHub file type
 export interface GitHubFile {
   path: string
@@ -291,7 +465,7 @@ export async function fetchGitHubDirectory(
   const apiUrl = `https://api.github.com/repos/${owner}/${repo}/contents/${path}?ref=${branch}`
   
   try {
-    const res = await fetch(apiUrl)
+    const res = await guardedFetch(apiUrl)
     if (!res.ok) {
       // Try master branch if main fails
       if (branch === 'main') {
@@ -307,7 +481,7 @@ export async function fetchGitHubDirectory(
       if (item.type === 'file') {
         const rawUrl = `https://raw.githubusercontent.com/${owner}/${repo}/${branch}/${item.path}`
         try {
-          const fileRes = await fetch(rawUrl)
+          const fileRes = await guardedFetch(rawUrl)
           if (fileRes.ok) {
             const content = await fileRes.text()
             // Get relative path from the root skill folder
@@ -333,6 +507,25 @@ export async function fetchGitHubDirectory(
   }
 }
 
+// Skills found in a GitHub repo: each folder with a SKILL.md, shallowest first
+export interface SkillCandidate {
+  path: string // "" for the repo root
+  skill_md_path: string
+  metadata: SkillFileMetadata | null
+}
+
+export interface RepoSkillDiscovery {
+  owner: string
+  repo: string
+  branch: string
+  candidates: SkillCandidate[]
+  truncated: boolean
+}
+
+export async function discoverSkillsInRepo(repoUrl: string): Promise<RepoSkillDiscovery> {
+  return invoke('discover_skills_in_repo', { repoUrl })
+}
+
 // Fetch all files for a skill from GitHub
 export async function fetchSkillFilesFromGitHub(
   repoUrl: string,
@@ -366,26 +559,93 @@ export async function fetchSkillContent(slug: string): Promise<string> {
   return skill.skill_md_raw || ''
 }
 
+// Where a skill would be installed for each tool, as [toolId, path] pairs (no files are written)
+// Pass the SKILL.md content and any custom folder name to get the same folder the install uses
+export async function resolveInstallPaths(
+  skillName: string,
+  toolIds: string[],
+  scope: 'personal' | 'project',
+  projectPath?: string,
+  skillContent?: string,
+  folderName?: string
+): Promise<[string, string][]> {
+  return invoke('resolve_install_paths', { skillName, skillContent, folderName, toolIds, scope, projectPath })
+}
+
 // Install multiple files for a skill (supports multi-file skills)
+export interface SkillFilesInstallResult {
+  installed_paths: string[]
+  skipped_files: string[]
+  note: string | null
+  verified: boolean // every installed file matched the hash the API reported
+  unverified_files: string[]
+}
+
+// skillMdOnly installs just SKILL.md and is recorded in the skill's SyncMeta;
+// skillId (the catalog id) lets the install create that SyncMeta
 export async function installSkillFiles(
   files: GitHubFile[],
   skillName: string,
-  toolIds: string[]
-): Promise<string[]> {
+  toolIds: string[],
+  expectedHashes?: Record<string, string>, // path -> SHA-256; a mismatch fails before anything is written
+  skillMdOnly = false,
+  skillId?: string
+): Promise<SkillFilesInstallResult> {
   // Convert GitHubFile[] to [path, content][] for Rust
   const filesTuples: [string, string][] = files.map(f => [f.path, f.content])
-  return invoke('install_skill_files', { files: filesTuples, skillName, toolIds })
+  return invoke('install_skill_files', { files: filesTuples, skillName, toolIds, expectedHashes, skillMdOnly, skillId })
 }
 
 // Install multiple files for a skill to a specific project directory
+export interface ProjectInstallResult {
+  path: string
+  gitignore_updated: boolean
+  skipped_files: string[]
+  verified: boolean // false if any file came without an expected hash
+  unverified_files: string[]
+}
+
 export async function installSkillFilesToProject(
   files: GitHubFile[],
   skillName: string,
   projectPath: string,
-  toolId: string
-): Promise<string> {
+  toolId: string,
+  gitignore = false,
+  skillMdOnly = false,
+  skillId?: string,
+  expectedHashes?: Record<string, string> // path -> SHA-256; a mismatch fails before anything is written
+): Promise<ProjectInstallResult> {
   const filesTuples: [string, string][] = files.map(f => [f.path, f.content])
-  return invoke('install_skill_files_to_project', { files: filesTuples, skillName, projectPath, toolId })
+  return invoke('install_skill_files_to_project', { files: filesTuples, skillName, projectPath, toolId, gitignore, skillMdOnly, skillId, expectedHashes })
+}
+
+// Copy personal skills of a tool into a project; an empty slugs list copies all of them
+export interface ProjectSkillCopy {
+  slug: string
+  status: 'copied' | 'skipped' | 'not_found' | 'ambiguous' | 'failed'
+  path: string | null
+  files_written: number
+  error: string | null
+}
+
+export async function copyPersonalSkillsToProject(
+  toolId: string,
+  projectPath: string,
+  slugs: string[] = [],
+  onConflict: 'error' | 'skip' | 'overwrite' | 'merge' = 'skip'
+): Promise<ProjectSkillCopy[]> {
+  return invoke('copy_personal_skills_to_project', { toolId, projectPath, slugs, onConflict })
+}
+
+// Git state of a project, to warn before installing into a branch with uncommitted changes
+export interface GitInfo {
+  is_repo: boolean
+  current_branch?: string // missing on a detached HEAD
+  is_dirty: boolean
+}
+
+export async function getProjectGitInfo(projectPath: string): Promise<GitInfo> {
+  return invoke('get_project_git_info', { projectPath })
 }
 
 // Smart install that uses GitHub direct download for multi-file skills
@@ -449,7 +709,11 @@ export async function smartInstallSkill(
   }
   
   if (skillMdRaw) {
-    await installSkill(skillMdRaw, folderName, toolIds)
+    // Callers only learn of failures through the error, so any failed tool is reported
+    const failures = installFailures(await installSkill(skillMdRaw, folderName, toolIds))
+    if (failures.length > 0) {
+      throw new Error(failures.join('; '))
+    }
     return
   }
 
@@ -526,8 +790,8 @@ export async function getSkillFiles(skillId: string): Promise<SkillFilesResponse
 }
 
 // Get file content from GitHub (proxied through Rust backend)
-export async function getFileContent(rawUrl: string): Promise<string> {
-  return invoke('get_remote_file_content', { rawUrl })
+export async function getFileContent(rawUrl: string, fetchUrlDirect = false): Promise<string> {
+  return invoke('get_remote_file_content', { rawUrl, fetchUrlDirect })
 }
 
 // Build raw GitHub URL for a file
@@ -582,7 +846,7 @@ export async function generateSkill(
   signal?: AbortSignal
 ): Promise<void> {
   const baseUrl = getApiBaseUrl()
-  const response = await fetch(`${baseUrl}/api/v1/desktop/generate-skill`, {
+  const response = await guardedFetch(`${baseUrl}/api/v1/desktop/generate-skill`, {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
@@ -637,7 +901,7 @@ export async function trackGeneration(
   request: TrackGenerationRequest
 ): Promise<void> {
   const baseUrl = getApiBaseUrl()
-  const response = await fetch(`${baseUrl}/api/v1/desktop/track-generation`, {
+  const response = await guardedFetch(`${baseUrl}/api/v1/desktop/track-generation`, {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
@@ -674,7 +938,7 @@ export async function enhanceText(
   onEvent: (event: EnhanceTextEvent) => void
 ): Promise<void> {
   const baseUrl = getApiBaseUrl()
-  const response = await fetch(`${baseUrl}/api/v1/desktop/enhance-text`, {
+  const response = await guardedFetch(`${baseUrl}/api/v1/desktop/enhance-text`, {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
@@ -725,7 +989,7 @@ export async function createUserSkill(
   request: CreateUserSkillRequest
 ): Promise<UserSkill> {
   const baseUrl = getApiBaseUrl()
-  const response = await fetch(`${baseUrl}/api/user/skills`, {
+  const response = await guardedFetch(`${baseUrl}/api/user/skills`, {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
@@ -753,7 +1017,7 @@ export async function listUserSkills(
   if (options?.visibility) params.set('visibility', options.visibility)
 
   const url = `${baseUrl}/api/user/skills${params.toString() ? '?' + params.toString() : ''}`
-  const response = await fetch(url, {
+  const response = await guardedFetch(url, {
     headers: {
       'Authorization': `Bearer ${accessToken}`,
     },
@@ -773,7 +1037,7 @@ export async function getUserSkillDetail(
   skillId: string
 ): Promise<UserSkill> {
   const baseUrl = getApiBaseUrl()
-  const response = await fetch(`${baseUrl}/api/user/skills/${skillId}`, {
+  const response = await guardedFetch(`${baseUrl}/api/user/skills/${skillId}`, {
     headers: {
       'Authorization': `Bearer ${accessToken}`,
     },
@@ -794,7 +1058,7 @@ export async function updateUserSkill(
   updates: Partial<CreateUserSkillRequest>
 ): Promise<UserSkill> {
   const baseUrl = getApiBaseUrl()
-  const response = await fetch(`${baseUrl}/api/user/skills/${skillId}`, {
+  const response = await guardedFetch(`${baseUrl}/api/user/skills/${skillId}`, {
     method: 'PUT',
     headers: {
       'Content-Type': 'application/json',
@@ -818,7 +1082,7 @@ export async function uploadSkillFiles(
   request: UploadFilesRequest
 ): Promise<{ version: number }> {
   const baseUrl = getApiBaseUrl()
-  const response = await fetch(`${baseUrl}/api/user/skills/${skillId}/files`, {
+  const response = await guardedFetch(`${baseUrl}/api/user/skills/${skillId}/files`, {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
@@ -842,7 +1106,7 @@ export async function getUploadUrl(
   request: UploadUrlRequest
 ): Promise<UploadUrlResponse> {
   const baseUrl = getApiBaseUrl()
-  const response = await fetch(`${baseUrl}/api/user/skills/${skillId}/upload-url`, {
+  const response = await guardedFetch(`${baseUrl}/api/user/skills/${skillId}/upload-url`, {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
@@ -866,7 +1130,7 @@ export async function publishSkill(
   request: PublishSkillRequest
 ): Promise<UserSkill> {
   const baseUrl = getApiBaseUrl()
-  const response = await fetch(`${baseUrl}/api/user/skills/${skillId}/publish`, {
+  const response = await guardedFetch(`${baseUrl}/api/user/skills/${skillId}/publish`, {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
@@ -890,7 +1154,7 @@ export async function setSkillVisibility(
   visibility: SkillVisibility
 ): Promise<UserSkill> {
   const baseUrl = getApiBaseUrl()
-  const response = await fetch(`${baseUrl}/api/user/skills/${skillId}/visibility`, {
+  const response = await guardedFetch(`${baseUrl}/api/user/skills/${skillId}/visibility`, {
     method: 'POST',
     headers: {
       'Content-Type': 'application/json',
@@ -913,7 +1177,7 @@ export async function getSkillVersions(
   skillId: string
 ): Promise<SkillVersion[]> {
   const baseUrl = getApiBaseUrl()
-  const response = await fetch(`${baseUrl}/api/user/skills/${skillId}/versions`, {
+  const response = await guardedFetch(`${baseUrl}/api/user/skills/${skillId}/versions`, {
     headers: {
       'Authorization': `Bearer ${accessToken}`,
     },
@@ -935,7 +1199,7 @@ export async function getSkillFilesForVersion(
 ): Promise<UserSkillFile[]> {
   const baseUrl = getApiBaseUrl()
   const params = version ? `?version=${version}` : ''
-  const response = await fetch(`${baseUrl}/api/user/skills/${skillId}/files${params}`, {
+  const response = await guardedFetch(`${baseUrl}/api/user/skills/${skillId}/files${params}`, {
     headers: {
       'Authorization': `Bearer ${accessToken}`,
     },
@@ -967,7 +1231,7 @@ export async function listMarketplaceSkills(
   const query = params.toString()
   const url = `${baseUrl}/api/user/skills/public${query ? `?${query}` : ''}`
 
-  const response = await fetch(url, {
+  const response = await guardedFetch(url, {
     headers: {
       'Authorization': `Bearer ${accessToken}`,
     },
@@ -2,7 +2,7 @@ import { useState, useEffect, useRef } from 'react'
 import { X, Sparkles, Loader2, Copy, Check, RotateCcw, AlertCircle, Download } from 'lucide-react'
 import MDEditor from '@uiw/react-md-editor'
 import { useAppStore } from '../store'
-import { generateSkill, type GenerateSkillEvent, installSkill, detectTools } from '../api/skillhub'
+import { generateSkill, type GenerateSkillEvent, installSkill, installFailures, detectTools } from '../api/skillhub'
 import { invoke } from '@tauri-apps/api/core'
 import ToolSelector from './ToolSelector'
 import { useTranslation } from 'react-i18next'
@@ -216,8 +216,16 @@ export default function AIGenerateDialog({
         showToast(t('aiGenerate.installedToProject', { name: skillName }), 'success')
       } else {
         // Install to personal (global) directory
-        await installSkill(generatedContent, skillName, selectedToolIds)
-        showToast(t('aiGenerate.installedToTools', { name: skillName, count: selectedToolIds.length }), 'success')
+        const results = await installSkill(generatedContent, skillName, selectedToolIds)
+        const failures = installFailures(results)
+        if (failures.length > 0) {
+          showToast(
+            t('common.installPartial', { installed: results.length - failures.length, total: results.length, failures: failures.join('; ') }),
+            failures.length === results.length ? 'error' : 'warning'
+          )
+        } else {
+          showToast(t('aiGenerate.installedToTools', { name: skillName, count: selectedToolIds.length }), 'success')
+        }
       }
 
       // Refresh tools to update counts
@@ -27,7 +27,7 @@ export default function KolDetail({ kol, onClose, onInstallSkill, onViewSkill }:
     setLoading(true)
     setError(null)
 
-    getKolDetail(kol.githubUsername)
+    getKolDetail(kol.username)
       .then(data => {
         setDetail(data)
       })
@@ -38,11 +38,11 @@ export default function KolDetail({ kol, onClose, onInstallSkill, onViewSkill }:
         showToast(message, 'error')
       })
       .finally(() => setLoading(false))
-  }, [kol.githubUsername, showToast])
+  }, [kol.username, showToast])
 
   const openProfile = async () => {
     try {
-      await open(`${SKILLHUB_URL}/u/${kol.githubUsername}`)
+      await open(`${SKILLHUB_URL}/u/${kol.username}`)
     } catch (error) {
       console.error('Failed to open browser:', error)
     }
@@ -60,10 +60,10 @@ export default function KolDetail({ kol, onClose, onInstallSkill, onViewSkill }:
         {/* Header */}
         <div className="flex items-center justify-between p-4 border-b-2 border-border-light">
           <div className="flex items-center gap-4">
-            {kol.avatarUrl ? (
+            {kol.avatar_url ? (
               <img
-                src={kol.avatarUrl}
-                alt={kol.displayName}
+                src={kol.avatar_url}
+                alt={kol.display_name}
                 className="w-16 h-16 border-2 border-foreground"
               />
             ) : (
@@ -72,16 +72,16 @@ export default function KolDetail({ kol, onClose, onInstallSkill, onViewSkill }:
               </div>
             )}
             <div>
-              <h2 className="text-xl font-bold tracking-tight">{kol.displayName}</h2>
-              <p className="text-sm text-muted-foreground">@{kol.githubUsername}</p>
+              <h2 className="text-xl font-bold tracking-tight">{kol.display_name}</h2>
+              <p className="text-sm text-muted-foreground">@{kol.username}</p>
               <div className="flex items-center gap-4 mt-1 text-xs text-muted-foreground uppercase tracking-wider">
                 <span className="flex items-center gap-1">
                   <Users size={12} />
-                  {t('kolDetail.followersCount', { count: kol.githubFollowers })}
+                  {t('kolDetail.followersCount', { count: kol.followers })}
                 </span>
                 <span className="flex items-center gap-1">
                   <Star size={12} />
-                  {t('kolDetail.starsCount', { count: detail?.user?.stats?.totalStars || kol.skillCount })}
+                  {t('kolDetail.starsCount', { count: detail?.total_stars || kol.skills_count })}
                 </span>
               </div>
             </div>
@@ -113,7 +113,7 @@ export default function KolDetail({ kol, onClose, onInstallSkill, onViewSkill }:
         {/* Skills */}
         <div className="flex-1 overflow-y-auto p-4">
           <h3 className="text-sm font-bold uppercase tracking-wider text-muted-foreground mb-4">
-            {t('kolDetail.skillsBy', { name: kol.displayName })}
+            {t('kolDetail.skillsBy', { name: kol.display_name })}
           </h3>
 
           {loading ? (
@@ -142,7 +142,7 @@ export default function KolDetail({ kol, onClose, onInstallSkill, onViewSkill }:
             </div>
           )}
 
-          {detail?.pagination?.hasMore && (
+          {detail?.pagination?.has_more && (
             <div className="mt-6 text-center">
               <button
                 onClick={openProfile}
@@ -158,7 +158,7 @@ export default function KolDetail({ kol, onClose, onInstallSkill, onViewSkill }:
         {/* Footer */}
         <div className="p-4 border-t-2 border-border-light">
           <p className="text-xs text-muted-foreground text-center uppercase tracking-wider">
-            {t('kolDetail.skillsTotal', { count: detail?.pagination?.total || kol.skillCount })}
+            {t('kolDetail.skillsTotal', { count: detail?.pagination?.total || kol.skills_count })}
           </p>
         </div>
       </div>
@@ -13,6 +13,7 @@ import { useNavigate } from 'react-router-dom'
 import { open } from '@tauri-apps/plugin-shell'
 import { useAppStore } from '../store'
 import { fetchWallet } from '../api/auth'
+import { preflightInstall, waitForDependencies, type PreflightReport } from '../hooks/useDependencies'
 import { Button } from './ui/button'
 import { Dialog, DialogContent } from './ui/dialog'
 import { Input } from './ui/input'
@@ -83,6 +84,8 @@ export default function SetupWizard({ isOpen, onClose, onComplete }: SetupWizard
   const [currentInstallIndex, setCurrentInstallIndex] = useState(0)
   const [isInstalling, setIsInstalling] = useState(false)
   const [installError, setInstallError] = useState<string | null>(null)
+  const [preflight, setPreflight] = useState<PreflightReport | null>(null)
+  const [isPreflighting, setIsPreflighting] = useState(false)
   const [showManualInstructions, setShowManualInstructions] = useState<ManualInstallInstructions | null>(null)
   
   // API Key configuration
@@ -110,6 +113,7 @@ export default function SetupWizard({ isOpen, onClose, onComplete }: SetupWizard
 
   const checkDependencies = async () => {
     setCurrentStep('checking')
+    setPreflight(null)
     try {
       const result = await invoke<DependencyStatus>('check_dependencies')
       setStatus(result)
@@ -254,10 +258,11 @@ export default function SetupWizard({ isOpen, onClose, onComplete }: SetupWizard
             xterm.writeln('')
             xterm.writeln('\x1b[36mVerifying installation...\x1b[0m')
             
-            // Wait a bit for PATH to update
-            await new Promise(resolve => setTimeout(resolve, 1000))
-            
-            const newStatus = await invoke<DependencyStatus>('check_dependencies')
+            // The dependency watcher reports the install once Claude Code shows up on PATH;
+            // if it doesn't within a few checks, take one last look ourselves
+            const newStatus =
+              (await waitForDependencies(s => s.claude_code.installed, 10000)) ??
+              (await invoke<DependencyStatus>('check_dependencies'))
             setStatus(newStatus)
             
             if (newStatus.claude_code.installed) {
@@ -288,6 +293,26 @@ export default function SetupWizard({ isOpen, onClose, onComplete }: SetupWizard
     }
   }, [currentInstallIndex, installSteps])
 
+  // Check permissions, PATH and disk space before running the first command
+  const startInstall = async () => {
+    const stepToRun = installSteps.find((s, i) => i >= currentInstallIndex && !s.skip_reason)
+    if (!stepToRun) return
+
+    setIsPreflighting(true)
+    try {
+      const report = await preflightInstall()
+      setPreflight(report)
+      if (!report.ready) return
+    } catch (error) {
+      // A failed check shouldn't stop the user from trying the install
+      console.error('Preflight check failed:', error)
+    } finally {
+      setIsPreflighting(false)
+    }
+
+    runInstallCommand(stepToRun)
+  }
+
   // Show manual instructions
   const showManualInstall = async (stepId: string) => {
     try {
@@ -301,6 +326,7 @@ export default function SetupWizard({ isOpen, onClose, onComplete }: SetupWizard
   // API Key validation result type
   interface ApiKeyValidationResult {
     valid: boolean
+    usable: boolean
     error_code: string | null
     message: string | null
   }
@@ -354,8 +380,8 @@ export default function SetupWizard({ isOpen, onClose, onComplete }: SetupWizard
         return
       }
 
-      // Key is valid - show warning if insufficient balance but still allow configuration
-      if (result.error_code === 'insufficient_balance') {
+      // Key is valid - show warning if it can't be billed yet but still allow configuration
+      if (!result.usable) {
         // Key is valid but needs balance - we'll still configure it
         console.log('API key valid but insufficient balance')
       }
@@ -487,6 +513,28 @@ export default function SetupWizard({ isOpen, onClose, onComplete }: SetupWizard
                 )}
               </div>
 
+              {/* Preflight issues */}
+              {preflight && preflight.issues.length > 0 && (
+                <div className={`border-2 p-4 ${preflight.ready ? 'border-yellow-500 bg-yellow-500/10' : 'border-red-500 bg-red-500/10'}`}>
+                  <div className="flex items-start gap-3">
+                    <AlertCircle size={20} className={`shrink-0 mt-0.5 ${preflight.ready ? 'text-yellow-500' : 'text-red-500'}`} />
+                    <div className="space-y-2">
+                      <p className={`text-sm font-medium ${preflight.ready ? 'text-yellow-600' : 'text-red-500'}`}>
+                        {preflight.ready ? t('setup.preflightWarnings') : t('setup.preflightBlocked')}
+                      </p>
+                      {preflight.issues.map(issue => (
+                        <div key={issue.id}>
+                          <p className="text-xs text-foreground">
+                            {issue.blocking ? '✗' : '!'} {issue.message}
+                          </p>
+                          <p className="text-xs text-muted-foreground mt-0.5">{issue.remediation}</p>
+                        </div>
+                      ))}
+                    </div>
+                  </div>
+                </div>
+              )}
+
               {/* Terminal output */}
               <div className="border-2 border-foreground overflow-hidden">
                 <div className="bg-[#0d1117] px-3 py-2 border-b border-gray-700 flex items-center gap-2">
@@ -554,8 +602,10 @@ export default function SetupWizard({ isOpen, onClose, onComplete }: SetupWizard
                   {showManualInstructions.docs_url && (
                     <a
                       href={showManualInstructions.docs_url}
-                      target="_blank"
-                      rel="noopener noreferrer"
+                      onClick={(e) => {
+                        e.preventDefault()
+                        invoke('open_external_url', { url: showManualInstructions.docs_url })
+                      }}
                       className="mt-3 inline-flex items-center gap-1 text-xs text-blue-500 hover:underline"
                     >
                       {t('setup.viewDocs')} <ExternalLink size={12} />
@@ -772,16 +822,16 @@ export default function SetupWizard({ isOpen, onClose, onComplete }: SetupWizard
                   {t('common.cancel')}
                 </Button>
                 <Button
-                  onClick={() => {
-                    const stepToRun = installSteps.find((s, i) => i >= currentInstallIndex && !s.skip_reason)
-                    if (stepToRun) {
-                      runInstallCommand(stepToRun)
-                    }
-                  }}
-                  disabled={isInstalling}
+                  onClick={startInstall}
+                  disabled={isInstalling || isPreflighting}
                   className="px-4 py-2 bg-foreground text-background font-semibold text-sm uppercase tracking-wide hover:opacity-90 disabled:opacity-50 transition-opacity flex items-center gap-2"
                 >
-                  {isInstalling ? (
+                  {isPreflighting ? (
+                    <>
+                      <Loader2 size={14} className="animate-spin" />
+                      {t('setup.preflightChecking')}
+                    </>
+                  ) : isInstalling ? (
                     <>
                       <Loader2 size={14} className="animate-spin" />
                       {t('setup.installing')}
@@ -3,7 +3,7 @@ import { X, Star, Download, ExternalLink, Github, Tag, FileText, Loader2, Folder
 import { open } from '@tauri-apps/plugin-shell'
 import { useTranslation } from 'react-i18next'
 import type { SkillHubSkill, SkillFileNode, SkillFilesResponse } from '../types'
-import { getSkillDetail, installSkill, installSkillFiles, installSkillFilesToProject, smartInstallSkill, smartInstallSkillToProject, getSkillFiles, getFileContent, buildRawGitHubUrl, type GitHubFile } from '../api/skillhub'
+import { getSkillDetail, installSkill, installFailures, installSkillFiles, installSkillFilesToProject, smartInstallSkill, smartInstallSkillToProject, getSkillFiles, getFileContent, buildRawGitHubUrl, type GitHubFile } from '../api/skillhub'
 import { useAppStore } from '../store'
 import ToolSelector from './ToolSelector'
 import FilePreview from './FilePreview'
@@ -42,6 +42,19 @@ function getAllFilePaths(nodes: SkillFileNode[]): string[] {
   return paths
 }
 
+// Map each file path to the SHA-256 the catalog reports for it, for install-time verification
+function getFileHashes(nodes: SkillFileNode[]): Record<string, string> {
+  const hashes: Record<string, string> = {}
+  for (const node of nodes) {
+    if (node.type === 'file') {
+      if (node.sha256) hashes[node.path] = node.sha256
+    } else if (node.children) {
+      Object.assign(hashes, getFileHashes(node.children))
+    }
+  }
+  return hashes
+}
+
 // File tree node component with checkbox
 function FileTreeNode({ 
   node, 
@@ -350,12 +363,12 @@ export default function SkillDetail({ skill: initialSkill, onClose }: SkillDetai
         if (files.length > 0) {
           if (installTarget === 'project' && projectPath) {
             for (const toolId of selectedToolIds) {
-              await installSkillFilesToProject(files, folderName, projectPath, toolId)
+              await installSkillFilesToProject(files, folderName, projectPath, toolId, false, false, skill.id, getFileHashes(filesData.tree))
             }
             showToast(t('skillDetail.installedFilesToProject', { count: files.length }), 'success')
           } else {
             // Install all files together preserving structure
-            await installSkillFiles(files, folderName, selectedToolIds)
+            await installSkillFiles(files, folderName, selectedToolIds, getFileHashes(filesData.tree), false, skill.id)
             showToast(t('skillDetail.installedFilesToTools', { count: files.length, toolCount: selectedToolIds.length }), 'success')
           }
           onClose()
@@ -379,8 +392,16 @@ export default function SkillDetail({ skill: initialSkill, onClose }: SkillDetai
           }
           showToast(t('skillDetail.installedToProject', { name: skill.name }), 'success')
         } else {
-          await installSkill(skill.skill_md_raw, skill.name, selectedToolIds)
-          showToast(t('skillDetail.installedToTools', { name: skill.name, count: selectedToolIds.length }), 'success')
+          const results = await installSkill(skill.skill_md_raw, skill.name, selectedToolIds)
+          const failures = installFailures(results)
+          if (failures.length > 0) {
+            showToast(
+              t('common.installPartial', { installed: results.length - failures.length, total: results.length, failures: failures.join('; ') }),
+              failures.length === results.length ? 'error' : 'warning'
+            )
+          } else {
+            showToast(t('skillDetail.installedToTools', { name: skill.name, count: selectedToolIds.length }), 'success')
+          }
         }
         onClose()
       }
@@ -162,7 +162,8 @@ export default function SkillPlayground({
   const [workingDirectory, setWorkingDirectory] = useState('')
 
   // Dependency check
-  const { status: depStatus, loading: depLoading, needsSetup, refresh: refreshDeps } = useDependencies()
+  // Kept current by the dependency watcher, including after the setup wizard installs
+  const { status: depStatus, loading: depLoading, needsSetup } = useDependencies()
   const [showSetupWizard, setShowSetupWizard] = useState(false)
 
   // Wallet balance
@@ -958,10 +959,7 @@ export default function SkillPlayground({
       <SetupWizard
         isOpen={showSetupWizard}
         onClose={() => setShowSetupWizard(false)}
-        onComplete={() => {
-          setShowSetupWizard(false)
-          refreshDeps()
-        }}
+        onComplete={() => setShowSetupWizard(false)}
       />
 
       {variant === 'modal' ? (
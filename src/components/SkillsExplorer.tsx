@@ -1,4 +1,4 @@
-import { useState, useEffect } from 'react'
+import { useState, useEffect, useRef } from 'react'
 import { invoke } from '@tauri-apps/api/core'
 import { open as openDialog } from '@tauri-apps/plugin-dialog'
 import { useTranslation } from 'react-i18next'
@@ -123,6 +123,8 @@ export default function SkillsExplorer({ tool, onClose, directPath, title }: Ski
   const [expandedPaths, setExpandedPaths] = useState<Set<string>>(new Set())
   const [moving, setMoving] = useState(false)
   const [deleting, setDeleting] = useState(false)
+  // Id of the in-flight get_folder_tree call, so it can be cancelled on navigation
+  const treeRequestRef = useRef<string | null>(null)
 
   // Skills-related directories for different tools
   const SKILLS_DIRS_MAP: Record<string, string[]> = {
@@ -171,10 +173,15 @@ export default function SkillsExplorer({ tool, onClose, directPath, title }: Ski
         ? directPath 
         : (tool.id === 'claude' ? tool.config_path : tool.skills_path)
       
+      const requestId = `tree-${Date.now()}-${Math.random().toString(36).slice(2)}`
+      treeRequestRef.current = requestId
       const result = await invoke<FileNode>('get_folder_tree', {
         path: pathToLoad,
         maxDepth: 5,
+        requestId,
       })
+      if (treeRequestRef.current !== requestId) return
+      treeRequestRef.current = null
       
       // Only filter for Claude/Codex when not in direct mode
       const allowedDirs = !directPath ? SKILLS_DIRS_MAP[tool.id] : undefined
@@ -202,6 +209,12 @@ export default function SkillsExplorer({ tool, onClose, directPath, title }: Ski
 
   useEffect(() => {
     loadTree()
+    return () => {
+      if (treeRequestRef.current) {
+        invoke('cancel_folder_tree', { requestId: treeRequestRef.current }).catch(() => {})
+        treeRequestRef.current = null
+      }
+    }
   }, [explorePath])
 
   const toggleExpand = (path: string) => {
@@ -393,7 +406,7 @@ export default function SkillsExplorer({ tool, onClose, directPath, title }: Ski
                   />
                 ) : (
                   <div className="text-center py-8 text-muted-foreground">
-                    {t('explorer.noContent')}
+                    {selectedNode.is_binary ? t('explorer.binaryFile') : t('explorer.noContent')}
                   </div>
                 )}
               </div>
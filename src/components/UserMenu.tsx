@@ -4,6 +4,7 @@ import { LogIn, LogOut, User, Heart, ChevronDown } from 'lucide-react'
 import { open } from '@tauri-apps/plugin-shell'
 import { useAppStore } from '../store'
 import { exchangeCodeForTokens, SKILLHUB_URL } from '../api/auth'
+import { guardedFetch } from '../api/network'
 
 interface UserMenuProps {
   collapsed?: boolean
@@ -41,7 +42,7 @@ export default function UserMenu({ collapsed = false }: UserMenuProps) {
       const expiresAt = Date.now() + tokens.expiresIn * 1000
 
       // Get user info (using token directly since we're not logged in yet)
-      const userResponse = await fetch(`${SKILLHUB_URL}/api/v1/oauth/userinfo`, {
+      const userResponse = await guardedFetch(`${SKILLHUB_URL}/api/v1/oauth/userinfo`, {
         headers: { Authorization: `Bearer ${tokens.accessToken}` },
       })
 
@@ -60,10 +61,10 @@ export default function UserMenu({ collapsed = false }: UserMenuProps) {
 
       // Fetch favorites and collections in parallel
       const [favoritesRes, collectionsRes] = await Promise.all([
-        fetch(`${SKILLHUB_URL}/api/v1/oauth/favorites`, {
+        guardedFetch(`${SKILLHUB_URL}/api/v1/oauth/favorites`, {
           headers: { Authorization: `Bearer ${tokens.accessToken}` },
         }),
-        fetch(`${SKILLHUB_URL}/api/v1/oauth/collections`, {
+        guardedFetch(`${SKILLHUB_URL}/api/v1/oauth/collections`, {
           headers: { Authorization: `Bearer ${tokens.accessToken}` },
         }),
       ])
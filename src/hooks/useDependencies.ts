@@ -1,5 +1,6 @@
 import { useState, useEffect, useCallback } from 'react'
 import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
 
 // Types matching Rust backend
 export interface DependencyInfo {
@@ -36,6 +37,19 @@ export interface InstallStep {
   skip_reason: string | null
 }
 
+export interface PreflightIssue {
+  id: 'home_not_writable' | 'npm_prefix_not_writable' | 'npm_bin_not_on_path' | 'low_disk_space'
+  message: string
+  remediation: string
+  blocking: boolean
+}
+
+export interface PreflightReport {
+  ready: boolean
+  issues: PreflightIssue[]
+  npm_prefix: string | null
+}
+
 export interface ManualInstallInstructions {
   step_id: string
   title: string
@@ -44,7 +58,8 @@ export interface ManualInstallInstructions {
 }
 
 /**
- * Hook to check and manage Claude Code dependencies
+ * Hook to check and manage Claude Code dependencies.
+ * Runs the dependency watcher while mounted, so status follows installs made elsewhere.
  */
 export function useDependencies() {
   const [status, setStatus] = useState<DependencyStatus | null>(null)
@@ -65,7 +80,30 @@ export function useDependencies() {
   }, [])
 
   useEffect(() => {
-    refresh()
+    let cancelled = false
+    let unlisten: (() => void) | undefined
+
+    listen<DependencyStatus>('dependencies-changed', (event) => {
+      setStatus(event.payload)
+      setError(null)
+      setLoading(false)
+    })
+      .then((fn) => {
+        if (cancelled) {
+          fn()
+          return
+        }
+        unlisten = fn
+        // The watcher emits the current status first, so no separate check is needed
+        return watchDependencies()
+      })
+      .catch(() => refresh())
+
+    return () => {
+      cancelled = true
+      unlisten?.()
+      stopWatchingDependencies().catch(() => {})
+    }
   }, [refresh])
 
   // Convenience getters
@@ -87,6 +125,70 @@ export function useDependencies() {
   }
 }
 
+/**
+ * Start watching dependencies; 'dependencies-changed' is emitted with the
+ * DependencyStatus first and then whenever readiness or an installed state changes
+ */
+export async function watchDependencies(): Promise<void> {
+  return invoke('watch_dependencies')
+}
+
+/**
+ * Stop the dependency watcher
+ */
+export async function stopWatchingDependencies(): Promise<boolean> {
+  return invoke<boolean>('stop_watching_dependencies')
+}
+
+/**
+ * Wait for a 'dependencies-changed' event whose status passes `predicate`.
+ * Resolves null after `timeoutMs` if none arrives; needs a running watcher.
+ */
+export async function waitForDependencies(
+  predicate: (status: DependencyStatus) => boolean,
+  timeoutMs: number
+): Promise<DependencyStatus | null> {
+  let settled = false
+  let unlisten: (() => void) | undefined
+  let timer: ReturnType<typeof setTimeout> | undefined
+  try {
+    return await new Promise<DependencyStatus | null>((resolve) => {
+      timer = setTimeout(() => resolve(null), timeoutMs)
+      listen<DependencyStatus>('dependencies-changed', (event) => {
+        if (predicate(event.payload)) resolve(event.payload)
+      }).then((fn) => {
+        if (settled) fn()
+        else unlisten = fn
+      })
+    })
+  } finally {
+    settled = true
+    clearTimeout(timer)
+    unlisten?.()
+  }
+}
+
+/**
+ * Check permissions, PATH and disk space before the guided install
+ */
+export async function preflightInstall(): Promise<PreflightReport> {
+  return invoke<PreflightReport>('preflight_install')
+}
+
+/**
+ * PATH entries the app sees; GUI launches can get a shorter PATH than a terminal
+ */
+export async function getEffectivePath(): Promise<string[]> {
+  return invoke<string[]>('get_effective_path')
+}
+
+/**
+ * Whether npm's global bin directory (where `claude` is installed) is on the app's PATH
+ */
+export async function pathContainsNpmGlobal(): Promise<boolean> {
+  return invoke<boolean>('path_contains_npm_global')
+}
+
 /**
  * Get installation steps for missing dependencies
  */
@@ -108,6 +210,24 @@ export async function configureClaudeCode(apiKey: string): Promise<void> {
   return invoke('configure_claude_code', { apiKey })
 }
 
+/**
+ * Shell config changes configureClaudeCode would make (API keys masked)
+ */
+export interface ConfigDiff {
+  file: string
+  before_excerpt: string
+  after_excerpt: string
+  lines_removed: string[]
+  lines_added: string[]
+}
+
+/**
+ * Preview the Claude Code configuration without writing it
+ */
+export async function previewClaudeCodeConfig(apiKey: string): Promise<ConfigDiff> {
+  return invoke<ConfigDiff>('preview_claude_code_config', { apiKey })
+}
+
 /**
  * Remove Claude Code configuration
  */
@@ -120,6 +240,7 @@ export async function removeClaudeCodeConfig(): Promise<void> {
  */
 export interface ApiKeyValidationResult {
   valid: boolean
+  usable: boolean
   error_code: string | null
   message: string | null
 }
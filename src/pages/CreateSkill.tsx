@@ -26,7 +26,7 @@ import {
 import MDEditor from '@uiw/react-md-editor'
 import { unzipSync, strFromU8, strToU8 } from 'fflate'
 import { useAppStore } from '../store'
-import { detectTools, installSkill, installSkillFiles, getUploadUrl } from '../api/skillhub'
+import { detectTools, installSkill, installFailures, installSkillFiles, getUploadUrl } from '../api/skillhub'
 import {
   createUserSkill,
   uploadSkillFiles,
@@ -226,8 +226,18 @@ export default function CreateSkill() {
       const skillName = slugify(name)
       const skillMd = textFiles.find(f => f.path.toLowerCase() === 'skill.md')
 
+      let showedFailures = false
       if (textFiles.length === 1 && skillMd) {
-        await installSkill(skillMd.text || strFromU8(skillMd.data), skillName, selectedToolIds)
+        const results = await installSkill(skillMd.text || strFromU8(skillMd.data), skillName, selectedToolIds)
+        const failures = installFailures(results)
+        if (failures.length > 0) {
+          showToast(
+            t('common.installPartial', { installed: results.length - failures.length, total: results.length, failures: failures.join('; ') }),
+            failures.length === results.length ? 'error' : 'warning'
+          )
+          if (failures.length === results.length) return
+          showedFailures = true
+        }
       } else {
         const installable = textFiles.map(f => ({
           path: f.path,
@@ -236,10 +246,13 @@ export default function CreateSkill() {
         await installSkillFiles(installable, skillName, selectedToolIds)
       }
 
-      if (files.some(f => f.isBinary)) {
-        showToast(t('create.install.installedWithBinary'), 'warning')
-      } else {
-        showToast(t('create.install.installedToTools', { count: selectedToolIds.length }), 'success')
+      // Partial installs have already shown each tool's result
+      if (!showedFailures) {
+        if (files.some(f => f.isBinary)) {
+          showToast(t('create.install.installedWithBinary'), 'warning')
+        } else {
+          showToast(t('create.install.installedToTools', { count: selectedToolIds.length }), 'success')
+        }
       }
       setSaveSuccess(true)
     } catch (error) {
@@ -4,6 +4,7 @@ import { open } from '@tauri-apps/plugin-shell'
 import { useTranslation } from 'react-i18next'
 import { useAppStore } from '../store'
 import { searchSkills, getCatalog, smartInstallSkill, smartInstallSkillToProject, detectTools, getKolList, type KolUser } from '../api/skillhub'
+import { getOfflineMode, isOfflineError } from '../api/network'
 import SkillCard from '../components/SkillCard'
 import SkillDetail from '../components/SkillDetail'
 import KolDetail from '../components/KolDetail'
@@ -86,6 +87,12 @@ export default function Discover() {
   // View mode state - default to list for better scannability
   const [viewMode, setViewMode] = useState<'grid' | 'list'>('list')
 
+  // In offline mode search and browse use the prefetched catalog
+  const [offline, setOffline] = useState(false)
+  useEffect(() => {
+    getOfflineMode().then(setOffline)
+  }, [])
+
   // Get installed tools for quick install
   const installedTools = tools.filter(t => t.installed)
 
@@ -115,7 +122,7 @@ export default function Discover() {
           })
           .catch((error) => {
             console.error('Failed to load KOL list:', error)
-            showToast(t('discover.failedToLoadKols'), 'error')
+            showToast(t(isOfflineError(error) ? 'discover.offlineUnavailable' : 'discover.failedToLoadKols'), 'error')
           })
           .finally(() => setKolLoading(false))
         return
@@ -261,7 +268,7 @@ export default function Discover() {
         <h1 className="text-3xl font-bold text-foreground mb-2 tracking-tight">
           {t('discover.title').toUpperCase()}
         </h1>
-        <p className="text-muted-foreground">{t('discover.subtitle')}</p>
+        <p className="text-muted-foreground">{t(offline ? 'discover.offlineSubtitle' : 'discover.subtitle')}</p>
       </div>
 
       {/* Search */}
@@ -392,10 +399,10 @@ export default function Discover() {
                   onClick={() => setViewingKol(kol)}
                 >
                   <div className="flex items-center gap-3 mb-3">
-                    {kol.avatarUrl ? (
+                    {kol.avatar_url ? (
                       <img
-                        src={kol.avatarUrl}
-                        alt={kol.displayName}
+                        src={kol.avatar_url}
+                        alt={kol.display_name}
                         className="w-12 h-12 border-2 border-foreground"
                       />
                     ) : (
@@ -404,8 +411,8 @@ export default function Discover() {
                       </div>
                     )}
                     <div className="flex-1 min-w-0">
-                      <h3 className="font-bold text-foreground truncate">{kol.displayName}</h3>
-                      <p className="text-sm text-muted-foreground truncate">@{kol.githubUsername}</p>
+                      <h3 className="font-bold text-foreground truncate">{kol.display_name}</h3>
+                      <p className="text-sm text-muted-foreground truncate">@{kol.username}</p>
                     </div>
                   </div>
                   {kol.bio && (
@@ -414,11 +421,11 @@ export default function Discover() {
                   <div className="flex items-center gap-4 text-xs text-muted-foreground uppercase tracking-wider">
                     <span className="flex items-center gap-1">
                       <Users size={12} />
-                      {kol.githubFollowers.toLocaleString()}
+                      {kol.followers.toLocaleString()}
                     </span>
                     <span className="flex items-center gap-1">
                       <Star size={12} />
-                      {t('discover.skillsCount', { count: kol.skillCount })}
+                      {t('discover.skillsCount', { count: kol.skills_count })}
                     </span>
                   </div>
                 </div>
@@ -22,6 +22,7 @@ import {
   detectTools,
   getSkillFilesForVersion,
   installSkill,
+  installFailures,
   smartInstallSkillToProject,
   listMarketplaceSkills,
 } from '../api/skillhub'
@@ -243,8 +244,16 @@ export default function Marketplace() {
         )
         showToast(t('marketplacePage.installedToProject', { name: viewingSkill.name }), 'success')
       } else {
-        await installSkill(skillContent, safeName, selectedToolIds)
-        showToast(t('marketplacePage.installedToTools', { name: viewingSkill.name, count: selectedToolIds.length }), 'success')
+        const results = await installSkill(skillContent, safeName, selectedToolIds)
+        const failures = installFailures(results)
+        if (failures.length > 0) {
+          showToast(
+            t('common.installPartial', { installed: results.length - failures.length, total: results.length, failures: failures.join('; ') }),
+            failures.length === results.length ? 'error' : 'warning'
+          )
+        } else {
+          showToast(t('marketplacePage.installedToTools', { name: viewingSkill.name, count: selectedToolIds.length }), 'success')
+        }
       }
 
       // Refresh tool state to update counts
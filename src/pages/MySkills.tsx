@@ -182,6 +182,7 @@ export default function MySkills() {
         version: data.version ?? skill.currentVersion,
         synced_at: new Date().toISOString(),
         platform_url: `${SKILLHUB_URL}/skills/${skill.slug}`,
+        catalog_files: data.files.map(f => f.filepath),
       }
       await invoke('write_sync_meta', { path: skillPath, meta })
 
@@ -222,6 +223,7 @@ export default function MySkills() {
         version: skill.currentVersion + 1,
         synced_at: new Date().toISOString(),
         platform_url: `${SKILLHUB_URL}/skills/${skill.slug}`,
+        catalog_files: localFiles.map(f => f.filepath),
       }
       await invoke('write_sync_meta', { path: state.localPath, meta })
 
@@ -311,6 +313,7 @@ export default function MySkills() {
         version,
         synced_at: new Date().toISOString(),
         platform_url: `${SKILLHUB_URL}/skills/${historySkill.slug}`,
+        catalog_files: data.files.map(f => f.filepath),
       }
       await invoke('write_sync_meta', { path: state.localPath, meta })
 
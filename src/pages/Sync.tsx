@@ -2,7 +2,7 @@ import { useState, useEffect } from 'react'
 import { RefreshCw, ArrowRight, ArrowLeftRight, Loader2, CheckSquare, Square, AlertTriangle, Check, X, Eye } from 'lucide-react'
 import { useTranslation } from 'react-i18next'
 import { useAppStore } from '../store'
-import { detectTools, getInstalledSkills, readSkillContent, installSkill } from '../api/skillhub'
+import { detectTools, getInstalledSkills, readSkillContent, installSkill, installFailures } from '../api/skillhub'
 import ToolIcon from '../components/ToolIcon'
 import type { InstalledSkill } from '../types'
 
@@ -182,6 +182,7 @@ export default function Sync() {
     setSyncing(true)
     let syncedCount = 0
     let failedCount = 0
+    const failures: string[] = []
 
     try {
       const skillsToSync = sourceSkills.filter(s => selectedSkills.has(s.path))
@@ -191,11 +192,17 @@ export default function Sync() {
           // Read skill content from source
           const content = await readSkillContent(skill.path)
 
-          // Install to all target tools
-          await installSkill(content, skill.name, targetTools)
-          syncedCount++
+          // Install to all target tools; a skill counts as failed if any tool failed
+          const toolFailures = installFailures(await installSkill(content, skill.name, targetTools))
+          if (toolFailures.length > 0) {
+            failures.push(...toolFailures.map(failure => `${skill.name} (${failure})`))
+            failedCount++
+          } else {
+            syncedCount++
+          }
         } catch (error) {
           console.error(`Failed to sync skill ${skill.name}:`, error)
+          failures.push(`${skill.name}: ${error instanceof Error ? error.message : String(error)}`)
           failedCount++
         }
       }
@@ -205,7 +212,7 @@ export default function Sync() {
       setTools(newTools)
 
       if (failedCount > 0) {
-        showToast(t('sync.syncPartial', { synced: syncedCount, failed: failedCount }), 'warning')
+        showToast(t('sync.syncPartial', { synced: syncedCount, failed: failedCount, failures: failures.join('; ') }), 'warning')
       } else {
         showToast(t('sync.syncSuccess', { count: syncedCount, tools: targetTools.length }), 'success')
       }
@@ -5,6 +5,52 @@ export interface DetectedTool {
   skills_path: string
   installed: boolean
   skills_count: number
+  version?: string | null
+  enabled: boolean
+  error?: string | null
+  supports_personal: boolean
+  supports_project: boolean
+  scope: 'personal' | 'project'
+}
+
+export interface ToolInfo {
+  id: string
+  name: string
+  config_paths: string[]
+  primary_subpath: string
+  all_subpaths: string[]
+  supports_personal: boolean
+  supports_project: boolean
+  custom: boolean // registered or corrected in config.json
+}
+
+// A tool registered by the user; reusing a built-in id corrects that tool's directories
+export interface CustomTool {
+  id: string
+  name: string
+  config_path: string // relative to the home or project directory, e.g. ".trae"
+  skills_subpath?: string // defaults to "skills"
+  supports_personal?: boolean
+  supports_project?: boolean
+}
+
+export interface ToolStats {
+  tool_id: string
+  name: string
+  skills_count: number
+  total_bytes: number
+}
+
+export interface DashboardStats {
+  total_skills: number
+  total_bytes: number
+  tools: ToolStats[]
+}
+
+export interface UpdateStats {
+  checked: number
+  updates_available: number
+  failed: number
 }
 
 export interface InstalledSkill {
@@ -13,6 +59,13 @@ export interface InstalledSkill {
   description?: string
   author?: string
   tool_id: string
+  modified_at?: number
+  linked?: boolean
+}
+
+export interface InstalledSkillsPage {
+  skills: InstalledSkill[]
+  total: number // skills across all pages
 }
 
 export interface SkillHubSkill {
@@ -56,6 +109,7 @@ export interface SkillFileNode {
   path: string
   type: 'file' | 'folder'
   size?: number
+  sha256?: string
   children?: SkillFileNode[]
 }
 
@@ -112,6 +166,13 @@ export interface SkillFileMetadata {
   description?: string
   author?: string
   category?: string
+  tags?: string[]
+}
+
+export interface CategoryCount {
+  kind: 'category' | 'tag'
+  name: string
+  count: number
 }
 
 export interface FileNode {
@@ -121,6 +182,8 @@ export interface FileNode {
   children?: FileNode[]
   content?: string
   metadata?: SkillFileMetadata
+  partial?: boolean
+  is_binary?: boolean // content looked binary, so it wasn't read
 }
 
 // User Hosted Skills Types
@@ -239,6 +302,8 @@ export interface SyncMeta {
   version: number
   synced_at: string
   platform_url: string
+  skill_md_only?: boolean
+  catalog_files?: string[] // files from the catalog, replaced by in-place updates
 }
 
 export interface PullResponse {